@@ -11,6 +11,7 @@ pub struct Program {
 
 pub type Expression = Spanned<Expr>;
 pub type Statement = Spanned<Stmt>;
+pub type Pattern = Spanned<Pat>;
 
 // Used fot error handling
 #[derive(Debug, Clone, PartialEq)]
@@ -21,7 +22,7 @@ pub enum Node {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expr {
-    IntegerLiteral(i32),
+    IntegerLiteral(i64),
     FloatLiteral(f64),
     BooleanLiteral(bool),
     StringLiteral(String),
@@ -38,6 +39,180 @@ pub enum Expr {
         operator: Token,
         right: Box<Expression>,
     },
+
+    Assign {
+        target: Box<Expression>,
+        value: Box<Expression>,
+    },
+
+    /// `target += value`, `operator` is the arithmetic operator applied
+    CompoundAssign {
+        target: Box<Expression>,
+        operator: Token,
+        value: Box<Expression>,
+    },
+
+    /// `value as Float`
+    Cast {
+        value: Box<Expression>,
+        target: TypeAnnotation,
+    },
+
+    Conditional {
+        then_expr: Box<Expression>,
+        condition: Box<Expression>,
+        else_expr: Box<Expression>,
+    },
+
+    Call {
+        callee: Box<Expression>,
+        arguments: Vec<Expression>,
+    },
+
+    Function {
+        parameters: Vec<Parameter>,
+        return_type: Option<TypeAnnotation>,
+        body: Vec<Statement>,
+    },
+
+    ArrayLiteral {
+        elements: Vec<Expression>,
+    },
+
+    Index {
+        object: Box<Expression>,
+        index: Box<Expression>,
+    },
+
+    MapLiteral {
+        entries: Vec<(Expression, Expression)>,
+    },
+
+    TupleLiteral {
+        elements: Vec<Expression>,
+    },
+
+    /// `pair.0`
+    TupleAccess {
+        object: Box<Expression>,
+        index: usize,
+    },
+
+    MethodCall {
+        object: Box<Expression>,
+        method: String,
+        arguments: Vec<Expression>,
+    },
+
+    /// `object?.member`, nil when the object is nil
+    OptionalChain {
+        object: Box<Expression>,
+        access: ChainAccess,
+    },
+
+    StructLiteral {
+        name: String,
+        fields: Vec<FieldInit>,
+    },
+
+    FieldAccess {
+        object: Box<Expression>,
+        field: String,
+    },
+
+    EnumVariant {
+        enum_name: String,
+        variant: String,
+        arguments: Vec<Expression>,
+    },
+
+    Match {
+        subject: Box<Expression>,
+        arms: Vec<MatchArm>,
+    },
+}
+
+/// What follows `?.` in an optional chain
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChainAccess {
+    Field(String),
+    Element(usize),
+    Method {
+        name: String,
+        arguments: Vec<Expression>,
+    },
+}
+
+/// `pattern if guard => body`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub guard: Option<Expression>,
+    pub body: Expression,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pat {
+    /// `_`
+    Wildcard,
+    /// A name that matches anything and binds the value
+    Binding(String),
+    /// Always a literal expression
+    Literal(Expr),
+    Range {
+        start: Expr,
+        end: Expr,
+        inclusive: bool,
+    },
+    /// Fields that are left out match anything
+    Struct {
+        name: String,
+        fields: Vec<FieldPattern>,
+    },
+    Variant {
+        enum_name: String,
+        variant: String,
+        payload: Vec<Pattern>,
+    },
+}
+
+/// `name: pattern` inside a struct pattern, the span covers the name
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldPattern {
+    pub name: String,
+    pub pattern: Pattern,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parameter {
+    pub name: String,
+    pub annotated_type: TypeAnnotation,
+    pub span: Span,
+}
+
+/// A field in a struct declaration
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub annotated_type: TypeAnnotation,
+    pub span: Span,
+}
+
+/// `name: value` inside a struct literal, the span covers the name
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldInit {
+    pub name: String,
+    pub value: Expression,
+    pub span: Span,
+}
+
+/// A variant in an enum declaration, with the types of its payload
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    pub name: String,
+    pub payload: Vec<TypeAnnotation>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -48,9 +223,69 @@ pub enum Stmt {
     VariableDeclaration {
         identifier: Expression,
         value: Expression,
-        annotated_type: TypeAnnotation,
+        /// Without one, the type is inferred from the value
+        annotated_type: Option<TypeAnnotation>,
+        mutable: bool,
         span: Span,
     },
+    /// `let (a, b) = pair`, element types come from the tuple
+    TupleDestructuring {
+        identifiers: Vec<Expression>,
+        value: Expression,
+        mutable: bool,
+        span: Span,
+    },
+    Block {
+        body: Vec<Statement>,
+    },
+    If {
+        condition: Expression,
+        then_branch: Vec<Statement>,
+        else_branch: Option<Vec<Statement>>,
+    },
+    While {
+        condition: Expression,
+        body: Vec<Statement>,
+        label: Option<String>,
+    },
+    For {
+        variable: Expression,
+        /// Without one, the type is that of the bounds
+        annotated_type: Option<TypeAnnotation>,
+        start: Box<Expression>,
+        end: Box<Expression>,
+        inclusive: bool,
+        step: Option<Box<Expression>>,
+        body: Vec<Statement>,
+        label: Option<String>,
+    },
+    Break {
+        label: Option<String>,
+    },
+    Continue {
+        label: Option<String>,
+    },
+    FunctionDeclaration {
+        identifier: Expression,
+        /// `T` in `fn first<T>(xs: [T]) -> T`
+        type_params: Vec<String>,
+        parameters: Vec<Parameter>,
+        return_type: Option<TypeAnnotation>,
+        body: Vec<Statement>,
+    },
+    Return {
+        value: Option<Expression>,
+    },
+    StructDeclaration {
+        identifier: Expression,
+        type_params: Vec<String>,
+        fields: Vec<Field>,
+    },
+    EnumDeclaration {
+        identifier: Expression,
+        type_params: Vec<String>,
+        variants: Vec<Variant>,
+    },
 }
 
 impl Expr {
@@ -63,6 +298,12 @@ impl Expr {
     }
 }
 
+impl Pat {
+    pub fn spanned(self, span: Span) -> Spanned<Self> {
+        Spanned { node: self, span }
+    }
+}
+
 impl Stmt {
     pub fn spanned(self, span: Span) -> Spanned<Self> {
         Spanned { node: self, span }
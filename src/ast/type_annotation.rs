@@ -2,14 +2,34 @@
 pub enum TypeAnnotation {
     StringType,
     IntegerType,
+    Int64Type,
+    ByteType,
     FloatType,
     BooleanType,
+    ArrayType(Box<TypeAnnotation>),
+    MapType {
+        key: Box<TypeAnnotation>,
+        value: Box<TypeAnnotation>,
+    },
+    /// `Int?`, either a value of the inner type or nil
+    OptionalType(Box<TypeAnnotation>),
+    /// `(Int, String)`
+    TupleType(Vec<TypeAnnotation>),
+    /// A user declared type such as a struct, resolved by the type checker,
+    /// with the type arguments of a generic type like `Box<Int>`
+    NamedType(String, Vec<TypeAnnotation>),
+    FunctionType {
+        params: Vec<TypeAnnotation>,
+        return_type: Option<Box<TypeAnnotation>>,
+    },
 }
 
 impl TypeAnnotation {
     pub fn from_identifier(name: &str) -> Option<Self> {
         match name {
             "Int" => Some(TypeAnnotation::IntegerType),
+            "Int64" => Some(TypeAnnotation::Int64Type),
+            "Byte" => Some(TypeAnnotation::ByteType),
             "Float" => Some(TypeAnnotation::FloatType),
             "Bool" => Some(TypeAnnotation::BooleanType),
             "String" => Some(TypeAnnotation::StringType),
@@ -1,9 +1,9 @@
 use core::fmt;
 
 use byteorder::{BigEndian, ByteOrder};
-use num_enum::IntoPrimitive;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 
-#[derive(IntoPrimitive, Clone, Copy, Debug)]
+#[derive(IntoPrimitive, TryFromPrimitive, Clone, Copy, Debug)]
 #[repr(u8)]
 pub enum OpCode {
     Halt = 0x01,
@@ -53,25 +53,196 @@ pub enum OpCode {
     // General equality (works on any type)
     CompareEqual = 0x1E,
     CompareNotEqual = 0x1F,
+
+    // Variables
+    DefineGlobal = 0x20,
+    LoadGlobal = 0x21,
+    StoreGlobal = 0x22,
+    LoadLocal = 0x26,
+    StoreLocal = 0x27,
+
+    // Control flow
+    Jump = 0x23,
+    JumpIfFalse = 0x24,
+    Loop = 0x25,
+
+    // Functions
+    Call = 0x28,
+    Return = 0x29,
+
+    // Closures
+    Closure = 0x2A,
+    CaptureLocal = 0x2B,
+    CaptureUpvalue = 0x2C,
+    GetUpvalue = 0x2D,
+    SetUpvalue = 0x2E,
+    CloseUpvalue = 0x2F,
+
+    // Arrays
+    BuildArray = 0x30,
+    GetIndex = 0x31,
+    SetIndex = 0x32,
+
+    // Maps
+    BuildMap = 0x33,
+    MapHas = 0x34,
+    MapRemove = 0x35,
+    MapKeys = 0x36,
+
+    // Structs
+    BuildStruct = 0x37,
+    GetField = 0x38,
+    SetField = 0x39,
+
+    // Enums
+    BuildVariant = 0x3A,
+    IsVariant = 0x3B,
+    GetPayload = 0x3C,
+
+    // Tuples
+    BuildTuple = 0x3D,
+    GetElement = 0x3E,
+    UnpackTuple = 0x3F,
+
+    // Optionals
+    JumpIfNil = 0x40,
+
+    // Conversions
+    CastIntToFloat = 0x41,
+    CastFloatToInt = 0x42,
+    CastToString = 0x43,
+
+    // Integer arithmetic that cannot overflow
+    WrappingAddInt = 0x44,
+    WrappingSubtractInt = 0x45,
+    WrappingMultiplyInt = 0x46,
+    SaturatingAddInt = 0x47,
+    SaturatingSubtractInt = 0x48,
+    SaturatingMultiplyInt = 0x49,
+
+    // Integer division, remainder and bitwise operations
+    ModuloInt = 0x4A,
+    FloorDivideInt = 0x4B,
+    BitwiseAndInt = 0x4C,
+    BitwiseOrInt = 0x4D,
+    BitwiseXorInt = 0x4E,
+    ShiftLeftInt = 0x4F,
+    ShiftRightInt = 0x50,
+    BitwiseNotInt = 0x51,
+
+    // Stack
+    Duplicate = 0x52,
+
+    // Int64 arithmetic, comparison and bitwise operations
+    AddInt64 = 0x53,
+    SubtractInt64 = 0x54,
+    MultiplyInt64 = 0x55,
+    DivideInt64 = 0x56,
+    ExponentInt64 = 0x57,
+    ModuloInt64 = 0x58,
+    FloorDivideInt64 = 0x59,
+    BitwiseAndInt64 = 0x5A,
+    BitwiseOrInt64 = 0x5B,
+    BitwiseXorInt64 = 0x5C,
+    ShiftLeftInt64 = 0x5D,
+    ShiftRightInt64 = 0x5E,
+    BitwiseNotInt64 = 0x5F,
+    UnaryNegateInt64 = 0x60,
+    CompareLessInt64 = 0x61,
+    CompareLessEqualInt64 = 0x62,
+    CompareGreaterInt64 = 0x63,
+    CompareGreaterEqualInt64 = 0x64,
+    WrappingAddInt64 = 0x65,
+    WrappingSubtractInt64 = 0x66,
+    WrappingMultiplyInt64 = 0x67,
+    SaturatingAddInt64 = 0x68,
+    SaturatingSubtractInt64 = 0x69,
+    SaturatingMultiplyInt64 = 0x6A,
+
+    // Byte arithmetic, comparison and bitwise operations
+    AddByte = 0x6B,
+    SubtractByte = 0x6C,
+    MultiplyByte = 0x6D,
+    DivideByte = 0x6E,
+    ExponentByte = 0x6F,
+    ModuloByte = 0x70,
+    FloorDivideByte = 0x71,
+    BitwiseAndByte = 0x72,
+    BitwiseOrByte = 0x73,
+    BitwiseXorByte = 0x74,
+    ShiftLeftByte = 0x75,
+    ShiftRightByte = 0x76,
+    BitwiseNotByte = 0x77,
+    CompareLessByte = 0x78,
+    CompareLessEqualByte = 0x79,
+    CompareGreaterByte = 0x7A,
+    CompareGreaterEqualByte = 0x7B,
+    WrappingAddByte = 0x7C,
+    WrappingSubtractByte = 0x7D,
+    WrappingMultiplyByte = 0x7E,
+    SaturatingAddByte = 0x7F,
+    SaturatingSubtractByte = 0x80,
+    SaturatingMultiplyByte = 0x81,
+
+    // Conversions between integer widths
+    CastToInt = 0x82,
+    CastToInt64 = 0x83,
+    CastToByte = 0x84,
+
+    // Range loops
+    CheckRangeStepInt = 0x85,
+    CheckRangeStepInt64 = 0x86,
+    CheckRangeStepByte = 0x87,
 }
 
 impl fmt::Display for OpCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
-            OpCode::AddInt | OpCode::AddFloat => "+",
-            OpCode::SubtractInt | OpCode::SubtractFloat => "-",
-            OpCode::MultiplyInt | OpCode::MultiplyFloat => "*",
-            OpCode::DivideInt | OpCode::DivideFloat => "/",
-            OpCode::ExponentInt | OpCode::ExponentFloat => "^",
-            OpCode::UnaryNegateInt | OpCode::UnaryNegateFloat => "-",
+            OpCode::AddInt | OpCode::AddFloat | OpCode::AddInt64 | OpCode::AddByte => "+",
+            OpCode::SubtractInt
+            | OpCode::SubtractFloat
+            | OpCode::SubtractInt64
+            | OpCode::SubtractByte => "-",
+            OpCode::MultiplyInt
+            | OpCode::MultiplyFloat
+            | OpCode::MultiplyInt64
+            | OpCode::MultiplyByte => "*",
+            OpCode::DivideInt | OpCode::DivideFloat | OpCode::DivideInt64 | OpCode::DivideByte => {
+                "/"
+            }
+            OpCode::ExponentInt
+            | OpCode::ExponentFloat
+            | OpCode::ExponentInt64
+            | OpCode::ExponentByte => "^",
+            OpCode::UnaryNegateInt | OpCode::UnaryNegateFloat | OpCode::UnaryNegateInt64 => "-",
             OpCode::UnaryNot => "not",
-            OpCode::CompareLessInt | OpCode::CompareLessFloat => "<",
-            OpCode::CompareLessEqualInt | OpCode::CompareLessEqualFloat => "<=",
-            OpCode::CompareGreaterInt | OpCode::CompareGreaterFloat => ">",
-            OpCode::CompareGreaterEqualInt | OpCode::CompareGreaterEqualFloat => ">=",
+            OpCode::CompareLessInt
+            | OpCode::CompareLessFloat
+            | OpCode::CompareLessInt64
+            | OpCode::CompareLessByte => "<",
+            OpCode::CompareLessEqualInt
+            | OpCode::CompareLessEqualFloat
+            | OpCode::CompareLessEqualInt64
+            | OpCode::CompareLessEqualByte => "<=",
+            OpCode::CompareGreaterInt
+            | OpCode::CompareGreaterFloat
+            | OpCode::CompareGreaterInt64
+            | OpCode::CompareGreaterByte => ">",
+            OpCode::CompareGreaterEqualInt
+            | OpCode::CompareGreaterEqualFloat
+            | OpCode::CompareGreaterEqualInt64
+            | OpCode::CompareGreaterEqualByte => ">=",
             OpCode::CompareEqual => "==",
             OpCode::CompareNotEqual => "!=",
             OpCode::ConcatString => "+",
+            OpCode::ModuloInt | OpCode::ModuloInt64 | OpCode::ModuloByte => "%",
+            OpCode::FloorDivideInt | OpCode::FloorDivideInt64 | OpCode::FloorDivideByte => "div",
+            OpCode::BitwiseAndInt | OpCode::BitwiseAndInt64 | OpCode::BitwiseAndByte => "&",
+            OpCode::BitwiseOrInt | OpCode::BitwiseOrInt64 | OpCode::BitwiseOrByte => "|",
+            OpCode::BitwiseXorInt | OpCode::BitwiseXorInt64 | OpCode::BitwiseXorByte => "xor",
+            OpCode::ShiftLeftInt | OpCode::ShiftLeftInt64 | OpCode::ShiftLeftByte => "<<",
+            OpCode::ShiftRightInt | OpCode::ShiftRightInt64 | OpCode::ShiftRightByte => ">>",
+            OpCode::BitwiseNotInt | OpCode::BitwiseNotInt64 | OpCode::BitwiseNotByte => "~",
             _ => return write!(f, "{:?}", self),
         };
         write!(f, "{}", s)
@@ -102,6 +273,7 @@ impl OpCode {
             let width = definition.operands_width[i];
 
             match width {
+                1 => instructions[offset] = *operand as u8,
                 2 => BigEndian::write_i16(&mut instructions[offset..], *operand as i16),
 
                 _ => unreachable!(
@@ -255,10 +427,472 @@ impl OpCode {
                 name: "COMPARE_NOT_EQUAL",
                 operands_width: vec![],
             },
+
+            // Variables
+            OpCode::DefineGlobal => Definition {
+                name: "DEFINE_GLOBAL",
+                operands_width: vec![2],
+            },
+            OpCode::LoadGlobal => Definition {
+                name: "LOAD_GLOBAL",
+                operands_width: vec![2],
+            },
+            OpCode::StoreGlobal => Definition {
+                name: "STORE_GLOBAL",
+                operands_width: vec![2],
+            },
+            OpCode::LoadLocal => Definition {
+                name: "LOAD_LOCAL",
+                operands_width: vec![2],
+            },
+            OpCode::StoreLocal => Definition {
+                name: "STORE_LOCAL",
+                operands_width: vec![2],
+            },
+
+            // Control flow
+            OpCode::Jump => Definition {
+                name: "JUMP",
+                operands_width: vec![2],
+            },
+            OpCode::JumpIfFalse => Definition {
+                name: "JUMP_IF_FALSE",
+                operands_width: vec![2],
+            },
+            OpCode::Loop => Definition {
+                name: "LOOP",
+                operands_width: vec![2],
+            },
+
+            // Functions
+            OpCode::Call => Definition {
+                name: "CALL",
+                operands_width: vec![1],
+            },
+            OpCode::Return => Definition {
+                name: "RETURN",
+                operands_width: vec![],
+            },
+
+            // Closures
+            OpCode::Closure => Definition {
+                name: "CLOSURE",
+                operands_width: vec![2],
+            },
+            OpCode::CaptureLocal => Definition {
+                name: "CAPTURE_LOCAL",
+                operands_width: vec![2],
+            },
+            OpCode::CaptureUpvalue => Definition {
+                name: "CAPTURE_UPVALUE",
+                operands_width: vec![2],
+            },
+            OpCode::GetUpvalue => Definition {
+                name: "GET_UPVALUE",
+                operands_width: vec![2],
+            },
+            OpCode::SetUpvalue => Definition {
+                name: "SET_UPVALUE",
+                operands_width: vec![2],
+            },
+            OpCode::CloseUpvalue => Definition {
+                name: "CLOSE_UPVALUE",
+                operands_width: vec![],
+            },
+
+            // Arrays
+            OpCode::BuildArray => Definition {
+                name: "BUILD_ARRAY",
+                operands_width: vec![2],
+            },
+            OpCode::GetIndex => Definition {
+                name: "GET_INDEX",
+                operands_width: vec![],
+            },
+            OpCode::SetIndex => Definition {
+                name: "SET_INDEX",
+                operands_width: vec![],
+            },
+
+            // Maps
+            OpCode::BuildMap => Definition {
+                name: "BUILD_MAP",
+                operands_width: vec![2],
+            },
+            OpCode::MapHas => Definition {
+                name: "MAP_HAS",
+                operands_width: vec![],
+            },
+            OpCode::MapRemove => Definition {
+                name: "MAP_REMOVE",
+                operands_width: vec![],
+            },
+            OpCode::MapKeys => Definition {
+                name: "MAP_KEYS",
+                operands_width: vec![],
+            },
+
+            // Structs
+            OpCode::BuildStruct => Definition {
+                name: "BUILD_STRUCT",
+                operands_width: vec![2, 2],
+            },
+            OpCode::GetField => Definition {
+                name: "GET_FIELD",
+                operands_width: vec![2],
+            },
+            OpCode::SetField => Definition {
+                name: "SET_FIELD",
+                operands_width: vec![2],
+            },
+
+            // Enums
+            OpCode::BuildVariant => Definition {
+                name: "BUILD_VARIANT",
+                operands_width: vec![2, 2, 2],
+            },
+            OpCode::IsVariant => Definition {
+                name: "IS_VARIANT",
+                operands_width: vec![2],
+            },
+            OpCode::GetPayload => Definition {
+                name: "GET_PAYLOAD",
+                operands_width: vec![2],
+            },
+
+            // Tuples
+            OpCode::BuildTuple => Definition {
+                name: "BUILD_TUPLE",
+                operands_width: vec![2],
+            },
+            OpCode::GetElement => Definition {
+                name: "GET_ELEMENT",
+                operands_width: vec![2],
+            },
+            OpCode::UnpackTuple => Definition {
+                name: "UNPACK_TUPLE",
+                operands_width: vec![2],
+            },
+
+            // Optionals
+            OpCode::JumpIfNil => Definition {
+                name: "JUMP_IF_NIL",
+                operands_width: vec![2],
+            },
+
+            // Conversions
+            OpCode::CastIntToFloat => Definition {
+                name: "CAST_INT_TO_FLOAT",
+                operands_width: vec![],
+            },
+            OpCode::CastFloatToInt => Definition {
+                name: "CAST_FLOAT_TO_INT",
+                operands_width: vec![],
+            },
+            OpCode::CastToString => Definition {
+                name: "CAST_TO_STRING",
+                operands_width: vec![],
+            },
+
+            // Integer arithmetic that cannot overflow
+            OpCode::WrappingAddInt => Definition {
+                name: "WRAPPING_ADD_INT",
+                operands_width: vec![],
+            },
+            OpCode::WrappingSubtractInt => Definition {
+                name: "WRAPPING_SUBTRACT_INT",
+                operands_width: vec![],
+            },
+            OpCode::WrappingMultiplyInt => Definition {
+                name: "WRAPPING_MULTIPLY_INT",
+                operands_width: vec![],
+            },
+            OpCode::SaturatingAddInt => Definition {
+                name: "SATURATING_ADD_INT",
+                operands_width: vec![],
+            },
+            OpCode::SaturatingSubtractInt => Definition {
+                name: "SATURATING_SUBTRACT_INT",
+                operands_width: vec![],
+            },
+            OpCode::SaturatingMultiplyInt => Definition {
+                name: "SATURATING_MULTIPLY_INT",
+                operands_width: vec![],
+            },
+
+            // Integer division, remainder and bitwise operations
+            OpCode::ModuloInt => Definition {
+                name: "MODULO_INT",
+                operands_width: vec![],
+            },
+            OpCode::FloorDivideInt => Definition {
+                name: "FLOOR_DIVIDE_INT",
+                operands_width: vec![],
+            },
+            OpCode::BitwiseAndInt => Definition {
+                name: "BITWISE_AND_INT",
+                operands_width: vec![],
+            },
+            OpCode::BitwiseOrInt => Definition {
+                name: "BITWISE_OR_INT",
+                operands_width: vec![],
+            },
+            OpCode::BitwiseXorInt => Definition {
+                name: "BITWISE_XOR_INT",
+                operands_width: vec![],
+            },
+            OpCode::ShiftLeftInt => Definition {
+                name: "SHIFT_LEFT_INT",
+                operands_width: vec![],
+            },
+            OpCode::ShiftRightInt => Definition {
+                name: "SHIFT_RIGHT_INT",
+                operands_width: vec![],
+            },
+            OpCode::BitwiseNotInt => Definition {
+                name: "BITWISE_NOT_INT",
+                operands_width: vec![],
+            },
+
+            // Stack
+            OpCode::Duplicate => Definition {
+                name: "DUPLICATE",
+                operands_width: vec![1],
+            },
+
+            // Int64 arithmetic, comparison and bitwise operations
+            OpCode::AddInt64 => Definition {
+                name: "ADD_INT64",
+                operands_width: vec![],
+            },
+            OpCode::SubtractInt64 => Definition {
+                name: "SUBTRACT_INT64",
+                operands_width: vec![],
+            },
+            OpCode::MultiplyInt64 => Definition {
+                name: "MULTIPLY_INT64",
+                operands_width: vec![],
+            },
+            OpCode::DivideInt64 => Definition {
+                name: "DIVIDE_INT64",
+                operands_width: vec![],
+            },
+            OpCode::ExponentInt64 => Definition {
+                name: "EXPONENT_INT64",
+                operands_width: vec![],
+            },
+            OpCode::ModuloInt64 => Definition {
+                name: "MODULO_INT64",
+                operands_width: vec![],
+            },
+            OpCode::FloorDivideInt64 => Definition {
+                name: "FLOOR_DIVIDE_INT64",
+                operands_width: vec![],
+            },
+            OpCode::BitwiseAndInt64 => Definition {
+                name: "BITWISE_AND_INT64",
+                operands_width: vec![],
+            },
+            OpCode::BitwiseOrInt64 => Definition {
+                name: "BITWISE_OR_INT64",
+                operands_width: vec![],
+            },
+            OpCode::BitwiseXorInt64 => Definition {
+                name: "BITWISE_XOR_INT64",
+                operands_width: vec![],
+            },
+            OpCode::ShiftLeftInt64 => Definition {
+                name: "SHIFT_LEFT_INT64",
+                operands_width: vec![],
+            },
+            OpCode::ShiftRightInt64 => Definition {
+                name: "SHIFT_RIGHT_INT64",
+                operands_width: vec![],
+            },
+            OpCode::BitwiseNotInt64 => Definition {
+                name: "BITWISE_NOT_INT64",
+                operands_width: vec![],
+            },
+            OpCode::UnaryNegateInt64 => Definition {
+                name: "UNARY_NEGATE_INT64",
+                operands_width: vec![],
+            },
+            OpCode::CompareLessInt64 => Definition {
+                name: "COMPARE_LESS_INT64",
+                operands_width: vec![],
+            },
+            OpCode::CompareLessEqualInt64 => Definition {
+                name: "COMPARE_LESS_EQUAL_INT64",
+                operands_width: vec![],
+            },
+            OpCode::CompareGreaterInt64 => Definition {
+                name: "COMPARE_GREATER_INT64",
+                operands_width: vec![],
+            },
+            OpCode::CompareGreaterEqualInt64 => Definition {
+                name: "COMPARE_GREATER_EQUAL_INT64",
+                operands_width: vec![],
+            },
+            OpCode::WrappingAddInt64 => Definition {
+                name: "WRAPPING_ADD_INT64",
+                operands_width: vec![],
+            },
+            OpCode::WrappingSubtractInt64 => Definition {
+                name: "WRAPPING_SUBTRACT_INT64",
+                operands_width: vec![],
+            },
+            OpCode::WrappingMultiplyInt64 => Definition {
+                name: "WRAPPING_MULTIPLY_INT64",
+                operands_width: vec![],
+            },
+            OpCode::SaturatingAddInt64 => Definition {
+                name: "SATURATING_ADD_INT64",
+                operands_width: vec![],
+            },
+            OpCode::SaturatingSubtractInt64 => Definition {
+                name: "SATURATING_SUBTRACT_INT64",
+                operands_width: vec![],
+            },
+            OpCode::SaturatingMultiplyInt64 => Definition {
+                name: "SATURATING_MULTIPLY_INT64",
+                operands_width: vec![],
+            },
+
+            // Byte arithmetic, comparison and bitwise operations
+            OpCode::AddByte => Definition {
+                name: "ADD_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::SubtractByte => Definition {
+                name: "SUBTRACT_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::MultiplyByte => Definition {
+                name: "MULTIPLY_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::DivideByte => Definition {
+                name: "DIVIDE_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::ExponentByte => Definition {
+                name: "EXPONENT_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::ModuloByte => Definition {
+                name: "MODULO_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::FloorDivideByte => Definition {
+                name: "FLOOR_DIVIDE_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::BitwiseAndByte => Definition {
+                name: "BITWISE_AND_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::BitwiseOrByte => Definition {
+                name: "BITWISE_OR_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::BitwiseXorByte => Definition {
+                name: "BITWISE_XOR_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::ShiftLeftByte => Definition {
+                name: "SHIFT_LEFT_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::ShiftRightByte => Definition {
+                name: "SHIFT_RIGHT_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::BitwiseNotByte => Definition {
+                name: "BITWISE_NOT_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::CompareLessByte => Definition {
+                name: "COMPARE_LESS_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::CompareLessEqualByte => Definition {
+                name: "COMPARE_LESS_EQUAL_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::CompareGreaterByte => Definition {
+                name: "COMPARE_GREATER_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::CompareGreaterEqualByte => Definition {
+                name: "COMPARE_GREATER_EQUAL_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::WrappingAddByte => Definition {
+                name: "WRAPPING_ADD_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::WrappingSubtractByte => Definition {
+                name: "WRAPPING_SUBTRACT_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::WrappingMultiplyByte => Definition {
+                name: "WRAPPING_MULTIPLY_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::SaturatingAddByte => Definition {
+                name: "SATURATING_ADD_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::SaturatingSubtractByte => Definition {
+                name: "SATURATING_SUBTRACT_BYTE",
+                operands_width: vec![],
+            },
+            OpCode::SaturatingMultiplyByte => Definition {
+                name: "SATURATING_MULTIPLY_BYTE",
+                operands_width: vec![],
+            },
+
+            // Conversions between integer widths
+            OpCode::CastToInt => Definition {
+                name: "CAST_TO_INT",
+                operands_width: vec![],
+            },
+            OpCode::CastToInt64 => Definition {
+                name: "CAST_TO_INT64",
+                operands_width: vec![],
+            },
+            OpCode::CastToByte => Definition {
+                name: "CAST_TO_BYTE",
+                operands_width: vec![],
+            },
+
+            // Range loops
+            OpCode::CheckRangeStepInt => Definition {
+                name: "CHECK_RANGE_STEP_INT",
+                operands_width: vec![],
+            },
+            OpCode::CheckRangeStepInt64 => Definition {
+                name: "CHECK_RANGE_STEP_INT64",
+                operands_width: vec![],
+            },
+            OpCode::CheckRangeStepByte => Definition {
+                name: "CHECK_RANGE_STEP_BYTE",
+                operands_width: vec![],
+            },
         }
     }
 }
 
+impl OpCode {
+    /// Whether the first operand of this opcode is an instruction offset
+    pub fn is_jump(&self) -> bool {
+        matches!(
+            self,
+            OpCode::Jump | OpCode::JumpIfFalse | OpCode::JumpIfNil | OpCode::Loop
+        )
+    }
+}
+
 pub trait ToOpcode {
     fn to_opcode(self) -> OpCode;
 }
@@ -314,11 +948,161 @@ impl ToOpcode for u8 {
             0x1E => OpCode::CompareEqual,
             0x1F => OpCode::CompareNotEqual,
 
+            // Variables
+            0x20 => OpCode::DefineGlobal,
+            0x21 => OpCode::LoadGlobal,
+            0x22 => OpCode::StoreGlobal,
+
+            // Control flow
+            0x23 => OpCode::Jump,
+            0x24 => OpCode::JumpIfFalse,
+            0x25 => OpCode::Loop,
+
+            // Variables
+            0x26 => OpCode::LoadLocal,
+            0x27 => OpCode::StoreLocal,
+
+            // Functions
+            0x28 => OpCode::Call,
+            0x29 => OpCode::Return,
+
+            // Closures
+            0x2A => OpCode::Closure,
+            0x2B => OpCode::CaptureLocal,
+            0x2C => OpCode::CaptureUpvalue,
+            0x2D => OpCode::GetUpvalue,
+            0x2E => OpCode::SetUpvalue,
+            0x2F => OpCode::CloseUpvalue,
+
+            // Arrays
+            0x30 => OpCode::BuildArray,
+            0x31 => OpCode::GetIndex,
+            0x32 => OpCode::SetIndex,
+
+            // Maps
+            0x33 => OpCode::BuildMap,
+            0x34 => OpCode::MapHas,
+            0x35 => OpCode::MapRemove,
+            0x36 => OpCode::MapKeys,
+
+            // Structs
+            0x37 => OpCode::BuildStruct,
+            0x38 => OpCode::GetField,
+            0x39 => OpCode::SetField,
+
+            // Enums
+            0x3A => OpCode::BuildVariant,
+            0x3B => OpCode::IsVariant,
+            0x3C => OpCode::GetPayload,
+
+            // Tuples
+            0x3D => OpCode::BuildTuple,
+            0x3E => OpCode::GetElement,
+            0x3F => OpCode::UnpackTuple,
+
+            // Optionals
+            0x40 => OpCode::JumpIfNil,
+
+            // Conversions
+            0x41 => OpCode::CastIntToFloat,
+            0x42 => OpCode::CastFloatToInt,
+            0x43 => OpCode::CastToString,
+
+            // Integer arithmetic that cannot overflow
+            0x44 => OpCode::WrappingAddInt,
+            0x45 => OpCode::WrappingSubtractInt,
+            0x46 => OpCode::WrappingMultiplyInt,
+            0x47 => OpCode::SaturatingAddInt,
+            0x48 => OpCode::SaturatingSubtractInt,
+            0x49 => OpCode::SaturatingMultiplyInt,
+
+            // Integer division, remainder and bitwise operations
+            0x4A => OpCode::ModuloInt,
+            0x4B => OpCode::FloorDivideInt,
+            0x4C => OpCode::BitwiseAndInt,
+            0x4D => OpCode::BitwiseOrInt,
+            0x4E => OpCode::BitwiseXorInt,
+            0x4F => OpCode::ShiftLeftInt,
+            0x50 => OpCode::ShiftRightInt,
+            0x51 => OpCode::BitwiseNotInt,
+
+            // Stack
+            0x52 => OpCode::Duplicate,
+
+            // Int64 arithmetic, comparison and bitwise operations
+            0x53 => OpCode::AddInt64,
+            0x54 => OpCode::SubtractInt64,
+            0x55 => OpCode::MultiplyInt64,
+            0x56 => OpCode::DivideInt64,
+            0x57 => OpCode::ExponentInt64,
+            0x58 => OpCode::ModuloInt64,
+            0x59 => OpCode::FloorDivideInt64,
+            0x5A => OpCode::BitwiseAndInt64,
+            0x5B => OpCode::BitwiseOrInt64,
+            0x5C => OpCode::BitwiseXorInt64,
+            0x5D => OpCode::ShiftLeftInt64,
+            0x5E => OpCode::ShiftRightInt64,
+            0x5F => OpCode::BitwiseNotInt64,
+            0x60 => OpCode::UnaryNegateInt64,
+            0x61 => OpCode::CompareLessInt64,
+            0x62 => OpCode::CompareLessEqualInt64,
+            0x63 => OpCode::CompareGreaterInt64,
+            0x64 => OpCode::CompareGreaterEqualInt64,
+            0x65 => OpCode::WrappingAddInt64,
+            0x66 => OpCode::WrappingSubtractInt64,
+            0x67 => OpCode::WrappingMultiplyInt64,
+            0x68 => OpCode::SaturatingAddInt64,
+            0x69 => OpCode::SaturatingSubtractInt64,
+            0x6A => OpCode::SaturatingMultiplyInt64,
+
+            // Byte arithmetic, comparison and bitwise operations
+            0x6B => OpCode::AddByte,
+            0x6C => OpCode::SubtractByte,
+            0x6D => OpCode::MultiplyByte,
+            0x6E => OpCode::DivideByte,
+            0x6F => OpCode::ExponentByte,
+            0x70 => OpCode::ModuloByte,
+            0x71 => OpCode::FloorDivideByte,
+            0x72 => OpCode::BitwiseAndByte,
+            0x73 => OpCode::BitwiseOrByte,
+            0x74 => OpCode::BitwiseXorByte,
+            0x75 => OpCode::ShiftLeftByte,
+            0x76 => OpCode::ShiftRightByte,
+            0x77 => OpCode::BitwiseNotByte,
+            0x78 => OpCode::CompareLessByte,
+            0x79 => OpCode::CompareLessEqualByte,
+            0x7A => OpCode::CompareGreaterByte,
+            0x7B => OpCode::CompareGreaterEqualByte,
+            0x7C => OpCode::WrappingAddByte,
+            0x7D => OpCode::WrappingSubtractByte,
+            0x7E => OpCode::WrappingMultiplyByte,
+            0x7F => OpCode::SaturatingAddByte,
+            0x80 => OpCode::SaturatingSubtractByte,
+            0x81 => OpCode::SaturatingMultiplyByte,
+
+            // Conversions between integer widths
+            0x82 => OpCode::CastToInt,
+            0x83 => OpCode::CastToInt64,
+            0x84 => OpCode::CastToByte,
+
+            // Range loops
+            0x85 => OpCode::CheckRangeStepInt,
+            0x86 => OpCode::CheckRangeStepInt64,
+            0x87 => OpCode::CheckRangeStepByte,
+
             _ => unreachable!("Cannot convert byte '{}' to an opcode", self),
         }
     }
 }
 
+pub fn read_uint8(instructions: &Instructions, offset: usize) -> u8 {
+    instructions[offset]
+}
+
 pub fn read_uint16(instructions: &Instructions, offset: usize) -> u16 {
     BigEndian::read_u16(&instructions[offset..offset + 2])
 }
+
+pub fn write_uint16(instructions: &mut Instructions, offset: usize, value: u16) {
+    BigEndian::write_u16(&mut instructions[offset..offset + 2], value)
+}
@@ -298,7 +298,12 @@ fn compile_source(source: &str) -> Bytecode {
     };
 
     let mut compiler = Compiler::new();
-    match compiler.compile_program(program) {
+    let result = compiler.compile_program(program);
+    for warning in compiler.warnings.errors() {
+        warning.report(source);
+    }
+
+    match result {
         Ok(bytecode) => bytecode,
         Err(errors) => {
             errors.report_all(source);
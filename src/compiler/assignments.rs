@@ -0,0 +1,69 @@
+use crate::{
+    ast::ast::{Expr, Expression},
+    bytecode::bytecode::OpCode,
+    compiler::compiler::Compiler,
+    tokens::Token,
+    utils::Span,
+};
+
+impl Compiler {
+    /// `target += value` evaluates whatever locates the target only once,
+    /// so `items[next()] += 1` calls `next` a single time. The object and
+    /// index are duplicated on the stack, one copy to read the old value
+    /// through and one to store the new value through
+    pub(crate) fn compile_compound_assignment(
+        &mut self,
+        target: Expression,
+        operator: Token,
+        value: Expression,
+        span: Span,
+    ) -> Option<()> {
+        let target_type = self.get_expr_type(&target);
+        let value_type = self.get_expr_type(&value);
+        let target_span = target.span;
+
+        match target.node {
+            Expr::Identifier(name) => {
+                let symbol = self.resolve_symbol(name, target_span)?;
+
+                self.emit_load(&symbol, target_span);
+                self.temporaries += 1;
+                self.compile_expression(value)?;
+                self.emit_binary_op(operator.get_token_type(), target_type, value_type, span);
+                self.emit_store(&symbol, span);
+            }
+
+            Expr::Index { object, index } => {
+                self.compile_expression(*object)?;
+                self.compile_expression(*index)?;
+
+                self.emit(OpCode::Duplicate, vec![2], target_span);
+                self.emit(OpCode::GetIndex, vec![], target_span);
+                self.temporaries += 1;
+
+                self.compile_expression(value)?;
+                self.emit_binary_op(operator.get_token_type(), target_type, value_type, span);
+                self.emit(OpCode::SetIndex, vec![], span);
+            }
+
+            Expr::FieldAccess { object, field } => {
+                let index = self.resolve_field(&object, &field).0;
+                self.compile_expression(*object)?;
+
+                self.emit(OpCode::Duplicate, vec![1], target_span);
+                self.emit(OpCode::GetField, vec![index], target_span);
+                self.temporaries += 1;
+
+                self.compile_expression(value)?;
+                self.emit_binary_op(operator.get_token_type(), target_type, value_type, span);
+                self.emit(OpCode::SetField, vec![index], span);
+            }
+
+            _ => unreachable!(
+                "Parser only accepts variables, fields and indexing as assignment targets"
+            ),
+        }
+
+        Some(())
+    }
+}
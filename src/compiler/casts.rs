@@ -0,0 +1,35 @@
+use crate::{
+    ast::{ast::Expression, type_annotation::TypeAnnotation},
+    bytecode::bytecode::OpCode,
+    compiler::compiler::Compiler,
+    type_checker::type_checker::Type,
+    utils::Span,
+};
+
+impl Compiler {
+    /// Casting a value to its own type leaves it as it is
+    pub(crate) fn compile_cast(
+        &mut self,
+        value: Expression,
+        target: &TypeAnnotation,
+        span: Span,
+    ) -> Option<()> {
+        let value_type = self.get_expr_type(&value);
+        let target = self.resolve_annotation(target);
+        self.compile_expression(value)?;
+
+        let opcode = match (&value_type, &target) {
+            _ if value_type == target => return Some(()),
+            (Type::Float, Type::Integer) => OpCode::CastFloatToInt,
+            (_, Type::Float) => OpCode::CastIntToFloat,
+            (_, Type::Integer) => OpCode::CastToInt,
+            (_, Type::Int64) => OpCode::CastToInt64,
+            (_, Type::Byte) => OpCode::CastToByte,
+            (_, Type::String) => OpCode::CastToString,
+            _ => unreachable!("Invalid casts should be caught in type checker"),
+        };
+
+        self.emit(opcode, vec![], span);
+        Some(())
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{
+    ast::ast::Expression, bytecode::bytecode::OpCode, compiler::compiler::Compiler,
+    type_checker::type_checker::Type, utils::Span,
+};
+
+impl Compiler {
+    pub(crate) fn compile_array_literal(
+        &mut self,
+        elements: Vec<Expression>,
+        span: Span,
+    ) -> Option<()> {
+        let count = elements.len();
+
+        for element in elements {
+            self.compile_expression(element)?;
+        }
+
+        self.emit(OpCode::BuildArray, vec![count], span);
+        Some(())
+    }
+
+    pub(crate) fn compile_map_literal(
+        &mut self,
+        entries: Vec<(Expression, Expression)>,
+        span: Span,
+    ) -> Option<()> {
+        let count = entries.len();
+
+        for (key, value) in entries {
+            self.compile_expression(key)?;
+            self.compile_expression(value)?;
+        }
+
+        self.emit(OpCode::BuildMap, vec![count], span);
+        Some(())
+    }
+
+    pub(crate) fn compile_index(
+        &mut self,
+        object: Expression,
+        index: Expression,
+        span: Span,
+    ) -> Option<()> {
+        // The index keeps its own span on the stack, which the VM
+        // reports when it is out of bounds
+        self.compile_expression(object)?;
+        self.compile_expression(index)?;
+
+        self.emit(OpCode::GetIndex, vec![], span);
+        Some(())
+    }
+
+    /// Built in methods compile to a dedicated opcode each
+    pub(crate) fn compile_method_call(
+        &mut self,
+        object: Expression,
+        method: String,
+        arguments: Vec<Expression>,
+        span: Span,
+    ) -> Option<()> {
+        let object_type = self.get_expr_type(&object);
+
+        self.compile_expression(object)?;
+        self.compile_method_arguments(&object_type, &method, arguments, span)
+    }
+
+    /// Compile the arguments of a method called on the value already on the stack
+    pub(crate) fn compile_method_arguments(
+        &mut self,
+        object_type: &Type,
+        method: &str,
+        arguments: Vec<Expression>,
+        span: Span,
+    ) -> Option<()> {
+        let opcode = match (object_type, method) {
+            (Type::Map { .. }, "has") => OpCode::MapHas,
+            (Type::Map { .. }, "remove") => OpCode::MapRemove,
+            (Type::Map { .. }, "keys") => OpCode::MapKeys,
+            (integer_type, method) if integer_type.is_integer() => {
+                Self::integer_method_opcode(method, integer_type)
+            }
+            _ => unreachable!("Unknown methods should be caught in type checker"),
+        };
+
+        for argument in arguments {
+            self.compile_expression(argument)?;
+        }
+
+        self.emit(opcode, vec![], span);
+        Some(())
+    }
+
+    /// `object[index] = value` leaves the assigned value on the stack
+    pub(crate) fn compile_index_assignment(
+        &mut self,
+        object: Expression,
+        index: Expression,
+        value: Expression,
+        span: Span,
+    ) -> Option<()> {
+        self.compile_expression(object)?;
+        self.compile_expression(index)?;
+        self.compile_expression(value)?;
+
+        self.emit(OpCode::SetIndex, vec![], span);
+        Some(())
+    }
+}
@@ -1,21 +1,49 @@
-use std::mem;
+use std::{collections::HashMap, mem};
 
 use crate::{
     ast::ast::{Expr, Expression, Program, Statement, Stmt},
     bytecode::bytecode::{Instructions, OpCode},
+    compiler::{
+        control_flow::LoopContext,
+        symbol_table::{Symbol, SymbolScope, SymbolTable},
+    },
     errors::{ErrorCollector, HydorError},
     runtime_value::RuntimeValue,
-    tokens::TokenType,
-    type_checker::type_checker::{Type, TypeChecker},
+    tokens::{Token, TokenType},
+    type_checker::{
+        enums::EnumDefinition,
+        structs::StructDefinition,
+        type_checker::{Type, TypeChecker},
+    },
     utils::Span,
 };
 
 pub struct Compiler {
-    instructions: Instructions,
+    pub(crate) instructions: Instructions,
     constants: Vec<RuntimeValue>,
     string_table: Vec<String>,
-    debug_info: DebugInfo,
+    pub(crate) debug_info: DebugInfo,
+    pub(crate) symbol_table: SymbolTable,
+    pub(crate) loops: Vec<LoopContext>,
+    /// Struct layouts, taken from the type checker
+    pub(crate) structs: HashMap<String, StructDefinition>,
+    /// Enum variants and their tags, taken from the type checker
+    pub(crate) enums: HashMap<String, EnumDefinition>,
+    /// Type parameters of the generic functions being compiled, outermost first
+    pub(crate) type_parameters: Vec<String>,
+    /// Types that depend on flow or on more than one branch, such as narrowed
+    /// variables and match results, taken from the type checker
+    pub(crate) expr_types: HashMap<Span, Type>,
+    /// Types of the variables declared without an annotation, keyed by the
+    /// span of their name, taken from the type checker
+    pub inferred_types: HashMap<Span, Type>,
+    /// Values an enclosing expression has pushed above the locals, so that
+    /// locals declared inside an expression land in the right stack slot
+    pub(crate) temporaries: usize,
+    /// Whether a jump past the 16-bit operand range was already reported
+    pub(crate) jump_too_far: bool,
     errors: ErrorCollector,
+    pub warnings: ErrorCollector,
 }
 
 pub struct Bytecode {
@@ -74,7 +102,17 @@ impl Compiler {
             constants: Vec::new(),
             string_table: Vec::new(),
             debug_info: DebugInfo::new(),
+            symbol_table: SymbolTable::new(),
+            loops: Vec::new(),
+            structs: HashMap::new(),
+            enums: HashMap::new(),
+            type_parameters: Vec::new(),
+            expr_types: HashMap::new(),
+            inferred_types: HashMap::new(),
+            temporaries: 0,
+            jump_too_far: false,
             errors: ErrorCollector::new(),
+            warnings: ErrorCollector::new(),
         }
     }
 
@@ -82,7 +120,16 @@ impl Compiler {
     pub fn compile_program(&mut self, program: Program) -> Result<Bytecode, ErrorCollector> {
         // Type check the entire program before compiling
         let mut type_checker = TypeChecker::new();
-        type_checker.check_program(&program)?;
+        let checked = type_checker.check_program(&program);
+        self.warnings = mem::take(&mut type_checker.warnings);
+        checked?;
+
+        self.structs = mem::take(&mut type_checker.structs);
+        self.enums = mem::take(&mut type_checker.enums);
+        self.expr_types = mem::take(&mut type_checker.expr_types);
+        self.inferred_types = mem::take(&mut type_checker.inferred_types);
+
+        self.define_top_level_functions(&program.statements);
 
         for stmt in program.statements {
             let result = self.try_compile_statement(stmt);
@@ -109,8 +156,10 @@ impl Compiler {
         }
     }
 
-    fn try_compile_statement(&mut self, stmt: Statement) -> Option<()> {
+    pub(crate) fn try_compile_statement(&mut self, stmt: Statement) -> Option<()> {
         let span = stmt.span;
+        // Statements always start with only locals on the stack
+        self.temporaries = 0;
 
         match stmt.node {
             Stmt::Expression { expression } => {
@@ -118,6 +167,75 @@ impl Compiler {
                 self.emit(OpCode::Pop, vec![], span);
             }
 
+            Stmt::VariableDeclaration {
+                identifier,
+                value,
+                annotated_type,
+                ..
+            } => {
+                let var_name = match identifier.node {
+                    Expr::Identifier(name) => name,
+                    _ => unreachable!("Var names are always identifiers"),
+                };
+
+                self.compile_expression(value)?;
+
+                let var_type = match &annotated_type {
+                    Some(annotated_type) => self.resolve_annotation(annotated_type),
+                    None => self.inferred_types[&identifier.span].clone(),
+                };
+                if self.symbol_table.is_top_level() {
+                    let slot = self.symbol_table.define_global(var_name, var_type);
+                    self.emit(OpCode::DefineGlobal, vec![slot], span);
+                } else {
+                    // The value is already sitting in the local's stack slot
+                    self.symbol_table.define_local(var_name, var_type);
+                }
+            }
+
+            Stmt::TupleDestructuring {
+                identifiers, value, ..
+            } => self.compile_tuple_destructuring(identifiers, value, span)?,
+
+            Stmt::Block { body } => self.compile_block(body, span)?,
+
+            // Struct layouts and enum tags are already known from type checking
+            Stmt::StructDeclaration { .. } | Stmt::EnumDeclaration { .. } => {}
+
+            Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => self.compile_if_statement(condition, then_branch, else_branch, span)?,
+
+            Stmt::While {
+                condition,
+                body,
+                label,
+            } => self.compile_while_statement(condition, body, label, span)?,
+
+            for_loop @ Stmt::For { .. } => self.compile_for_statement(for_loop, span)?,
+
+            Stmt::FunctionDeclaration {
+                identifier,
+                type_params,
+                parameters,
+                return_type,
+                body,
+            } => self.compile_function_declaration(
+                identifier,
+                type_params,
+                parameters,
+                return_type,
+                body,
+                span,
+            )?,
+
+            Stmt::Return { value } => self.compile_return(value, span)?,
+
+            Stmt::Break { label } => self.compile_break(label, span),
+            Stmt::Continue { label } => self.compile_continue(label, span),
+
             unknown => {
                 self.throw_error(HydorError::UnknownAST {
                     node: unknown.to_node(),
@@ -130,12 +248,23 @@ impl Compiler {
         Some(())
     }
 
-    fn compile_expression(&mut self, expr: Expression) -> Option<()> {
+    pub(crate) fn compile_expression(&mut self, expr: Expression) -> Option<()> {
+        let temporaries = self.temporaries;
+        let result = self.compile_expression_node(expr);
+
+        // However much the expression pushed along the way, it leaves one value behind
+        self.temporaries = temporaries + 1;
+        result
+    }
+
+    fn compile_expression_node(&mut self, expr: Expression) -> Option<()> {
         let span = expr.span;
 
         match expr.node {
+            // The type checker makes sure the literal fits its width
             Expr::IntegerLiteral(v) => {
-                let idx = self.add_constant(RuntimeValue::IntegerLiteral(v));
+                let constant = Self::integer_constant(v, &self.integer_literal_type(span));
+                let idx = self.add_constant(constant);
                 self.emit(OpCode::LoadConstant, vec![idx], span);
             }
 
@@ -161,6 +290,91 @@ impl Compiler {
                 self.emit(OpCode::LoadNil, vec![], span);
             }
 
+            Expr::Identifier(name) => {
+                let symbol = self.resolve_symbol(name, span)?;
+                self.emit_load(&symbol, span);
+            }
+
+            Expr::Assign { target, value } => {
+                let name = match target.node {
+                    Expr::Identifier(name) => name,
+                    Expr::Index { object, index } => {
+                        return self.compile_index_assignment(*object, *index, *value, span);
+                    }
+                    Expr::FieldAccess { object, field } => {
+                        return self.compile_field_assignment(*object, field, *value, span);
+                    }
+                    _ => unreachable!(
+                        "Parser only accepts identifiers and indexing as assignment targets"
+                    ),
+                };
+
+                let symbol = self.resolve_symbol(name, target.span)?;
+
+                self.compile_expression(*value)?;
+                self.emit_store(&symbol, span);
+            }
+
+            Expr::CompoundAssign {
+                target,
+                operator,
+                value,
+            } => self.compile_compound_assignment(*target, operator, *value, span)?,
+
+            Expr::Call { callee, arguments } => self.compile_call(*callee, arguments, span)?,
+
+            Expr::ArrayLiteral { elements } => self.compile_array_literal(elements, span)?,
+
+            Expr::Index { object, index } => self.compile_index(*object, *index, span)?,
+
+            Expr::MapLiteral { entries } => self.compile_map_literal(entries, span)?,
+
+            Expr::MethodCall {
+                object,
+                method,
+                arguments,
+            } => self.compile_method_call(*object, method, arguments, span)?,
+
+            Expr::StructLiteral { name, fields } => {
+                self.compile_struct_literal(name, fields, span)?
+            }
+
+            Expr::FieldAccess { object, field } => {
+                self.compile_field_access(*object, field, span)?
+            }
+
+            Expr::TupleLiteral { elements } => self.compile_tuple_literal(elements, span)?,
+
+            Expr::TupleAccess { object, index } => {
+                self.compile_tuple_access(*object, index, span)?
+            }
+
+            Expr::EnumVariant {
+                enum_name,
+                variant,
+                arguments,
+            } => self.compile_enum_variant(enum_name, variant, arguments, span)?,
+
+            Expr::Match { subject, arms } => self.compile_match(*subject, arms, span)?,
+
+            Expr::Function {
+                parameters,
+                return_type,
+                body,
+            } => self.compile_function(
+                "<anonymous>".to_string(),
+                parameters,
+                return_type,
+                body,
+                span,
+            )?,
+
+            Expr::Conditional {
+                then_expr,
+                condition,
+                else_expr,
+            } => self.compile_conditional_expr(*then_expr, *condition, *else_expr, span)?,
+
             Expr::Unary { operator, right } => {
                 self.compile_expression(*right.clone())?;
                 let operand_type = self.get_expr_type(&right);
@@ -169,6 +383,7 @@ impl Compiler {
                     TokenType::Minus => {
                         match operand_type {
                             Type::Integer => self.emit(OpCode::UnaryNegateInt, vec![], span),
+                            Type::Int64 => self.emit(OpCode::UnaryNegateInt64, vec![], span),
                             Type::Float => self.emit(OpCode::UnaryNegateFloat, vec![], span),
                             _ => {
                                 self.throw_error(HydorError::TypeMismatch {
@@ -181,10 +396,38 @@ impl Compiler {
                         }
                     }
                     TokenType::Not => self.emit(OpCode::UnaryNot, vec![], span),
+                    TokenType::Tilde => {
+                        let opcode = match operand_type {
+                            Type::Int64 => OpCode::BitwiseNotInt64,
+                            Type::Byte => OpCode::BitwiseNotByte,
+                            _ => OpCode::BitwiseNotInt,
+                        };
+                        self.emit(opcode, vec![], span)
+                    }
                     _ => unreachable!("Unhandled unary operator type"),
                 };
             }
 
+            Expr::Cast { value, target } => self.compile_cast(*value, &target, span)?,
+
+            Expr::BinaryOperation {
+                left,
+                operator,
+                right,
+            } if matches!(operator.get_token_type(), TokenType::And | TokenType::Or) => {
+                self.compile_logical_expr(*left, operator.get_token_type(), *right, span)?
+            }
+
+            Expr::BinaryOperation {
+                left,
+                operator: Token::QuestionQuestion,
+                right,
+            } => self.compile_nil_coalescing(*left, *right, span)?,
+
+            Expr::OptionalChain { object, access } => {
+                self.compile_optional_chain(*object, access, span)?
+            }
+
             Expr::BinaryOperation {
                 left,
                 operator,
@@ -195,121 +438,7 @@ impl Compiler {
 
                 let left_type = self.get_expr_type(&left);
                 let right_type = self.get_expr_type(&right);
-
-                match operator.get_token_type() {
-                    TokenType::Plus => match (left_type, right_type) {
-                        (Type::Integer, Type::Integer) => self.emit(OpCode::AddInt, vec![], span),
-
-                        (Type::Float, Type::Float) => self.emit(OpCode::AddFloat, vec![], span),
-
-                        (Type::String, Type::String) => {
-                            self.emit(OpCode::ConcatString, vec![], span)
-                        }
-                        _ => {
-                            unreachable!("Type mismatch should be caught in type checker")
-                        }
-                    },
-
-                    TokenType::Minus => match (left_type, right_type) {
-                        (Type::Integer, Type::Integer) => {
-                            self.emit(OpCode::SubtractInt, vec![], span)
-                        }
-                        (Type::Float, Type::Float) => {
-                            self.emit(OpCode::SubtractFloat, vec![], span)
-                        }
-                        _ => {
-                            unreachable!("Type mismatch should be caught in type checker")
-                        }
-                    },
-
-                    TokenType::Asterisk => match (left_type, right_type) {
-                        (Type::Integer, Type::Integer) => {
-                            self.emit(OpCode::MultiplyInt, vec![], span)
-                        }
-                        (Type::Float, Type::Float) => {
-                            self.emit(OpCode::MultiplyFloat, vec![], span)
-                        }
-                        _ => {
-                            unreachable!("Type mismatch should be caught in type checker")
-                        }
-                    },
-
-                    TokenType::Slash => match (left_type, right_type) {
-                        (Type::Integer, Type::Integer) => {
-                            self.emit(OpCode::DivideInt, vec![], span)
-                        }
-                        (Type::Float, Type::Float) => self.emit(OpCode::DivideFloat, vec![], span),
-                        _ => {
-                            unreachable!("Type mismatch should be caught in type checker")
-                        }
-                    },
-
-                    TokenType::Caret => match (left_type, right_type) {
-                        (Type::Integer, Type::Integer) => {
-                            self.emit(OpCode::ExponentInt, vec![], span)
-                        }
-                        (Type::Float, Type::Float) => {
-                            self.emit(OpCode::ExponentFloat, vec![], span)
-                        }
-                        _ => {
-                            unreachable!("Type mismatch should be caught in type checker")
-                        }
-                    },
-
-                    TokenType::LessThan => match (left_type, right_type) {
-                        (Type::Integer, Type::Integer) => {
-                            self.emit(OpCode::CompareLessInt, vec![], span)
-                        }
-                        (Type::Float, Type::Float) => {
-                            self.emit(OpCode::CompareLessFloat, vec![], span)
-                        }
-                        _ => {
-                            unreachable!("Type mismatch should be caught in type checker")
-                        }
-                    },
-
-                    TokenType::LessThanEqual => match (left_type, right_type) {
-                        (Type::Integer, Type::Integer) => {
-                            self.emit(OpCode::CompareLessEqualInt, vec![], span)
-                        }
-                        (Type::Float, Type::Float) => {
-                            self.emit(OpCode::CompareLessEqualFloat, vec![], span)
-                        }
-                        _ => {
-                            unreachable!("Type mismatch should be caught in type checker")
-                        }
-                    },
-
-                    TokenType::GreaterThan => match (left_type, right_type) {
-                        (Type::Integer, Type::Integer) => {
-                            self.emit(OpCode::CompareGreaterInt, vec![], span)
-                        }
-                        (Type::Float, Type::Float) => {
-                            self.emit(OpCode::CompareGreaterFloat, vec![], span)
-                        }
-                        _ => {
-                            unreachable!("Type mismatch should be caught in type checker")
-                        }
-                    },
-
-                    TokenType::GreaterThanEqual => match (left_type, right_type) {
-                        (Type::Integer, Type::Integer) => {
-                            self.emit(OpCode::CompareGreaterEqualInt, vec![], span)
-                        }
-                        (Type::Float, Type::Float) => {
-                            self.emit(OpCode::CompareGreaterEqualFloat, vec![], span)
-                        }
-                        _ => {
-                            unreachable!("Type mismatch should be caught in type checker")
-                        }
-                    },
-
-                    TokenType::Equal => self.emit(OpCode::CompareEqual, vec![], span),
-
-                    TokenType::NotEqual => self.emit(OpCode::CompareNotEqual, vec![], span),
-
-                    _ => unreachable!("Unhandled binary operator type"),
-                };
+                self.emit_binary_op(operator.get_token_type(), left_type, right_type, span);
             }
 
             unknown => {
@@ -324,13 +453,59 @@ impl Compiler {
         Some(())
     }
 
-    fn get_expr_type(&self, expr: &Expression) -> Type {
+    /// Emit the opcode for a binary operator whose operands are already on the stack
+    pub(crate) fn emit_binary_op(
+        &mut self,
+        operator: TokenType,
+        left_type: Type,
+        right_type: Type,
+        span: Span,
+    ) {
+        let opcode = match (operator, &left_type, &right_type) {
+            (TokenType::Equal, ..) => OpCode::CompareEqual,
+            (TokenType::NotEqual, ..) => OpCode::CompareNotEqual,
+
+            (TokenType::Plus, Type::String, Type::String) => OpCode::ConcatString,
+
+            (operator, Type::Float, Type::Float) => match operator {
+                TokenType::Plus => OpCode::AddFloat,
+                TokenType::Minus => OpCode::SubtractFloat,
+                TokenType::Asterisk => OpCode::MultiplyFloat,
+                TokenType::Slash => OpCode::DivideFloat,
+                TokenType::Caret => OpCode::ExponentFloat,
+                TokenType::LessThan => OpCode::CompareLessFloat,
+                TokenType::LessThanEqual => OpCode::CompareLessEqualFloat,
+                TokenType::GreaterThan => OpCode::CompareGreaterFloat,
+                TokenType::GreaterThanEqual => OpCode::CompareGreaterEqualFloat,
+                _ => unreachable!("Type mismatch should be caught in type checker"),
+            },
+
+            // The type checker only lets integers of one width through to the rest
+            (operator, integer_type, _) if left_type == right_type => {
+                Self::integer_opcode(operator, integer_type)
+            }
+
+            _ => unreachable!("Type mismatch should be caught in type checker"),
+        };
+
+        self.emit(opcode, vec![], span);
+    }
+
+    pub(crate) fn get_expr_type(&self, expr: &Expression) -> Type {
         match &expr.node {
-            Expr::IntegerLiteral(_) => Type::Integer,
+            Expr::IntegerLiteral(_) => self.integer_literal_type(expr.span),
             Expr::FloatLiteral(_) => Type::Float,
             Expr::BooleanLiteral(_) => Type::Bool,
             Expr::StringLiteral(_) => Type::String,
             Expr::NilLiteral => Type::Nil,
+            // A narrowed variable has the type the type checker narrowed it to
+            Expr::Identifier(name) => {
+                self.expr_types.get(&expr.span).cloned().unwrap_or_else(|| {
+                    self.symbol_table
+                        .resolve_type(name)
+                        .expect("Undefined identifiers should be caught in type checker")
+                })
+            }
 
             Expr::Unary { right, operator } => {
                 match operator.get_token_type() {
@@ -342,6 +517,7 @@ impl Compiler {
                         // !x is always Bool
                         Type::Bool
                     }
+                    TokenType::Tilde => self.get_expr_type(right),
                     _ => unreachable!(),
                 }
             }
@@ -351,24 +527,130 @@ impl Compiler {
                 | TokenType::Minus
                 | TokenType::Asterisk
                 | TokenType::Slash
-                | TokenType::Caret => self.get_expr_type(left),
+                | TokenType::Caret
+                | TokenType::Percent
+                | TokenType::Div
+                | TokenType::Ampersand
+                | TokenType::Pipe
+                | TokenType::Xor
+                | TokenType::ShiftLeft
+                | TokenType::ShiftRight => self.get_expr_type(left),
 
                 TokenType::LessThan
                 | TokenType::LessThanEqual
                 | TokenType::GreaterThan
                 | TokenType::GreaterThanEqual
                 | TokenType::Equal
-                | TokenType::NotEqual => Type::Bool,
+                | TokenType::NotEqual
+                | TokenType::And
+                | TokenType::Or => Type::Bool,
+
+                TokenType::QuestionQuestion => self.expr_types[&expr.span].clone(),
 
                 _ => unreachable!(),
             },
 
+            Expr::Assign { value, .. } => self.get_expr_type(value),
+            Expr::CompoundAssign { target, .. } => self.get_expr_type(target),
+            Expr::Cast { target, .. } => self.resolve_annotation(target),
+            Expr::Conditional { .. } => self.expr_types[&expr.span].clone(),
+            Expr::Function {
+                parameters,
+                return_type,
+                ..
+            } => Type::from_signature(parameters, return_type.as_ref())
+                .resolve_names(&self.enums, &self.type_parameters),
+            // Calls to generic functions have their result types recorded
+            Expr::Call { callee, .. } => match self.expr_types.get(&expr.span) {
+                Some(return_type) => return_type.clone(),
+                None => match self.get_expr_type(callee) {
+                    Type::Function { return_type, .. } => *return_type,
+                    _ => unreachable!("Calls to non-functions should be caught in type checker"),
+                },
+            },
+            // An empty literal only appears where the type checker knows its type
+            Expr::ArrayLiteral { elements } => Type::Array(Box::new(
+                elements
+                    .first()
+                    .map_or(Type::Nil, |first| self.get_expr_type(first)),
+            )),
+            Expr::MapLiteral { entries } => match entries.first() {
+                Some((key, value)) => Type::Map {
+                    key: Box::new(self.get_expr_type(key)),
+                    value: Box::new(self.get_expr_type(value)),
+                },
+                None => Type::Map {
+                    key: Box::new(Type::Nil),
+                    value: Box::new(Type::Nil),
+                },
+            },
+            Expr::Index { object, .. } => match self.get_expr_type(object) {
+                Type::Array(element_type) => *element_type,
+                Type::Map { value, .. } => *value,
+                _ => unreachable!("Indexing non-collections should be caught in type checker"),
+            },
+            Expr::StructLiteral { name, .. } => match self.expr_types.get(&expr.span) {
+                Some(struct_type) => struct_type.clone(),
+                None => Type::Struct(name.clone(), Vec::new()),
+            },
+            Expr::Match { .. } | Expr::OptionalChain { .. } => self.expr_types[&expr.span].clone(),
+            Expr::FieldAccess { object, field } => self.resolve_field(object, field).1,
+            Expr::EnumVariant { enum_name, .. } => match self.expr_types.get(&expr.span) {
+                Some(enum_type) => enum_type.clone(),
+                None => Type::Enum(enum_name.clone(), Vec::new()),
+            },
+            Expr::TupleLiteral { elements } => {
+                Type::Tuple(elements.iter().map(|e| self.get_expr_type(e)).collect())
+            }
+            Expr::TupleAccess { object, index } => match self.get_expr_type(object) {
+                Type::Tuple(mut elements) => elements.swap_remove(*index),
+                _ => unreachable!("Tuple access on non-tuples should be caught in type checker"),
+            },
+            Expr::MethodCall { object, method, .. } => {
+                TypeChecker::method_signature(&self.get_expr_type(object), method)
+                    .map(|(_, return_type)| return_type)
+                    .expect("Unknown methods should be caught in type checker")
+            }
+
             _ => unreachable!("Unknown expression type"),
         }
     }
 
+    pub(crate) fn resolve_symbol(&mut self, name: String, span: Span) -> Option<Symbol> {
+        match self.symbol_table.resolve(&name) {
+            Some(symbol) => Some(symbol),
+            None => {
+                self.throw_error(HydorError::UndefinedIdentifier {
+                    ident_name: name,
+                    span,
+                });
+                None
+            }
+        }
+    }
+
+    pub(crate) fn emit_load(&mut self, symbol: &Symbol, span: Span) {
+        let opcode = match symbol.scope {
+            SymbolScope::Global => OpCode::LoadGlobal,
+            SymbolScope::Local => OpCode::LoadLocal,
+            SymbolScope::Upvalue => OpCode::GetUpvalue,
+        };
+
+        self.emit(opcode, vec![symbol.index], span);
+    }
+
+    pub(crate) fn emit_store(&mut self, symbol: &Symbol, span: Span) {
+        let opcode = match symbol.scope {
+            SymbolScope::Global => OpCode::StoreGlobal,
+            SymbolScope::Local => OpCode::StoreLocal,
+            SymbolScope::Upvalue => OpCode::SetUpvalue,
+        };
+
+        self.emit(opcode, vec![symbol.index], span);
+    }
+
     /// Add a string to the string table (with deduplication)
-    fn intern_string(&mut self, s: String) -> usize {
+    pub(crate) fn intern_string(&mut self, s: String) -> usize {
         // Check if we already have this string
         if let Some(pos) = self.string_table.iter().position(|existing| existing == &s) {
             return pos;
@@ -389,20 +671,20 @@ impl Compiler {
     }
 
     /// Emit an instruction with span tracking
-    fn emit(&mut self, opcode: OpCode, operands: Vec<usize>, span: Span) -> usize {
+    pub(crate) fn emit(&mut self, opcode: OpCode, operands: Vec<usize>, span: Span) -> usize {
         let instruction = OpCode::make(opcode, operands);
         let position = self.add_instruction(instruction, span);
         position
     }
 
     /// Add a constant to the constants table
-    fn add_constant(&mut self, value: RuntimeValue) -> usize {
+    pub(crate) fn add_constant(&mut self, value: RuntimeValue) -> usize {
         self.constants.push(value);
         self.constants.len() - 1
     }
 
     /// Record a compilation error
-    fn throw_error(&mut self, error: HydorError) {
+    pub(crate) fn throw_error(&mut self, error: HydorError) {
         self.errors.add(error);
     }
 
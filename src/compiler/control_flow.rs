@@ -0,0 +1,385 @@
+use crate::{
+    ast::ast::{Expr, Expression, Statement, Stmt},
+    bytecode::bytecode::{OpCode, write_uint16},
+    compiler::compiler::Compiler,
+    errors::HydorError,
+    tokens::TokenType,
+    utils::Span,
+};
+
+/// Placeholder operand for jumps whose target is not known yet
+const UNPATCHED_JUMP: usize = 0xFFFF;
+
+/// Bookkeeping for the loop currently being compiled
+pub struct LoopContext {
+    pub label: Option<String>,
+    /// Number of locals alive when the body starts, anything above
+    /// this is discarded when jumping out of the body
+    pub local_count: usize,
+    /// Where `continue` jumps to, if already known
+    pub continue_target: Option<usize>,
+    pub break_jumps: Vec<usize>,
+    pub continue_jumps: Vec<usize>,
+}
+
+impl Compiler {
+    pub(crate) fn compile_if_statement(
+        &mut self,
+        condition: Expression,
+        then_branch: Vec<Statement>,
+        else_branch: Option<Vec<Statement>>,
+        span: Span,
+    ) -> Option<()> {
+        self.compile_expression(condition)?;
+        let then_jump = self.emit_jump(OpCode::JumpIfFalse, span);
+
+        self.compile_block(then_branch, span)?;
+
+        match else_branch {
+            Some(else_branch) => {
+                let else_jump = self.emit_jump(OpCode::Jump, span);
+                self.patch_jump(then_jump);
+
+                self.compile_block(else_branch, span)?;
+                self.patch_jump(else_jump);
+            }
+            None => self.patch_jump(then_jump),
+        }
+
+        Some(())
+    }
+
+    /// Only the selected branch of `a if cond else b` is evaluated
+    pub(crate) fn compile_conditional_expr(
+        &mut self,
+        then_expr: Expression,
+        condition: Expression,
+        else_expr: Expression,
+        span: Span,
+    ) -> Option<()> {
+        // The condition is popped again before either branch runs
+        let temporaries = self.temporaries;
+
+        self.compile_expression(condition)?;
+        let else_jump = self.emit_jump(OpCode::JumpIfFalse, span);
+
+        self.temporaries = temporaries;
+        self.compile_expression(then_expr)?;
+        let end_jump = self.emit_jump(OpCode::Jump, span);
+
+        self.patch_jump(else_jump);
+        self.temporaries = temporaries;
+        self.compile_expression(else_expr)?;
+        self.patch_jump(end_jump);
+
+        Some(())
+    }
+
+    /// Short-circuit `and`/`or`, skipping the right operand when
+    /// the left one already decides the result
+    pub(crate) fn compile_logical_expr(
+        &mut self,
+        left: Expression,
+        operator: TokenType,
+        right: Expression,
+        span: Span,
+    ) -> Option<()> {
+        // The left operand is popped again before the right one runs
+        let temporaries = self.temporaries;
+
+        self.compile_expression(left)?;
+        let short_circuit_jump = self.emit_jump(OpCode::JumpIfFalse, span);
+        self.temporaries = temporaries;
+
+        match operator {
+            TokenType::And => {
+                self.compile_expression(right)?;
+                let end_jump = self.emit_jump(OpCode::Jump, span);
+
+                self.patch_jump(short_circuit_jump);
+                self.emit(OpCode::LoadBoolFalse, vec![], span);
+                self.patch_jump(end_jump);
+            }
+            TokenType::Or => {
+                self.emit(OpCode::LoadBoolTrue, vec![], span);
+                let end_jump = self.emit_jump(OpCode::Jump, span);
+
+                self.patch_jump(short_circuit_jump);
+                self.compile_expression(right)?;
+                self.patch_jump(end_jump);
+            }
+            _ => unreachable!("Only 'and' and 'or' are logical operators"),
+        }
+
+        Some(())
+    }
+
+    pub(crate) fn compile_while_statement(
+        &mut self,
+        condition: Expression,
+        body: Vec<Statement>,
+        label: Option<String>,
+        span: Span,
+    ) -> Option<()> {
+        let loop_start = self.instructions.len();
+
+        self.compile_expression(condition)?;
+        let exit_jump = self.emit_jump(OpCode::JumpIfFalse, span);
+
+        self.loops.push(LoopContext {
+            label,
+            local_count: self.symbol_table.local_count(),
+            continue_target: Some(loop_start),
+            break_jumps: Vec::new(),
+            continue_jumps: Vec::new(),
+        });
+        let body_result = self.compile_block(body, span);
+        let loop_context = self.loops.pop().expect("Loop context was just pushed");
+        body_result?;
+
+        self.emit_loop(loop_start, span);
+        self.patch_jump(exit_jump);
+
+        for break_jump in loop_context.break_jumps {
+            self.patch_jump(break_jump);
+        }
+
+        Some(())
+    }
+
+    /// Lower `for i in start..end step n` to a counter loop. The range
+    /// state lives in hidden stack slots, so nothing is heap allocated.
+    /// Every iteration binds `i` to a fresh copy of the counter, so closures
+    /// created in the body keep the value of their own iteration
+    pub(crate) fn compile_for_statement(&mut self, for_loop: Stmt, span: Span) -> Option<()> {
+        let Stmt::For {
+            variable,
+            start,
+            end,
+            inclusive,
+            step,
+            body,
+            label,
+            ..
+        } = for_loop
+        else {
+            unreachable!("Only for loops are compiled here")
+        };
+
+        let var_name = match variable.node {
+            Expr::Identifier(name) => name,
+            _ => unreachable!("Loop variables are always identifiers"),
+        };
+
+        // The bounds and the step all have the type of the loop variable
+        let range_type = self.get_expr_type(&start);
+        let one_idx = self.add_constant(Self::integer_constant(1, &range_type));
+
+        // Evaluate the bounds before the loop variable comes into scope
+        self.compile_expression(*start)?;
+        self.compile_expression(*end)?;
+        match step {
+            Some(step) => {
+                let step_span = step.span;
+                self.compile_expression(*step)?;
+                // A step that never advances would loop forever
+                let check_opcode = Self::range_step_opcode(&range_type);
+                self.emit(check_opcode, vec![], step_span);
+            }
+            None => {
+                self.emit(OpCode::LoadConstant, vec![one_idx], span);
+            }
+        }
+
+        self.symbol_table.begin_scope();
+        // Hidden locals use names that can never be written in source
+        let counter_slot = self
+            .symbol_table
+            .define_local("<range counter>".to_string(), range_type.clone());
+        let end_slot = self
+            .symbol_table
+            .define_local("<range end>".to_string(), range_type.clone());
+        let step_slot = self
+            .symbol_table
+            .define_local("<range step>".to_string(), range_type.clone());
+
+        // Skip the loop entirely if the range is empty
+        let compare_operator = if inclusive {
+            TokenType::LessThanEqual
+        } else {
+            TokenType::LessThan
+        };
+        let compare_opcode = Self::integer_opcode(compare_operator, &range_type);
+        self.emit(OpCode::LoadLocal, vec![counter_slot], span);
+        self.emit(OpCode::LoadLocal, vec![end_slot], span);
+        self.emit(compare_opcode, vec![], span);
+        let empty_jump = self.emit_jump(OpCode::JumpIfFalse, span);
+
+        // From here on the end is the last value the counter may take.
+        // The range is not empty, so this cannot overflow
+        if !inclusive {
+            self.emit(OpCode::LoadLocal, vec![end_slot], span);
+            self.emit(OpCode::LoadConstant, vec![one_idx], span);
+            let subtract_opcode = Self::integer_opcode(TokenType::Minus, &range_type);
+            self.emit(subtract_opcode, vec![], span);
+            self.emit(OpCode::StoreLocal, vec![end_slot], span);
+            self.emit(OpCode::Pop, vec![], span);
+        }
+
+        let loop_start = self.instructions.len();
+
+        // `break` and `continue` discard the copy along with the body's locals
+        self.loops.push(LoopContext {
+            label,
+            local_count: self.symbol_table.local_count(),
+            continue_target: None,
+            break_jumps: Vec::new(),
+            continue_jumps: Vec::new(),
+        });
+
+        self.symbol_table.begin_scope();
+        self.emit(OpCode::LoadLocal, vec![counter_slot], span);
+        self.symbol_table.define_local(var_name, range_type.clone());
+        let body_result = self.compile_block(body, span);
+        self.end_scope(span);
+
+        let loop_context = self.loops.pop().expect("Loop context was just pushed");
+        body_result?;
+
+        // `continue` lands on the increment
+        for continue_jump in loop_context.continue_jumps {
+            self.patch_jump(continue_jump);
+        }
+
+        // Stop once another step would pass the end, without ever computing
+        // a counter past it. The distance saturates instead of overflowing,
+        // a saturated distance is still at least the step
+        self.emit(OpCode::LoadLocal, vec![end_slot], span);
+        self.emit(OpCode::LoadLocal, vec![counter_slot], span);
+        let distance_opcode = Self::integer_method_opcode("saturating_sub", &range_type);
+        self.emit(distance_opcode, vec![], span);
+        self.emit(OpCode::LoadLocal, vec![step_slot], span);
+        let exit_compare_opcode = Self::integer_opcode(TokenType::GreaterThanEqual, &range_type);
+        self.emit(exit_compare_opcode, vec![], span);
+        let exit_jump = self.emit_jump(OpCode::JumpIfFalse, span);
+
+        self.emit(OpCode::LoadLocal, vec![counter_slot], span);
+        self.emit(OpCode::LoadLocal, vec![step_slot], span);
+        let add_opcode = Self::integer_opcode(TokenType::Plus, &range_type);
+        self.emit(add_opcode, vec![], span);
+        self.emit(OpCode::StoreLocal, vec![counter_slot], span);
+        self.emit(OpCode::Pop, vec![], span);
+        self.emit_loop(loop_start, span);
+
+        self.patch_jump(exit_jump);
+        self.patch_jump(empty_jump);
+        for break_jump in loop_context.break_jumps {
+            self.patch_jump(break_jump);
+        }
+
+        self.end_scope(span);
+
+        Some(())
+    }
+
+    pub(crate) fn compile_break(&mut self, label: Option<String>, span: Span) {
+        self.discard_loop_locals(&label, span);
+
+        let jump = self.emit_jump(OpCode::Jump, span);
+        self.find_loop(&label).break_jumps.push(jump);
+    }
+
+    pub(crate) fn compile_continue(&mut self, label: Option<String>, span: Span) {
+        self.discard_loop_locals(&label, span);
+
+        match self.find_loop(&label).continue_target {
+            Some(target) => {
+                self.emit_loop(target, span);
+            }
+            None => {
+                let jump = self.emit_jump(OpCode::Jump, span);
+                self.find_loop(&label).continue_jumps.push(jump);
+            }
+        }
+    }
+
+    /// Pop the locals declared inside the target loop before jumping out of it
+    fn discard_loop_locals(&mut self, label: &Option<String>, span: Span) {
+        let loop_local_count = self.find_loop(label).local_count;
+        let discarded = self.symbol_table.locals_above(loop_local_count);
+
+        self.emit_discard_locals(&discarded, span);
+    }
+
+    /// Find the innermost loop, or the loop carrying `label`
+    fn find_loop(&mut self, label: &Option<String>) -> &mut LoopContext {
+        self.loops
+            .iter_mut()
+            .rev()
+            .find(|l| label.is_none() || l.label == *label)
+            .expect("Loop control outside of a loop should be caught in type checker")
+    }
+
+    /// Compile statements in their own scope, popping the locals they declare
+    pub(crate) fn compile_block(&mut self, statements: Vec<Statement>, span: Span) -> Option<()> {
+        self.symbol_table.begin_scope();
+
+        let result = statements
+            .into_iter()
+            .try_for_each(|stmt| self.try_compile_statement(stmt));
+
+        self.end_scope(span);
+        result
+    }
+
+    /// Close the current scope and pop its locals off the stack
+    pub(crate) fn end_scope(&mut self, span: Span) {
+        let popped = self.symbol_table.end_scope();
+        self.emit_discard_locals(&popped, span);
+    }
+
+    /// Pop locals off the stack, moving captured ones into their upvalue first
+    pub(crate) fn emit_discard_locals(&mut self, captured: &[bool], span: Span) {
+        for &is_captured in captured {
+            let opcode = if is_captured {
+                OpCode::CloseUpvalue
+            } else {
+                OpCode::Pop
+            };
+            self.emit(opcode, vec![], span);
+        }
+    }
+
+    /// Emit a jump with a placeholder target, returning its position for patching
+    pub(crate) fn emit_jump(&mut self, opcode: OpCode, span: Span) -> usize {
+        self.emit(opcode, vec![UNPATCHED_JUMP], span)
+    }
+
+    /// Jump back to `target`, which is already known
+    pub(crate) fn emit_loop(&mut self, target: usize, span: Span) {
+        let target = self.check_jump_target(target, span);
+        self.emit(OpCode::Loop, vec![target], span);
+    }
+
+    /// Point a previously emitted jump at the current end of the instructions
+    pub(crate) fn patch_jump(&mut self, position: usize) {
+        let span = self.debug_info.get_span(position);
+        let target = self.check_jump_target(self.instructions.len(), span);
+        write_uint16(&mut self.instructions, position + 1, target as u16);
+    }
+
+    /// Jump operands are absolute 16-bit offsets, anything past that
+    /// would be truncated into a jump to the wrong instruction
+    fn check_jump_target(&mut self, target: usize, span: Span) -> usize {
+        if target <= u16::MAX as usize {
+            return target;
+        }
+
+        // Every later jump overflows as well, one error is enough
+        if !self.jump_too_far {
+            self.jump_too_far = true;
+            self.throw_error(HydorError::JumpTooFar { target, span });
+        }
+        0
+    }
+}
@@ -1,7 +1,7 @@
 use colored::*;
 
 use crate::{
-    bytecode::bytecode::{Instructions, OpCode, ToOpcode, read_uint16},
+    bytecode::bytecode::{Instructions, OpCode, ToOpcode, read_uint8, read_uint16},
     compiler::compiler::Bytecode,
     runtime_value::RuntimeValue,
 };
@@ -48,11 +48,22 @@ fn disassemble_instructions(
                 }
 
                 match width {
+                    // Jump targets are printed as instruction offsets
+                    2 if i == 0 && opcode.is_jump() => {
+                        let target = read_uint16(instructions, offset);
+                        print!("{}", format!("-> {:04}", target).cyan());
+                        offset += 2;
+                    }
                     2 => {
                         let operand = read_uint16(instructions, offset);
                         print!("{}", format!("{:#04x}", operand).white());
                         offset += 2;
                     }
+                    1 => {
+                        let operand = read_uint8(instructions, offset);
+                        print!("{}", format!("{:#04x}", operand).white());
+                        offset += 1;
+                    }
                     _ => unreachable!(),
                 }
             }
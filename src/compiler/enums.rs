@@ -0,0 +1,37 @@
+use crate::{
+    ast::{ast::Expression, type_annotation::TypeAnnotation},
+    bytecode::bytecode::OpCode,
+    compiler::compiler::Compiler,
+    type_checker::type_checker::Type,
+    utils::Span,
+};
+
+impl Compiler {
+    /// Payload values are evaluated left to right, then wrapped with the variant's tag
+    pub(crate) fn compile_enum_variant(
+        &mut self,
+        enum_name: String,
+        variant: String,
+        arguments: Vec<Expression>,
+        span: Span,
+    ) -> Option<()> {
+        let (tag, _) = self.enums[&enum_name]
+            .variant(&variant)
+            .expect("Unknown variants should be caught in type checker");
+
+        let count = arguments.len();
+        for argument in arguments {
+            self.compile_expression(argument)?;
+        }
+
+        let name_index = self.intern_string(format!("{}::{}", enum_name, variant));
+        self.emit(OpCode::BuildVariant, vec![name_index, tag, count], span);
+        Some(())
+    }
+
+    /// Type an annotation refers to, with enum names and type parameters
+    /// told apart from struct names
+    pub(crate) fn resolve_annotation(&self, an_type: &TypeAnnotation) -> Type {
+        Type::from_anotated_type(an_type).resolve_names(&self.enums, &self.type_parameters)
+    }
+}
@@ -0,0 +1,189 @@
+use std::mem;
+
+use crate::{
+    ast::{
+        ast::{Expr, Expression, Parameter, Statement, Stmt},
+        type_annotation::TypeAnnotation,
+    },
+    bytecode::bytecode::OpCode,
+    compiler::compiler::Compiler,
+    runtime_value::RuntimeValue,
+    type_checker::type_checker::Type,
+    utils::Span,
+};
+
+impl Compiler {
+    /// Top-level functions are bound to a global, nested ones to a local.
+    /// Generic functions are compiled once, their type parameters are erased
+    pub(crate) fn compile_function_declaration(
+        &mut self,
+        identifier: Expression,
+        type_params: Vec<String>,
+        parameters: Vec<Parameter>,
+        return_type: Option<TypeAnnotation>,
+        body: Vec<Statement>,
+        span: Span,
+    ) -> Option<()> {
+        let name = match identifier.node {
+            Expr::Identifier(name) => name,
+            _ => unreachable!("Function names are always identifiers"),
+        };
+
+        let enclosing = self.type_parameters.len();
+        self.type_parameters.extend(type_params.iter().cloned());
+
+        let result = if self.symbol_table.is_top_level() {
+            let slot = self
+                .symbol_table
+                .global_slot(&name)
+                .expect("Top-level functions are defined before compiling any statement");
+            let compiled = self.compile_function(name, parameters, return_type, body, span);
+            if compiled.is_some() {
+                self.emit(OpCode::DefineGlobal, vec![slot], span);
+            }
+            compiled
+        } else {
+            // Defined before the body so the function can call itself.
+            // The closure lands in the local's stack slot, same as a 'let'
+            let function_type = Type::generic(
+                &type_params,
+                self.resolve_signature(&parameters, return_type.as_ref()),
+            );
+            self.symbol_table.define_local(name.clone(), function_type);
+            self.compile_function(name, parameters, return_type, body, span)
+        };
+
+        self.type_parameters.truncate(enclosing);
+        result
+    }
+
+    /// Give every top-level function its global slot up front, so functions
+    /// can refer to the ones declared after them
+    pub(crate) fn define_top_level_functions(&mut self, statements: &[Statement]) {
+        for stmt in statements {
+            let Stmt::FunctionDeclaration {
+                identifier,
+                type_params,
+                parameters,
+                return_type,
+                ..
+            } = &stmt.node
+            else {
+                continue;
+            };
+
+            let name = match &identifier.node {
+                Expr::Identifier(name) => name.clone(),
+                _ => unreachable!("Function names are always identifiers"),
+            };
+
+            let enclosing = self.type_parameters.len();
+            self.type_parameters.extend(type_params.iter().cloned());
+            let function_type = Type::generic(
+                type_params,
+                self.resolve_signature(parameters, return_type.as_ref()),
+            );
+            self.type_parameters.truncate(enclosing);
+
+            self.symbol_table.define_global(name, function_type);
+        }
+    }
+
+    fn resolve_signature(
+        &self,
+        parameters: &[Parameter],
+        return_type: Option<&TypeAnnotation>,
+    ) -> Type {
+        Type::from_signature(parameters, return_type)
+            .resolve_names(&self.enums, &self.type_parameters)
+    }
+
+    /// Functions are compiled inline, behind a jump that skips over the body.
+    /// Leaves a closure over the captured variables on the stack
+    pub(crate) fn compile_function(
+        &mut self,
+        name: String,
+        parameters: Vec<Parameter>,
+        return_type: Option<TypeAnnotation>,
+        body: Vec<Statement>,
+        span: Span,
+    ) -> Option<()> {
+        let function_type = self.resolve_signature(&parameters, return_type.as_ref());
+
+        let skip_body_jump = self.emit_jump(OpCode::Jump, span);
+        let entry = self.instructions.len();
+
+        self.symbol_table.begin_function(function_type);
+        for param in &parameters {
+            self.symbol_table.define_local(
+                param.name.clone(),
+                self.resolve_annotation(&param.annotated_type),
+            );
+        }
+
+        // Loops outside the function cannot be targeted from inside of it
+        let enclosing_loops = mem::take(&mut self.loops);
+        let body_result = body
+            .into_iter()
+            .try_for_each(|stmt| self.try_compile_statement(stmt));
+        self.loops = enclosing_loops;
+
+        // Falling off the end of the body returns nil
+        self.emit(OpCode::LoadNil, vec![], span);
+        self.emit(OpCode::Return, vec![], span);
+
+        let upvalues = self.symbol_table.end_function();
+        body_result?;
+
+        self.patch_jump(skip_body_jump);
+
+        let function = RuntimeValue::Function {
+            name: self.intern_string(name),
+            arity: parameters.len(),
+            entry,
+        };
+        let const_idx = self.add_constant(function);
+        self.emit(OpCode::Closure, vec![const_idx], span);
+
+        for upvalue in upvalues {
+            let opcode = if upvalue.is_local {
+                OpCode::CaptureLocal
+            } else {
+                OpCode::CaptureUpvalue
+            };
+            self.emit(opcode, vec![upvalue.index], span);
+        }
+
+        Some(())
+    }
+
+    pub(crate) fn compile_return(&mut self, value: Option<Expression>, span: Span) -> Option<()> {
+        match value {
+            Some(value) => self.compile_expression(value)?,
+            None => {
+                self.emit(OpCode::LoadNil, vec![], span);
+            }
+        }
+
+        // The VM discards the whole frame, so locals need no explicit pops
+        self.emit(OpCode::Return, vec![], span);
+        Some(())
+    }
+
+    pub(crate) fn compile_call(
+        &mut self,
+        callee: Expression,
+        arguments: Vec<Expression>,
+        span: Span,
+    ) -> Option<()> {
+        let argc = arguments.len();
+
+        self.compile_expression(callee)?;
+        for argument in arguments {
+            self.compile_expression(argument)?;
+        }
+
+        self.emit(OpCode::Call, vec![argc], span);
+        Some(())
+    }
+}
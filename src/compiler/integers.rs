@@ -0,0 +1,158 @@
+use crate::{
+    bytecode::bytecode::OpCode, compiler::compiler::Compiler, runtime_value::RuntimeValue,
+    tokens::TokenType, type_checker::type_checker::Type, utils::Span,
+};
+
+impl Compiler {
+    /// Integer literals are `Int` unless the type checker recorded another width
+    pub(crate) fn integer_literal_type(&self, span: Span) -> Type {
+        match self.expr_types.get(&span) {
+            Some(integer_type) if integer_type.is_integer() => integer_type.clone(),
+            _ => Type::Integer,
+        }
+    }
+
+    /// `value` as a constant of `integer_type`
+    pub(crate) fn integer_constant(value: i64, integer_type: &Type) -> RuntimeValue {
+        match integer_type {
+            Type::Int64 => RuntimeValue::Int64Literal(value),
+            Type::Byte => RuntimeValue::ByteLiteral(value as u8),
+            _ => RuntimeValue::IntegerLiteral(value as i32),
+        }
+    }
+
+    /// The opcode that checks the step of a range loop over `integer_type`
+    pub(crate) fn range_step_opcode(integer_type: &Type) -> OpCode {
+        Self::by_width(
+            integer_type,
+            OpCode::CheckRangeStepInt,
+            OpCode::CheckRangeStepInt64,
+            OpCode::CheckRangeStepByte,
+        )
+    }
+
+    /// The typed opcode of a binary operator on two integers of `integer_type`
+    pub(crate) fn integer_opcode(operator: TokenType, integer_type: &Type) -> OpCode {
+        let [int, int64, byte] = match operator {
+            TokenType::Plus => [OpCode::AddInt, OpCode::AddInt64, OpCode::AddByte],
+            TokenType::Minus => [
+                OpCode::SubtractInt,
+                OpCode::SubtractInt64,
+                OpCode::SubtractByte,
+            ],
+            TokenType::Asterisk => [
+                OpCode::MultiplyInt,
+                OpCode::MultiplyInt64,
+                OpCode::MultiplyByte,
+            ],
+            TokenType::Slash => [OpCode::DivideInt, OpCode::DivideInt64, OpCode::DivideByte],
+            TokenType::Caret => [
+                OpCode::ExponentInt,
+                OpCode::ExponentInt64,
+                OpCode::ExponentByte,
+            ],
+            TokenType::Percent => [OpCode::ModuloInt, OpCode::ModuloInt64, OpCode::ModuloByte],
+            TokenType::Div => [
+                OpCode::FloorDivideInt,
+                OpCode::FloorDivideInt64,
+                OpCode::FloorDivideByte,
+            ],
+            TokenType::Ampersand => [
+                OpCode::BitwiseAndInt,
+                OpCode::BitwiseAndInt64,
+                OpCode::BitwiseAndByte,
+            ],
+            TokenType::Pipe => [
+                OpCode::BitwiseOrInt,
+                OpCode::BitwiseOrInt64,
+                OpCode::BitwiseOrByte,
+            ],
+            TokenType::Xor => [
+                OpCode::BitwiseXorInt,
+                OpCode::BitwiseXorInt64,
+                OpCode::BitwiseXorByte,
+            ],
+            TokenType::ShiftLeft => [
+                OpCode::ShiftLeftInt,
+                OpCode::ShiftLeftInt64,
+                OpCode::ShiftLeftByte,
+            ],
+            TokenType::ShiftRight => [
+                OpCode::ShiftRightInt,
+                OpCode::ShiftRightInt64,
+                OpCode::ShiftRightByte,
+            ],
+            TokenType::LessThan => [
+                OpCode::CompareLessInt,
+                OpCode::CompareLessInt64,
+                OpCode::CompareLessByte,
+            ],
+            TokenType::LessThanEqual => [
+                OpCode::CompareLessEqualInt,
+                OpCode::CompareLessEqualInt64,
+                OpCode::CompareLessEqualByte,
+            ],
+            TokenType::GreaterThan => [
+                OpCode::CompareGreaterInt,
+                OpCode::CompareGreaterInt64,
+                OpCode::CompareGreaterByte,
+            ],
+            TokenType::GreaterThanEqual => [
+                OpCode::CompareGreaterEqualInt,
+                OpCode::CompareGreaterEqualInt64,
+                OpCode::CompareGreaterEqualByte,
+            ],
+            _ => unreachable!("Unhandled binary operator type"),
+        };
+
+        Self::by_width(integer_type, int, int64, byte)
+    }
+
+    /// The typed opcode of the built in method `method` on integers
+    pub(crate) fn integer_method_opcode(method: &str, integer_type: &Type) -> OpCode {
+        let [int, int64, byte] = match method {
+            "wrapping_add" => [
+                OpCode::WrappingAddInt,
+                OpCode::WrappingAddInt64,
+                OpCode::WrappingAddByte,
+            ],
+            "wrapping_sub" => [
+                OpCode::WrappingSubtractInt,
+                OpCode::WrappingSubtractInt64,
+                OpCode::WrappingSubtractByte,
+            ],
+            "wrapping_mul" => [
+                OpCode::WrappingMultiplyInt,
+                OpCode::WrappingMultiplyInt64,
+                OpCode::WrappingMultiplyByte,
+            ],
+            "saturating_add" => [
+                OpCode::SaturatingAddInt,
+                OpCode::SaturatingAddInt64,
+                OpCode::SaturatingAddByte,
+            ],
+            "saturating_sub" => [
+                OpCode::SaturatingSubtractInt,
+                OpCode::SaturatingSubtractInt64,
+                OpCode::SaturatingSubtractByte,
+            ],
+            "saturating_mul" => [
+                OpCode::SaturatingMultiplyInt,
+                OpCode::SaturatingMultiplyInt64,
+                OpCode::SaturatingMultiplyByte,
+            ],
+            _ => unreachable!("Unknown methods should be caught in type checker"),
+        };
+
+        Self::by_width(integer_type, int, int64, byte)
+    }
+
+    fn by_width(integer_type: &Type, int: OpCode, int64: OpCode, byte: OpCode) -> OpCode {
+        match integer_type {
+            Type::Integer => int,
+            Type::Int64 => int64,
+            Type::Byte => byte,
+            _ => unreachable!("Type mismatch should be caught in type checker"),
+        }
+    }
+}
@@ -1,3 +1,15 @@
+pub mod assignments;
+pub mod casts;
+pub mod collections;
 pub mod compiler;
+pub mod control_flow;
 pub mod disassembler;
+pub mod enums;
+pub mod functions;
+pub mod integers;
+pub mod optionals;
+pub mod patterns;
 pub mod serializer;
+pub mod structs;
+pub mod symbol_table;
+pub mod tuples;
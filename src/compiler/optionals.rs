@@ -0,0 +1,64 @@
+use crate::{
+    ast::ast::{ChainAccess, Expression},
+    bytecode::bytecode::OpCode,
+    compiler::compiler::Compiler,
+    type_checker::type_checker::Type,
+    utils::Span,
+};
+
+impl Compiler {
+    /// `value ?? default` keeps the value unless it is nil, in which case
+    /// it is dropped and the default is evaluated in its place
+    pub(crate) fn compile_nil_coalescing(
+        &mut self,
+        value: Expression,
+        default: Expression,
+        span: Span,
+    ) -> Option<()> {
+        let temporaries = self.temporaries;
+
+        self.compile_expression(value)?;
+        let nil_jump = self.emit_jump(OpCode::JumpIfNil, span);
+        let end_jump = self.emit_jump(OpCode::Jump, span);
+
+        self.patch_jump(nil_jump);
+        self.emit(OpCode::Pop, vec![], span);
+        self.temporaries = temporaries;
+        self.compile_expression(default)?;
+        self.patch_jump(end_jump);
+
+        Some(())
+    }
+
+    /// A nil object is left on the stack as the result, so the access is skipped
+    pub(crate) fn compile_optional_chain(
+        &mut self,
+        object: Expression,
+        access: ChainAccess,
+        span: Span,
+    ) -> Option<()> {
+        let inner_type = match self.get_expr_type(&object) {
+            Type::Optional(inner) => *inner,
+            _ => unreachable!("Chaining on non-optionals should be caught in type checker"),
+        };
+
+        self.compile_expression(object)?;
+        let nil_jump = self.emit_jump(OpCode::JumpIfNil, span);
+
+        match access {
+            ChainAccess::Field(field) => {
+                let (index, _) = self.struct_field(&inner_type, &field);
+                self.emit(OpCode::GetField, vec![index], span);
+            }
+            ChainAccess::Element(index) => {
+                self.emit(OpCode::GetElement, vec![index], span);
+            }
+            ChainAccess::Method { name, arguments } => {
+                self.compile_method_arguments(&inner_type, &name, arguments, span)?
+            }
+        }
+
+        self.patch_jump(nil_jump);
+        Some(())
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{
+    ast::ast::{Expression, MatchArm, Pat, Pattern},
+    bytecode::bytecode::OpCode,
+    compiler::compiler::Compiler,
+    tokens::TokenType,
+    type_checker::type_checker::Type,
+    utils::Span,
+};
+
+/// One step from the match subject down to the value a nested pattern looks at
+#[derive(Debug, Clone, Copy)]
+enum Access {
+    Field(usize),
+    Payload(usize),
+}
+
+impl Compiler {
+    /// The subject lives in a hidden local while the arms are tried in order.
+    /// A failed test jumps to the next arm, and the body of the arm that
+    /// matches overwrites the subject's slot with the result
+    pub(crate) fn compile_match(
+        &mut self,
+        subject: Expression,
+        arms: Vec<MatchArm>,
+        span: Span,
+    ) -> Option<()> {
+        let subject_type = self.get_expr_type(&subject);
+        let temporaries = self.temporaries;
+        self.compile_expression(subject)?;
+
+        // Whatever the enclosing expression pushed sits below the subject,
+        // so it has to be accounted for before the subject gets a slot
+        self.symbol_table.begin_scope();
+        for _ in 0..temporaries {
+            self.symbol_table
+                .define_local("<temporary>".to_string(), Type::Nil);
+        }
+        let subject_slot = self
+            .symbol_table
+            .define_local("<match subject>".to_string(), subject_type.clone());
+
+        let mut end_jumps = Vec::new();
+
+        for arm in arms {
+            let mut fail_jumps = Vec::new();
+            self.compile_pattern_tests(
+                &arm.pattern,
+                &subject_type,
+                subject_slot,
+                &mut Vec::new(),
+                &mut fail_jumps,
+            )?;
+
+            self.symbol_table.begin_scope();
+            self.compile_pattern_bindings(
+                &arm.pattern,
+                &subject_type,
+                subject_slot,
+                &mut Vec::new(),
+            );
+
+            let guard_jump = match arm.guard {
+                Some(guard) => {
+                    self.temporaries = 0;
+                    self.compile_expression(guard)?;
+                    Some(self.emit_jump(OpCode::JumpIfFalse, span))
+                }
+                None => None,
+            };
+
+            self.temporaries = 0;
+            self.compile_expression(arm.body)?;
+            self.emit(OpCode::StoreLocal, vec![subject_slot], span);
+            self.emit(OpCode::Pop, vec![], span);
+
+            let bindings = self.symbol_table.end_scope();
+            self.emit_discard_locals(&bindings, span);
+            end_jumps.push(self.emit_jump(OpCode::Jump, span));
+
+            // A failed guard still has to drop the bindings it was given
+            if let Some(guard_jump) = guard_jump {
+                self.patch_jump(guard_jump);
+                self.emit_discard_locals(&bindings, span);
+                fail_jumps.push(self.emit_jump(OpCode::Jump, span));
+            }
+
+            for jump in fail_jumps {
+                self.patch_jump(jump);
+            }
+        }
+
+        for jump in end_jumps {
+            self.patch_jump(jump);
+        }
+
+        // The subject's slot now holds the result, which stays on the stack
+        self.symbol_table.end_scope();
+        Some(())
+    }
+
+    /// Emit the checks `pattern` makes, each jumping away when it fails
+    fn compile_pattern_tests(
+        &mut self,
+        pattern: &Pattern,
+        expected: &Type,
+        subject_slot: usize,
+        path: &mut Vec<Access>,
+        fail_jumps: &mut Vec<usize>,
+    ) -> Option<()> {
+        let span = pattern.span;
+
+        match &pattern.node {
+            Pat::Wildcard | Pat::Binding(_) => {}
+
+            Pat::Literal(literal) => {
+                self.emit_path_load(subject_slot, path, span);
+                self.compile_expression(literal.clone().spanned(span))?;
+                self.emit(OpCode::CompareEqual, vec![], span);
+                fail_jumps.push(self.emit_jump(OpCode::JumpIfFalse, span));
+            }
+
+            Pat::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                let (lower, upper) = match (expected, inclusive) {
+                    (integer_type, _) if integer_type.is_integer() => {
+                        let upper = if *inclusive {
+                            TokenType::LessThanEqual
+                        } else {
+                            TokenType::LessThan
+                        };
+                        (
+                            Self::integer_opcode(TokenType::GreaterThanEqual, integer_type),
+                            Self::integer_opcode(upper, integer_type),
+                        )
+                    }
+                    (Type::Float, true) => (
+                        OpCode::CompareGreaterEqualFloat,
+                        OpCode::CompareLessEqualFloat,
+                    ),
+                    (Type::Float, false) => {
+                        (OpCode::CompareGreaterEqualFloat, OpCode::CompareLessFloat)
+                    }
+                    _ => unreachable!("Ranges on other types should be caught in type checker"),
+                };
+
+                for (bound, opcode) in [(start, lower), (end, upper)] {
+                    self.emit_path_load(subject_slot, path, span);
+                    self.compile_expression(bound.clone().spanned(span))?;
+                    self.emit(opcode, vec![], span);
+                    fail_jumps.push(self.emit_jump(OpCode::JumpIfFalse, span));
+                }
+            }
+
+            Pat::Struct { fields, .. } => {
+                for field in fields {
+                    let (index, field_type) = self.struct_field(expected, &field.name);
+
+                    path.push(Access::Field(index));
+                    self.compile_pattern_tests(
+                        &field.pattern,
+                        &field_type,
+                        subject_slot,
+                        path,
+                        fail_jumps,
+                    )?;
+                    path.pop();
+                }
+            }
+
+            Pat::Variant {
+                enum_name,
+                variant,
+                payload,
+            } => {
+                let (tag, payload_types) = self.pattern_variant(enum_name, variant, expected);
+
+                self.emit_path_load(subject_slot, path, span);
+                self.emit(OpCode::IsVariant, vec![tag], span);
+                fail_jumps.push(self.emit_jump(OpCode::JumpIfFalse, span));
+
+                for (index, (pattern, payload_type)) in
+                    payload.iter().zip(&payload_types).enumerate()
+                {
+                    path.push(Access::Payload(index));
+                    self.compile_pattern_tests(
+                        pattern,
+                        payload_type,
+                        subject_slot,
+                        path,
+                        fail_jumps,
+                    )?;
+                    path.pop();
+                }
+            }
+        }
+
+        Some(())
+    }
+
+    /// Push the value of every binding in `pattern`, each becoming a local
+    fn compile_pattern_bindings(
+        &mut self,
+        pattern: &Pattern,
+        expected: &Type,
+        subject_slot: usize,
+        path: &mut Vec<Access>,
+    ) {
+        match &pattern.node {
+            Pat::Wildcard | Pat::Literal(_) | Pat::Range { .. } => {}
+
+            Pat::Binding(name) => {
+                self.emit_path_load(subject_slot, path, pattern.span);
+                self.symbol_table
+                    .define_local(name.clone(), expected.clone());
+            }
+
+            Pat::Struct { fields, .. } => {
+                for field in fields {
+                    let (index, field_type) = self.struct_field(expected, &field.name);
+
+                    path.push(Access::Field(index));
+                    self.compile_pattern_bindings(&field.pattern, &field_type, subject_slot, path);
+                    path.pop();
+                }
+            }
+
+            Pat::Variant {
+                enum_name,
+                variant,
+                payload,
+            } => {
+                let (_, payload_types) = self.pattern_variant(enum_name, variant, expected);
+
+                for (index, (pattern, payload_type)) in
+                    payload.iter().zip(&payload_types).enumerate()
+                {
+                    path.push(Access::Payload(index));
+                    self.compile_pattern_bindings(pattern, payload_type, subject_slot, path);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    /// Tag and payload types of `variant` in a value of type `expected`
+    fn pattern_variant(
+        &self,
+        enum_name: &str,
+        variant: &str,
+        expected: &Type,
+    ) -> (usize, Vec<Type>) {
+        let type_args = match expected {
+            Type::Enum(_, type_args) => type_args.as_slice(),
+            _ => &[],
+        };
+
+        let definition = &self.enums[enum_name];
+        definition
+            .variant(variant)
+            .map(|(tag, types)| {
+                let types = types
+                    .iter()
+                    .map(|t| t.instantiate(&definition.type_params, type_args))
+                    .collect();
+                (tag, types)
+            })
+            .expect("Unknown variants should be caught in type checker")
+    }
+
+    /// Load the subject and walk down to the part of it at `path`
+    fn emit_path_load(&mut self, subject_slot: usize, path: &[Access], span: Span) {
+        self.emit(OpCode::LoadLocal, vec![subject_slot], span);
+
+        for access in path {
+            match access {
+                Access::Field(index) => self.emit(OpCode::GetField, vec![*index], span),
+                Access::Payload(index) => self.emit(OpCode::GetPayload, vec![*index], span),
+            };
+        }
+    }
+}
@@ -5,6 +5,7 @@ use std::path::Path;
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
+use crate::bytecode::bytecode::{Instructions, OpCode, read_uint16};
 use crate::compiler::compiler::{Bytecode, DebugInfo};
 use crate::runtime_value::RuntimeValue;
 
@@ -19,6 +20,9 @@ enum ConstantType {
     Float = 1,
     Boolean = 2,
     String = 3,
+    Function = 4,
+    Int64 = 5,
+    Byte = 6,
 }
 
 impl Bytecode {
@@ -88,6 +92,7 @@ impl Bytecode {
         let instructions_len = file.read_u32::<BigEndian>()? as usize;
         let mut instructions = vec![0u8; instructions_len];
         file.read_exact(&mut instructions)?;
+        Self::verify_jump_targets(&instructions)?;
 
         // Read string table
         let string_table_len = file.read_u32::<BigEndian>()? as usize;
@@ -119,16 +124,75 @@ impl Bytecode {
         })
     }
 
+    /// Reject bytecode whose jumps point outside of the instruction stream
+    fn verify_jump_targets(instructions: &Instructions) -> io::Result<()> {
+        let mut offset = 0;
+
+        while offset < instructions.len() {
+            let opcode_byte = instructions[offset];
+            let opcode = OpCode::try_from(opcode_byte).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Unknown opcode {:#04x} at offset {}", opcode_byte, offset),
+                )
+            })?;
+            let definition = OpCode::get_definition(opcode);
+            let operands_len: usize = definition.operands_width.iter().sum();
+
+            if offset + operands_len >= instructions.len() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "Truncated '{}' instruction at offset {}",
+                        definition.name, offset
+                    ),
+                ));
+            }
+
+            if opcode.is_jump() {
+                let target = read_uint16(instructions, offset + 1) as usize;
+
+                if target > instructions.len() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "'{}' at offset {} jumps to invalid offset {}",
+                            definition.name, offset, target
+                        ),
+                    ));
+                }
+            }
+
+            offset += 1 + operands_len;
+        }
+
+        Ok(())
+    }
+
     fn write_constant(&self, file: &mut File, constant: &RuntimeValue) -> io::Result<()> {
         match constant {
             RuntimeValue::IntegerLiteral(v) => {
                 file.write_u8(ConstantType::Integer.into())?;
                 file.write_i32::<BigEndian>(*v)?;
             }
+            RuntimeValue::Int64Literal(v) => {
+                file.write_u8(ConstantType::Int64.into())?;
+                file.write_i64::<BigEndian>(*v)?;
+            }
+            RuntimeValue::ByteLiteral(v) => {
+                file.write_u8(ConstantType::Byte.into())?;
+                file.write_u8(*v)?;
+            }
             RuntimeValue::FloatLiteral(v) => {
                 file.write_u8(ConstantType::Float.into())?;
                 file.write_f64::<BigEndian>(*v)?;
             }
+            RuntimeValue::Function { name, arity, entry } => {
+                file.write_u8(ConstantType::Function.into())?;
+                file.write_u32::<BigEndian>(*name as u32)?;
+                file.write_u8(*arity as u8)?;
+                file.write_u32::<BigEndian>(*entry as u32)?;
+            }
 
             _ => unreachable!(),
         }
@@ -145,6 +209,14 @@ impl Bytecode {
                 let value = file.read_i32::<BigEndian>()?;
                 Ok(RuntimeValue::IntegerLiteral(value))
             }
+            ConstantType::Int64 => {
+                let value = file.read_i64::<BigEndian>()?;
+                Ok(RuntimeValue::Int64Literal(value))
+            }
+            ConstantType::Byte => {
+                let value = file.read_u8()?;
+                Ok(RuntimeValue::ByteLiteral(value))
+            }
             ConstantType::Float => {
                 let value = file.read_f64::<BigEndian>()?;
                 Ok(RuntimeValue::FloatLiteral(value))
@@ -157,6 +229,12 @@ impl Bytecode {
                 let idx = file.read_u32::<BigEndian>()? as usize;
                 Ok(RuntimeValue::StringLiteral(idx))
             }
+            ConstantType::Function => {
+                let name = file.read_u32::<BigEndian>()? as usize;
+                let arity = file.read_u8()? as usize;
+                let entry = file.read_u32::<BigEndian>()? as usize;
+                Ok(RuntimeValue::Function { name, arity, entry })
+            }
         }
     }
 
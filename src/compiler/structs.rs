@@ -0,0 +1,119 @@
+use crate::{
+    ast::ast::{Expression, FieldInit},
+    bytecode::bytecode::OpCode,
+    compiler::compiler::Compiler,
+    type_checker::type_checker::Type,
+    utils::Span,
+};
+
+impl Compiler {
+    /// Field values are evaluated in the order they are written, then put in
+    /// declaration order, which is the order `BuildStruct` expects them in
+    pub(crate) fn compile_struct_literal(
+        &mut self,
+        name: String,
+        fields: Vec<FieldInit>,
+        span: Span,
+    ) -> Option<()> {
+        let layout: Vec<String> = self.structs[&name]
+            .fields
+            .iter()
+            .map(|(field, _)| field.clone())
+            .collect();
+
+        // The values land in the stack slots just above the locals and
+        // whatever the enclosing expression pushed
+        let first_slot = self.symbol_table.local_count() + self.temporaries;
+
+        let in_layout_order = fields.iter().map(|init| &init.name).eq(layout.iter());
+        if in_layout_order {
+            for init in fields {
+                self.compile_expression(init.value)?;
+            }
+        } else {
+            let written_order: Vec<String> = fields.iter().map(|init| init.name.clone()).collect();
+
+            for init in fields {
+                self.compile_expression(init.value)?;
+            }
+
+            for field in &layout {
+                let position = written_order
+                    .iter()
+                    .position(|name| name == field)
+                    .expect("Missing fields should be caught in type checker");
+                self.emit(OpCode::LoadLocal, vec![first_slot + position], span);
+            }
+        }
+
+        let name_index = self.intern_string(name);
+        self.emit(OpCode::BuildStruct, vec![name_index, layout.len()], span);
+
+        if !in_layout_order {
+            // Move the struct into the first value's slot and drop the rest
+            self.emit(OpCode::StoreLocal, vec![first_slot], span);
+            for _ in 0..layout.len() {
+                self.emit(OpCode::Pop, vec![], span);
+            }
+        }
+
+        Some(())
+    }
+
+    pub(crate) fn compile_field_access(
+        &mut self,
+        object: Expression,
+        field: String,
+        span: Span,
+    ) -> Option<()> {
+        let index = self.field_index(&object, &field);
+
+        self.compile_expression(object)?;
+        self.emit(OpCode::GetField, vec![index], span);
+        Some(())
+    }
+
+    /// `object.field = value` leaves the assigned value on the stack
+    pub(crate) fn compile_field_assignment(
+        &mut self,
+        object: Expression,
+        field: String,
+        value: Expression,
+        span: Span,
+    ) -> Option<()> {
+        let index = self.field_index(&object, &field);
+
+        self.compile_expression(object)?;
+        self.compile_expression(value)?;
+        self.emit(OpCode::SetField, vec![index], span);
+        Some(())
+    }
+
+    /// Layout index and type of `field`, resolved from the static type of `object`
+    pub(crate) fn resolve_field(&self, object: &Expression, field: &str) -> (usize, Type) {
+        self.struct_field(&self.get_expr_type(object), field)
+    }
+
+    /// Layout index and type of `field` on a value of type `struct_type`
+    pub(crate) fn struct_field(&self, struct_type: &Type, field: &str) -> (usize, Type) {
+        let (name, type_args) = match struct_type {
+            Type::Struct(name, type_args) => (name, type_args),
+            _ => unreachable!("Field access on non-structs should be caught in type checker"),
+        };
+
+        let definition = &self.structs[name];
+        definition
+            .field(field)
+            .map(|(index, field_type)| {
+                (
+                    index,
+                    field_type.instantiate(&definition.type_params, type_args),
+                )
+            })
+            .expect("Unknown fields should be caught in type checker")
+    }
+
+    fn field_index(&self, object: &Expression, field: &str) -> usize {
+        self.resolve_field(object, field).0
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+
+use crate::type_checker::type_checker::Type;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SymbolScope {
+    Global,
+    Local,
+    Upvalue,
+}
+
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    pub index: usize,
+    pub symbol_type: Type,
+    pub scope: SymbolScope,
+}
+
+#[derive(Debug, Clone)]
+struct Local {
+    name: String,
+    depth: usize,
+    symbol_type: Type,
+    /// Whether a closure refers to this local, so it must be closed instead of popped
+    captured: bool,
+}
+
+/// Where a closure finds a captured variable when it is created
+#[derive(Debug, Clone)]
+pub struct Upvalue {
+    /// Stack slot in the enclosing function if `is_local`, else its upvalue index
+    pub index: usize,
+    pub is_local: bool,
+    symbol_type: Type,
+}
+
+/// Locals of a single function body, the top-level script included
+#[derive(Debug, Default)]
+struct FunctionScope {
+    locals: Vec<Local>,
+    upvalues: Vec<Upvalue>,
+    scope_depth: usize,
+}
+
+/// Resolves variable names to their runtime storage slots
+pub struct SymbolTable {
+    globals: HashMap<String, Symbol>,
+    /// The function being compiled is last, the script itself is first
+    functions: Vec<FunctionScope>,
+}
+
+impl Default for SymbolTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SymbolTable {
+    pub fn new() -> Self {
+        Self {
+            globals: HashMap::new(),
+            functions: vec![FunctionScope::default()],
+        }
+    }
+
+    /// Allocate a new global slot for `name`
+    pub fn define_global(&mut self, name: String, symbol_type: Type) -> usize {
+        let index = self.globals.len();
+
+        self.globals.insert(
+            name,
+            Symbol {
+                index,
+                symbol_type,
+                scope: SymbolScope::Global,
+            },
+        );
+        index
+    }
+
+    /// Slot of an already defined global
+    pub fn global_slot(&self, name: &str) -> Option<usize> {
+        self.globals.get(name).map(|symbol| symbol.index)
+    }
+
+    /// Claim the next stack slot for `name` in the current scope
+    pub fn define_local(&mut self, name: String, symbol_type: Type) -> usize {
+        let function = self.current_mut();
+
+        function.locals.push(Local {
+            name,
+            depth: function.scope_depth,
+            symbol_type,
+            captured: false,
+        });
+        function.locals.len() - 1
+    }
+
+    pub fn local_count(&self) -> usize {
+        self.current().locals.len()
+    }
+
+    /// Whether new variables are declared as globals
+    pub fn is_top_level(&self) -> bool {
+        self.functions.len() == 1 && self.current().scope_depth == 0
+    }
+
+    pub fn begin_scope(&mut self) {
+        self.current_mut().scope_depth += 1;
+    }
+
+    /// Close the current scope, returning whether each local that went out
+    /// of scope was captured, innermost first
+    pub fn end_scope(&mut self) -> Vec<bool> {
+        let function = self.current_mut();
+        function.scope_depth -= 1;
+
+        let remaining = function
+            .locals
+            .iter()
+            .take_while(|local| local.depth <= function.scope_depth)
+            .count();
+
+        let popped = function.locals.split_off(remaining);
+        popped.iter().rev().map(|local| local.captured).collect()
+    }
+
+    /// Whether each local above the first `count` was captured, innermost first
+    pub fn locals_above(&self, count: usize) -> Vec<bool> {
+        self.current().locals[count..]
+            .iter()
+            .rev()
+            .map(|local| local.captured)
+            .collect()
+    }
+
+    /// Start a fresh set of stack slots for a function body.
+    /// Slot 0 holds the function being called
+    pub fn begin_function(&mut self, callee_type: Type) {
+        self.functions.push(FunctionScope {
+            scope_depth: 1,
+            ..Default::default()
+        });
+        self.define_local(String::new(), callee_type);
+    }
+
+    /// Finish the current function body, returning the variables it captures
+    pub fn end_function(&mut self) -> Vec<Upvalue> {
+        assert!(
+            self.functions.len() > 1,
+            "end_function called without matching begin_function"
+        );
+
+        self.functions
+            .pop()
+            .expect("Script scope always exists")
+            .upvalues
+    }
+
+    pub fn resolve(&mut self, name: &str) -> Option<Symbol> {
+        let current = self.functions.len() - 1;
+
+        // Innermost locals win over captured variables and over globals
+        if let Some((index, local)) = Self::find_local(&self.functions[current], name) {
+            return Some(Symbol {
+                index,
+                symbol_type: local.symbol_type.clone(),
+                scope: SymbolScope::Local,
+            });
+        }
+
+        if let Some(index) = self.resolve_upvalue(current, name) {
+            return Some(Symbol {
+                index,
+                symbol_type: self.functions[current].upvalues[index].symbol_type.clone(),
+                scope: SymbolScope::Upvalue,
+            });
+        }
+
+        self.globals.get(name).cloned()
+    }
+
+    /// Type of `name` as seen from the current function, without capturing it
+    pub fn resolve_type(&self, name: &str) -> Option<Type> {
+        self.functions
+            .iter()
+            .rev()
+            .find_map(|function| Self::find_local(function, name))
+            .map(|(_, local)| local.symbol_type.clone())
+            .or_else(|| self.globals.get(name).map(|s| s.symbol_type.clone()))
+    }
+
+    /// Capture `name` from the functions enclosing `function`, threading
+    /// the upvalue through every function in between
+    fn resolve_upvalue(&mut self, function: usize, name: &str) -> Option<usize> {
+        if function == 0 {
+            return None;
+        }
+
+        let enclosing = function - 1;
+
+        if let Some((slot, _)) = Self::find_local(&self.functions[enclosing], name) {
+            let local = &mut self.functions[enclosing].locals[slot];
+            local.captured = true;
+
+            let symbol_type = local.symbol_type.clone();
+            return Some(self.add_upvalue(function, slot, true, symbol_type));
+        }
+
+        let index = self.resolve_upvalue(enclosing, name)?;
+        let symbol_type = self.functions[enclosing].upvalues[index]
+            .symbol_type
+            .clone();
+        Some(self.add_upvalue(function, index, false, symbol_type))
+    }
+
+    fn add_upvalue(
+        &mut self,
+        function: usize,
+        index: usize,
+        is_local: bool,
+        symbol_type: Type,
+    ) -> usize {
+        let upvalues = &mut self.functions[function].upvalues;
+
+        // Capturing the same variable twice shares a single upvalue
+        if let Some(existing) = upvalues
+            .iter()
+            .position(|u| u.index == index && u.is_local == is_local)
+        {
+            return existing;
+        }
+
+        upvalues.push(Upvalue {
+            index,
+            is_local,
+            symbol_type,
+        });
+        upvalues.len() - 1
+    }
+
+    fn find_local<'a>(function: &'a FunctionScope, name: &str) -> Option<(usize, &'a Local)> {
+        function
+            .locals
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, local)| local.name == name)
+    }
+
+    fn current(&self) -> &FunctionScope {
+        self.functions.last().expect("Script scope always exists")
+    }
+
+    fn current_mut(&mut self) -> &mut FunctionScope {
+        self.functions
+            .last_mut()
+            .expect("Script scope always exists")
+    }
+}
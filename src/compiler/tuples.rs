@@ -0,0 +1,77 @@
+use crate::{
+    ast::ast::{Expr, Expression},
+    bytecode::bytecode::OpCode,
+    compiler::compiler::Compiler,
+    type_checker::type_checker::Type,
+    utils::Span,
+};
+
+impl Compiler {
+    pub(crate) fn compile_tuple_literal(
+        &mut self,
+        elements: Vec<Expression>,
+        span: Span,
+    ) -> Option<()> {
+        let count = elements.len();
+
+        for element in elements {
+            self.compile_expression(element)?;
+        }
+
+        self.emit(OpCode::BuildTuple, vec![count], span);
+        Some(())
+    }
+
+    pub(crate) fn compile_tuple_access(
+        &mut self,
+        object: Expression,
+        index: usize,
+        span: Span,
+    ) -> Option<()> {
+        self.compile_expression(object)?;
+        self.emit(OpCode::GetElement, vec![index], span);
+        Some(())
+    }
+
+    /// `UnpackTuple` leaves the first element deepest, so locals line up
+    /// with their stack slots and globals are defined from the last name back
+    pub(crate) fn compile_tuple_destructuring(
+        &mut self,
+        identifiers: Vec<Expression>,
+        value: Expression,
+        span: Span,
+    ) -> Option<()> {
+        let element_types = match self.get_expr_type(&value) {
+            Type::Tuple(elements) => elements,
+            _ => unreachable!("Destructuring non-tuples should be caught in type checker"),
+        };
+
+        self.compile_expression(value)?;
+        self.emit(OpCode::UnpackTuple, vec![identifiers.len()], span);
+
+        let names = identifiers
+            .into_iter()
+            .map(|identifier| match identifier.node {
+                Expr::Identifier(name) => name,
+                _ => unreachable!("Destructured names are always identifiers"),
+            });
+        let bindings: Vec<(String, Type)> = names.zip(element_types).collect();
+
+        if self.symbol_table.is_top_level() {
+            let slots: Vec<usize> = bindings
+                .into_iter()
+                .map(|(name, element_type)| self.symbol_table.define_global(name, element_type))
+                .collect();
+
+            for slot in slots.into_iter().rev() {
+                self.emit(OpCode::DefineGlobal, vec![slot], span);
+            }
+        } else {
+            for (name, element_type) in bindings {
+                self.symbol_table.define_local(name, element_type);
+            }
+        }
+
+        Some(())
+    }
+}
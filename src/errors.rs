@@ -20,8 +20,17 @@ pub enum HydorError {
         got: TokenType,
         span: Span,
     },
-    InvalidTypeName {
-        got: String,
+    InvalidAssignmentTarget {
+        span: Span,
+    },
+    InvalidEnumPath {
+        span: Span,
+    },
+    InvalidPattern {
+        token: TokenType,
+        span: Span,
+    },
+    IntegerLiteralTooLarge {
         span: Span,
     },
 
@@ -41,6 +50,11 @@ pub enum HydorError {
         operand_type: Type,
         span: Span,
     },
+    InvalidCast {
+        from: Type,
+        to: Type,
+        span: Span,
+    },
     InvalidBinaryOp {
         operator: TokenType,
         left_type: Type,
@@ -56,6 +70,170 @@ pub enum HydorError {
         original_span: Span,
         redeclaration_span: Span,
     },
+    AssignmentTypeMismatch {
+        name: String,
+        expected: Type,
+        got: Type,
+        span: Span,
+    },
+    ConstantReassignment {
+        name: String,
+        declaration_span: Span,
+        span: Span,
+    },
+    BranchTypeMismatch {
+        first_type: Type,
+        second_type: Type,
+        span: Span,
+    },
+    LoopControlOutsideLoop {
+        keyword: TokenType,
+        span: Span,
+    },
+    UndefinedLoopLabel {
+        label: String,
+        span: Span,
+    },
+    InvalidRangeStep {
+        step: i64,
+        span: Span,
+    },
+    IntegerLiteralOutOfRange {
+        value: i64,
+        expected: Type,
+        span: Span,
+    },
+    ReturnOutsideFunction {
+        span: Span,
+    },
+    ReturnTypeMismatch {
+        expected: Type,
+        got: Type,
+        span: Span,
+    },
+    MissingReturn {
+        name: String,
+        expected: Type,
+        span: Span,
+    },
+    NotCallable {
+        found: Type,
+        span: Span,
+    },
+    ArgumentCountMismatch {
+        expected: usize,
+        got: usize,
+        span: Span,
+    },
+    ArgumentTypeMismatch {
+        expected: Type,
+        got: Type,
+        span: Span,
+    },
+    TooManyParameters {
+        count: usize,
+        span: Span,
+    },
+    TooManyArguments {
+        count: usize,
+        span: Span,
+    },
+    UntypedEmptyArray {
+        span: Span,
+    },
+    NotIndexable {
+        found: Type,
+        span: Span,
+    },
+    UntypedEmptyMap {
+        span: Span,
+    },
+    UnhashableKeyType {
+        found: Type,
+        span: Span,
+    },
+    UnknownMethod {
+        found: Type,
+        method: String,
+        span: Span,
+    },
+    InvalidTypeName {
+        got: String,
+        span: Span,
+    },
+    TypeRedeclaration {
+        name: String,
+        original_span: Span,
+        redeclaration_span: Span,
+    },
+    UnknownField {
+        found: Type,
+        field: String,
+        span: Span,
+    },
+    MissingFields {
+        name: String,
+        fields: Vec<String>,
+        span: Span,
+    },
+    DuplicateField {
+        field: String,
+        span: Span,
+    },
+    UnknownVariant {
+        name: String,
+        variant: String,
+        span: Span,
+    },
+    DuplicateVariant {
+        variant: String,
+        span: Span,
+    },
+    PayloadCountMismatch {
+        variant: String,
+        expected: usize,
+        got: usize,
+        span: Span,
+    },
+    NonExhaustiveMatch {
+        missing: String,
+        span: Span,
+    },
+    TupleDestructureMismatch {
+        names: usize,
+        found: Type,
+        span: Span,
+    },
+    NotOptional {
+        operator: TokenType,
+        found: Type,
+        span: Span,
+    },
+    TypeArgumentConflict {
+        parameter: String,
+        first: Type,
+        first_span: Span,
+        second: Type,
+        span: Span,
+    },
+    CannotInferTypeArgument {
+        parameter: String,
+        span: Span,
+    },
+    TypeArgumentCountMismatch {
+        name: String,
+        expected: usize,
+        got: usize,
+        span: Span,
+    },
+    UntypedNil {
+        name: String,
+        span: Span,
+    },
+    /// Reported as a warning, it does not stop compilation
+    UnreachablePattern {
+        span: Span,
+    },
 
     // ----- Compiler -----
     UnknownAST {
@@ -66,6 +244,10 @@ pub enum HydorError {
         ident_name: String,
         span: Span,
     },
+    JumpTooFar {
+        target: usize,
+        span: Span,
+    },
 
     // ----- HydorVM -----
     StackUnderflow {
@@ -94,6 +276,63 @@ pub enum HydorError {
         blame_type: RuntimeType,
         span: Span,
     },
+    IntegerOverflow {
+        operation: TokenType,
+        operand_type: RuntimeType,
+        span: Span,
+    },
+    DivisionByZero {
+        span: Span,
+    },
+    NegativeExponent {
+        exponent: i64,
+        span: Span,
+    },
+    InvalidShift {
+        amount: i64,
+        bits: u32,
+        span: Span,
+    },
+
+    // ----- Runtime Call Errors -----
+    InvalidCallTarget {
+        callee_type: RuntimeType,
+        span: Span,
+    },
+    CallArityMismatch {
+        expected: usize,
+        got: usize,
+        span: Span,
+    },
+
+    // ----- Runtime Index Errors -----
+    IndexOutOfBounds {
+        index: i32,
+        length: usize,
+        span: Span,
+    },
+    KeyNotFound {
+        key: String,
+        span: Span,
+    },
+
+    // ----- Runtime Conversion Errors -----
+    InvalidFloatToInt {
+        value: f64,
+        target: RuntimeType,
+        span: Span,
+    },
+    IntegerOutOfRange {
+        value: i64,
+        target: RuntimeType,
+        span: Span,
+    },
+
+    // ----- Runtime Loop Errors -----
+    RangeStepNotPositive {
+        step: i64,
+        span: Span,
+    },
 }
 
 impl HydorError {
@@ -102,25 +341,79 @@ impl HydorError {
             HydorError::UnexpectedToken { span, .. } => *span,
             HydorError::ExpectedToken { span, .. } => *span,
             HydorError::KeywordTypeError { span, .. } => *span,
-            HydorError::InvalidTypeName { span, .. } => *span,
+            HydorError::InvalidAssignmentTarget { span } => *span,
+            HydorError::InvalidEnumPath { span } => *span,
+            HydorError::InvalidPattern { span, .. } => *span,
+            HydorError::IntegerLiteralTooLarge { span } => *span,
 
             HydorError::TypeMismatch { span, .. } => *span,
             HydorError::InvalidUnaryOp { span, .. } => *span,
+            HydorError::InvalidCast { span, .. } => *span,
             HydorError::InvalidBinaryOp { span, .. } => *span,
             HydorError::DeclarationTypeMismatch { span, .. } => *span,
             HydorError::UndefinedVariable { span, .. } => *span,
             HydorError::VariableRedeclaration {
                 redeclaration_span, ..
             } => *redeclaration_span,
+            HydorError::AssignmentTypeMismatch { span, .. } => *span,
+            HydorError::ConstantReassignment { span, .. } => *span,
+            HydorError::BranchTypeMismatch { span, .. } => *span,
+            HydorError::LoopControlOutsideLoop { span, .. } => *span,
+            HydorError::UndefinedLoopLabel { span, .. } => *span,
+            HydorError::InvalidRangeStep { span, .. } => *span,
+            HydorError::IntegerLiteralOutOfRange { span, .. } => *span,
+            HydorError::ReturnOutsideFunction { span } => *span,
+            HydorError::ReturnTypeMismatch { span, .. } => *span,
+            HydorError::MissingReturn { span, .. } => *span,
+            HydorError::NotCallable { span, .. } => *span,
+            HydorError::ArgumentCountMismatch { span, .. } => *span,
+            HydorError::ArgumentTypeMismatch { span, .. } => *span,
+            HydorError::TooManyParameters { span, .. } => *span,
+            HydorError::TooManyArguments { span, .. } => *span,
+            HydorError::UntypedEmptyArray { span } => *span,
+            HydorError::NotIndexable { span, .. } => *span,
+            HydorError::UntypedEmptyMap { span } => *span,
+            HydorError::UnhashableKeyType { span, .. } => *span,
+            HydorError::UnknownMethod { span, .. } => *span,
+            HydorError::InvalidTypeName { span, .. } => *span,
+            HydorError::TypeRedeclaration {
+                redeclaration_span, ..
+            } => *redeclaration_span,
+            HydorError::UnknownField { span, .. } => *span,
+            HydorError::MissingFields { span, .. } => *span,
+            HydorError::DuplicateField { span, .. } => *span,
+            HydorError::UnknownVariant { span, .. } => *span,
+            HydorError::DuplicateVariant { span, .. } => *span,
+            HydorError::PayloadCountMismatch { span, .. } => *span,
+            HydorError::NonExhaustiveMatch { span, .. } => *span,
+            HydorError::TupleDestructureMismatch { span, .. } => *span,
+            HydorError::NotOptional { span, .. } => *span,
+            HydorError::TypeArgumentConflict { span, .. } => *span,
+            HydorError::CannotInferTypeArgument { span, .. } => *span,
+            HydorError::TypeArgumentCountMismatch { span, .. } => *span,
+            HydorError::UntypedNil { span, .. } => *span,
+            HydorError::UnreachablePattern { span } => *span,
 
             HydorError::UnknownAST { span, .. } => *span,
             HydorError::UndefinedIdentifier { span, .. } => *span,
+            HydorError::JumpTooFar { span, .. } => *span,
 
             HydorError::StackUnderflow { span, .. } => *span,
             HydorError::StackOverflow { span, .. } => *span,
             HydorError::ArithmeticError { span, .. } => *span,
             HydorError::UnaryOperationError { span, .. } => *span,
             HydorError::ComparisonOperationError { span, .. } => *span,
+            HydorError::IntegerOverflow { span, .. } => *span,
+            HydorError::DivisionByZero { span } => *span,
+            HydorError::NegativeExponent { span, .. } => *span,
+            HydorError::InvalidShift { span, .. } => *span,
+            HydorError::InvalidCallTarget { span, .. } => *span,
+            HydorError::CallArityMismatch { span, .. } => *span,
+            HydorError::IndexOutOfBounds { span, .. } => *span,
+            HydorError::KeyNotFound { span, .. } => *span,
+            HydorError::InvalidFloatToInt { span, .. } => *span,
+            HydorError::IntegerOutOfRange { span, .. } => *span,
+            HydorError::RangeStepNotPositive { span, .. } => *span,
         }
     }
 
@@ -129,23 +422,75 @@ impl HydorError {
             HydorError::UnexpectedToken { .. } => "Syntax",
             HydorError::ExpectedToken { .. } => "Syntax",
             HydorError::KeywordTypeError { .. } => "Syntax",
-            HydorError::InvalidTypeName { .. } => "Syntax",
+            HydorError::InvalidAssignmentTarget { .. } => "Syntax",
+            HydorError::InvalidEnumPath { .. } => "Syntax",
+            HydorError::InvalidPattern { .. } => "Syntax",
+            HydorError::IntegerLiteralTooLarge { .. } => "Syntax",
 
             HydorError::TypeMismatch { .. } => "Type",
             HydorError::InvalidUnaryOp { .. } => "Type",
+            HydorError::InvalidCast { .. } => "Type",
             HydorError::InvalidBinaryOp { .. } => "Type",
             HydorError::DeclarationTypeMismatch { .. } => "Type",
             HydorError::UndefinedVariable { .. } => "Type",
             HydorError::VariableRedeclaration { .. } => "Type",
+            HydorError::AssignmentTypeMismatch { .. } => "Type",
+            HydorError::ConstantReassignment { .. } => "Type",
+            HydorError::BranchTypeMismatch { .. } => "Type",
+            HydorError::LoopControlOutsideLoop { .. } => "Type",
+            HydorError::UndefinedLoopLabel { .. } => "Type",
+            HydorError::InvalidRangeStep { .. } => "Type",
+            HydorError::IntegerLiteralOutOfRange { .. } => "Type",
+            HydorError::ReturnOutsideFunction { .. } => "Type",
+            HydorError::ReturnTypeMismatch { .. } => "Type",
+            HydorError::MissingReturn { .. } => "Type",
+            HydorError::NotCallable { .. } => "Type",
+            HydorError::ArgumentCountMismatch { .. } => "Type",
+            HydorError::ArgumentTypeMismatch { .. } => "Type",
+            HydorError::TooManyParameters { .. } => "Type",
+            HydorError::TooManyArguments { .. } => "Type",
+            HydorError::UntypedEmptyArray { .. } => "Type",
+            HydorError::NotIndexable { .. } => "Type",
+            HydorError::UntypedEmptyMap { .. } => "Type",
+            HydorError::UnhashableKeyType { .. } => "Type",
+            HydorError::UnknownMethod { .. } => "Type",
+            HydorError::InvalidTypeName { .. } => "Type",
+            HydorError::TypeRedeclaration { .. } => "Type",
+            HydorError::UnknownField { .. } => "Type",
+            HydorError::MissingFields { .. } => "Type",
+            HydorError::DuplicateField { .. } => "Type",
+            HydorError::UnknownVariant { .. } => "Type",
+            HydorError::DuplicateVariant { .. } => "Type",
+            HydorError::PayloadCountMismatch { .. } => "Type",
+            HydorError::NonExhaustiveMatch { .. } => "Type",
+            HydorError::TupleDestructureMismatch { .. } => "Type",
+            HydorError::NotOptional { .. } => "Type",
+            HydorError::TypeArgumentConflict { .. } => "Type",
+            HydorError::CannotInferTypeArgument { .. } => "Type",
+            HydorError::TypeArgumentCountMismatch { .. } => "Type",
+            HydorError::UntypedNil { .. } => "Type",
+            HydorError::UnreachablePattern { .. } => "Type",
 
             HydorError::UnknownAST { .. } => "Compiler",
             HydorError::UndefinedIdentifier { .. } => "Compiler",
+            HydorError::JumpTooFar { .. } => "Compiler",
 
             HydorError::StackUnderflow { .. } => "Runtime",
             HydorError::StackOverflow { .. } => "Runtime",
             HydorError::ArithmeticError { .. } => "Runtime",
             HydorError::UnaryOperationError { .. } => "Runtime",
             HydorError::ComparisonOperationError { .. } => "Runtime",
+            HydorError::IntegerOverflow { .. } => "Runtime",
+            HydorError::DivisionByZero { .. } => "Runtime",
+            HydorError::NegativeExponent { .. } => "Runtime",
+            HydorError::InvalidShift { .. } => "Runtime",
+            HydorError::InvalidCallTarget { .. } => "Runtime",
+            HydorError::CallArityMismatch { .. } => "Runtime",
+            HydorError::IndexOutOfBounds { .. } => "Runtime",
+            HydorError::KeyNotFound { .. } => "Runtime",
+            HydorError::InvalidFloatToInt { .. } => "Runtime",
+            HydorError::IntegerOutOfRange { .. } => "Runtime",
+            HydorError::RangeStepNotPositive { .. } => "Runtime",
         }
     }
 
@@ -160,9 +505,12 @@ impl HydorError {
             HydorError::KeywordTypeError { got, .. } => {
                 format!("'{}' is a keyword and cannot be used as a type name", got)
             }
-            HydorError::InvalidTypeName { got, .. } => {
-                format!("'{}' is not a valid type", got)
+            HydorError::InvalidAssignmentTarget { .. } => "Invalid assignment target".to_string(),
+            HydorError::InvalidEnumPath { .. } => "Expected an enum name before '::'".to_string(),
+            HydorError::InvalidPattern { token, .. } => {
+                format!("'{}' cannot start a pattern", token)
             }
+            HydorError::IntegerLiteralTooLarge { .. } => "Integer literal is too large".to_string(),
             HydorError::DeclarationTypeMismatch { got, expected, .. } => {
                 format!(
                     "Type mismatch in variable declaration: expected '{}', got '{}'",
@@ -197,13 +545,21 @@ impl HydorError {
                     operator, operand_type
                 )
             }
+            HydorError::InvalidCast { from, to, .. } => {
+                format!("Cannot cast a value of type '{}' to '{}'", from, to)
+            }
             HydorError::InvalidBinaryOp {
                 operator,
                 left_type,
                 right_type,
                 ..
             } => {
-                if left_type == right_type {
+                if *operator == TokenType::QuestionQuestion {
+                    format!(
+                        "Cannot use a '{}' as the default for a '{}'",
+                        right_type, left_type
+                    )
+                } else if left_type == right_type {
                     format!(
                         "Math operator '{}' is not supported for type '{}'",
                         operator, left_type
@@ -228,6 +584,179 @@ impl HydorError {
                     name, original_span.line, original_span.start_column
                 )
             }
+            HydorError::AssignmentTypeMismatch {
+                name,
+                expected,
+                got,
+                ..
+            } => {
+                format!(
+                    "Cannot assign a value of type '{}' to variable '{}' of type '{}'",
+                    got, name, expected
+                )
+            }
+            HydorError::ConstantReassignment { name, .. } => {
+                format!("Cannot assign to immutable variable '{}'", name)
+            }
+            HydorError::BranchTypeMismatch {
+                first_type,
+                second_type,
+                ..
+            } => {
+                format!(
+                    "Branches have incompatible types: '{}' and '{}'",
+                    first_type, second_type
+                )
+            }
+            HydorError::LoopControlOutsideLoop { keyword, .. } => {
+                format!("'{}' cannot be used outside of a loop", keyword)
+            }
+            HydorError::UndefinedLoopLabel { label, .. } => {
+                format!("Undefined loop label '{}'", label)
+            }
+            HydorError::InvalidRangeStep { step, .. } => {
+                format!("Range step must be positive, got {}", step)
+            }
+            HydorError::IntegerLiteralOutOfRange {
+                value, expected, ..
+            } => {
+                format!("Integer literal {} does not fit in '{}'", value, expected)
+            }
+            HydorError::ReturnOutsideFunction { .. } => {
+                "'return' cannot be used outside of a function".to_string()
+            }
+            HydorError::ReturnTypeMismatch { expected, got, .. } => {
+                format!(
+                    "Expected a return value of type '{}', but got '{}'",
+                    expected, got
+                )
+            }
+            HydorError::MissingReturn { name, expected, .. } => {
+                format!(
+                    "Function '{}' does not return a value of type '{}' on every path",
+                    name, expected
+                )
+            }
+            HydorError::NotCallable { found, .. } => {
+                format!("Cannot call a value of type '{}'", found)
+            }
+            HydorError::ArgumentCountMismatch { expected, got, .. } => {
+                format!("Expected {} argument(s), but got {}", expected, got)
+            }
+            HydorError::ArgumentTypeMismatch { expected, got, .. } => {
+                format!(
+                    "Expected an argument of type '{}', but got '{}'",
+                    expected, got
+                )
+            }
+            HydorError::TooManyParameters { count, .. } => {
+                format!(
+                    "Function has {} parameters, more than the limit of {}",
+                    count,
+                    u8::MAX
+                )
+            }
+            HydorError::TooManyArguments { count, .. } => {
+                format!(
+                    "Call passes {} arguments, more than the limit of {}",
+                    count,
+                    u8::MAX
+                )
+            }
+            HydorError::UntypedEmptyArray { .. } => {
+                "Cannot infer the element type of an empty array".to_string()
+            }
+            HydorError::NotIndexable { found, .. } => {
+                format!("Cannot index into a value of type '{}'", found)
+            }
+            HydorError::UntypedEmptyMap { .. } => {
+                "Cannot infer the key and value types of an empty map".to_string()
+            }
+            HydorError::UnhashableKeyType { found, .. } => {
+                format!("Type '{}' cannot be used as a map key", found)
+            }
+            HydorError::UnknownMethod { found, method, .. } => {
+                format!("Type '{}' has no method named '{}'", found, method)
+            }
+            HydorError::InvalidTypeName { got, .. } => {
+                format!("'{}' is not a valid type", got)
+            }
+            HydorError::TypeRedeclaration {
+                name,
+                original_span,
+                ..
+            } => {
+                format!(
+                    "Type '{}' is already declared at Ln {}:{}",
+                    name, original_span.line, original_span.start_column
+                )
+            }
+            HydorError::UnknownField { found, field, .. } => {
+                format!("Type '{}' has no field named '{}'", found, field)
+            }
+            HydorError::MissingFields { name, fields, .. } => {
+                let fields = fields
+                    .iter()
+                    .map(|f| format!("'{}'", f))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                format!("Missing field(s) {} in '{}'", fields, name)
+            }
+            HydorError::DuplicateField { field, .. } => {
+                format!("Field '{}' is specified more than once", field)
+            }
+            HydorError::UnknownVariant { name, variant, .. } => {
+                format!("Enum '{}' has no variant named '{}'", name, variant)
+            }
+            HydorError::DuplicateVariant { variant, .. } => {
+                format!("Variant '{}' is declared more than once", variant)
+            }
+            HydorError::PayloadCountMismatch {
+                variant,
+                expected,
+                got,
+                ..
+            } => format!(
+                "Variant '{}' carries {} value(s), but the pattern has {}",
+                variant, expected, got
+            ),
+            HydorError::NonExhaustiveMatch { missing, .. } => {
+                format!("Match is not exhaustive, '{}' is not covered", missing)
+            }
+            HydorError::TupleDestructureMismatch { names, found, .. } => {
+                format!("Cannot destructure '{}' into {} name(s)", found, names)
+            }
+            HydorError::NotOptional {
+                operator, found, ..
+            } => {
+                format!("'{}' needs an optional value, found '{}'", operator, found)
+            }
+            HydorError::TypeArgumentConflict {
+                parameter,
+                first,
+                second,
+                ..
+            } => format!(
+                "Type parameter '{}' cannot be both '{}' and '{}'",
+                parameter, first, second
+            ),
+            HydorError::CannotInferTypeArgument { parameter, .. } => {
+                format!("Cannot infer a type for type parameter '{}'", parameter)
+            }
+            HydorError::TypeArgumentCountMismatch {
+                name,
+                expected,
+                got,
+                ..
+            } => format!(
+                "'{}' takes {} type argument(s), but {} were given",
+                name, expected, got
+            ),
+            HydorError::UntypedNil { name, .. } => {
+                format!("Cannot infer the type of '{}' from 'nil'", name)
+            }
+            HydorError::UnreachablePattern { .. } => "Unreachable match arm".to_string(),
 
             HydorError::UnknownAST { node, .. } => match node {
                 Node::Statement(s) => {
@@ -243,6 +772,11 @@ impl HydorError {
                     ident_name
                 )
             }
+            HydorError::JumpTooFar { target, .. } => format!(
+                "Jump target {} is past the largest reachable offset {}",
+                target,
+                u16::MAX
+            ),
 
             HydorError::StackUnderflow { stack_length, .. } => {
                 format!(
@@ -297,6 +831,52 @@ impl HydorError {
                     operation, blame_type
                 )
             }
+            HydorError::IntegerOverflow {
+                operation,
+                operand_type,
+                ..
+            } => {
+                format!("{} overflow in '{}'", operand_type, operation)
+            }
+            HydorError::DivisionByZero { .. } => "Integer division by zero".to_string(),
+            HydorError::NegativeExponent { exponent, .. } => {
+                format!("Cannot raise an Integer to the negative power {}", exponent)
+            }
+            HydorError::InvalidShift { amount, bits, .. } => {
+                format!("Cannot shift a {} bit integer by {} bits", bits, amount)
+            }
+
+            HydorError::InvalidCallTarget { callee_type, .. } => {
+                format!("Cannot call a value of type '{}'", callee_type)
+            }
+            HydorError::CallArityMismatch { expected, got, .. } => {
+                format!(
+                    "Function expects {} argument(s), but was called with {}",
+                    expected, got
+                )
+            }
+            HydorError::IndexOutOfBounds { index, length, .. } => {
+                format!(
+                    "Index {} is out of bounds for an array of length {}",
+                    index, length
+                )
+            }
+            HydorError::KeyNotFound { key, .. } => {
+                format!("Key {} does not exist in the map", key)
+            }
+            HydorError::InvalidFloatToInt { value, target, .. } => {
+                if value.is_nan() {
+                    format!("Cannot convert NaN to {}", target)
+                } else {
+                    format!("Float {:?} is out of the {} range", value, target)
+                }
+            }
+            HydorError::IntegerOutOfRange { value, target, .. } => {
+                format!("{} is out of the {} range", value, target)
+            }
+            HydorError::RangeStepNotPositive { step, .. } => {
+                format!("Range step must be positive, got {}", step)
+            }
         }
     }
 
@@ -311,13 +891,31 @@ impl HydorError {
             HydorError::KeywordTypeError { .. } => {
                 Some("Keywords are reserved and cannot be used as type names".to_string())
             }
-            HydorError::InvalidTypeName { .. } => {
-                Some("Available types: Int, Float, Bool, String".to_string())
+            HydorError::InvalidEnumPath { .. } => {
+                Some("Variants are written as 'Enum::Variant'".to_string())
             }
-            HydorError::DeclarationTypeMismatch { got, expected, .. } => Some(format!(
-                "Either change the declared type to '{}' or provide a value of type '{}'",
-                got, expected
+            HydorError::IntegerLiteralTooLarge { .. } => Some(format!(
+                "Integer literals can be at most {}, the largest Int64",
+                i64::MAX
             )),
+            HydorError::InvalidPattern { .. } => Some(
+                "Patterns are literals, ranges, '_', names, 'Enum::Variant(...)' or 'Struct { ... }'"
+                    .to_string(),
+            ),
+            HydorError::InvalidAssignmentTarget { .. } => Some(
+                "Only variables, fields, array elements and map entries can be assigned to"
+                    .to_string(),
+            ),
+            HydorError::DeclarationTypeMismatch { got, expected, .. } => match got {
+                Type::Nil => Some(format!(
+                    "Declare it as '{}' if it can be nil",
+                    Type::optional(expected.clone())
+                )),
+                _ => Some(format!(
+                    "Either change the declared type to '{}' or provide a value of type '{}'",
+                    got, expected
+                )),
+            },
             HydorError::TypeMismatch { expected, .. } => {
                 if expected.len() > 1 {
                     Some("Ensure the value matches one of the expected types".to_string())
@@ -327,18 +925,45 @@ impl HydorError {
             }
             HydorError::InvalidUnaryOp { operator, .. } => match operator {
                 TokenType::Not => Some("Logical negation requires a boolean operand".to_string()),
-                TokenType::Minus => {
-                    Some("Numeric negation requires an integer or float operand".to_string())
-                }
+                TokenType::Minus => Some(
+                    "Numeric negation requires an 'Int', 'Int64' or 'Float' operand, 'Byte' is unsigned"
+                        .to_string(),
+                ),
+                TokenType::Tilde => Some("Bitwise not requires an integer operand".to_string()),
                 _ => Some("This operator is not supported for the given type".to_string()),
             },
+            HydorError::InvalidCast { .. } => Some(
+                "'Int', 'Int64', 'Byte' and 'Float' convert to each other, \
+                 and numbers and bools convert to 'String'"
+                    .to_string(),
+            ),
             HydorError::InvalidBinaryOp {
                 operator,
                 left_type,
                 right_type,
                 ..
             } => {
-                if left_type != right_type {
+                if *operator == TokenType::QuestionQuestion {
+                    Some("The default must have the type of the optional's value".to_string())
+                } else if matches!(left_type, Type::Optional(_))
+                    || matches!(right_type, Type::Optional(_))
+                {
+                    Some(
+                        "Check for nil first with 'if x != nil', or give a default with '??'"
+                            .to_string(),
+                    )
+                } else if matches!(
+                    operator,
+                    TokenType::Percent
+                        | TokenType::Div
+                        | TokenType::Ampersand
+                        | TokenType::Pipe
+                        | TokenType::Xor
+                        | TokenType::ShiftLeft
+                        | TokenType::ShiftRight
+                ) {
+                    Some(format!("Operator '{}' requires integer operands", operator))
+                } else if left_type != right_type {
                     Some("Both operands must have the same type".to_string())
                 } else {
                     match operator {
@@ -366,6 +991,147 @@ impl HydorError {
                 "Remove this declaration or rename the variable to a different name than '{}'",
                 name
             )),
+            HydorError::AssignmentTypeMismatch { expected, .. } => Some(format!(
+                "Assign a value of type '{}' or declare a new variable",
+                expected
+            )),
+            HydorError::ConstantReassignment { .. } => {
+                Some("Only variables declared with 'let' can be reassigned".to_string())
+            }
+            HydorError::BranchTypeMismatch { .. } => {
+                Some("Every branch must produce a value of the same type".to_string())
+            }
+            HydorError::LoopControlOutsideLoop { keyword, .. } => Some(format!(
+                "Move '{}' inside the body of a 'while' loop",
+                keyword
+            )),
+            HydorError::UndefinedLoopLabel { label, .. } => Some(format!(
+                "Label an enclosing loop with '{}: while ...'",
+                label
+            )),
+            HydorError::InvalidRangeStep { .. } => {
+                Some("Ranges only count upwards, so the step must be greater than zero".to_string())
+            }
+            HydorError::IntegerLiteralOutOfRange { expected, .. } => {
+                expected.integer_range().map(|(min, max)| {
+                    format!("'{}' holds values from {} to {}", expected, min, max)
+                })
+            }
+            HydorError::ReturnOutsideFunction { .. } => {
+                Some("Move 'return' inside the body of a function".to_string())
+            }
+            HydorError::ReturnTypeMismatch { expected, .. } => Some(format!(
+                "Return a value of type '{}' or change the declared return type",
+                expected
+            )),
+            HydorError::MissingReturn { expected, .. } => Some(format!(
+                "Add a 'return' of type '{}' at the end of the function body",
+                expected
+            )),
+            HydorError::NotCallable { .. } => Some("Only functions can be called".to_string()),
+            HydorError::ArgumentCountMismatch { expected, .. } => {
+                Some(format!("Pass exactly {} argument(s)", expected))
+            }
+            HydorError::ArgumentTypeMismatch { expected, .. } => Some(format!(
+                "Pass a value of type '{}' for this parameter",
+                expected
+            )),
+            HydorError::TooManyParameters { .. } => Some(
+                "Group related parameters into a struct or tuple".to_string(),
+            ),
+            HydorError::TooManyArguments { .. } => Some(
+                "Group related arguments into a struct or tuple".to_string(),
+            ),
+            HydorError::UntypedEmptyArray { .. } => Some(
+                "Annotate the variable with an array type, e.g. 'let xs: [Int] = []'".to_string(),
+            ),
+            HydorError::NotIndexable { .. } => {
+                Some("Only arrays and maps can be indexed".to_string())
+            }
+            HydorError::UntypedEmptyMap { .. } => Some(
+                "Annotate the variable with a map type, e.g. 'let m: Map<String, Int> = {}'"
+                    .to_string(),
+            ),
+            HydorError::UnhashableKeyType { .. } => {
+                Some("Map keys must be of type 'Int', 'Bool' or 'String'".to_string())
+            }
+            HydorError::InvalidTypeName { got, .. } => Some(format!(
+                "Available types: Int, Int64, Byte, Float, Bool, String, or declare 'struct {}' or 'enum {}' first",
+                got, got
+            )),
+            HydorError::TypeRedeclaration { name, .. } => Some(format!(
+                "Remove this declaration or rename the type to a different name than '{}'",
+                name
+            )),
+            HydorError::UnknownField { found, .. } => match found {
+                Type::Struct(name, _) => Some(format!("Check the declaration of '{}'", name)),
+                Type::Tuple(elements) => Some(format!(
+                    "This tuple has {} element(s), counting from 0",
+                    elements.len()
+                )),
+                _ => Some("Only structs and tuples have fields".to_string()),
+            },
+            HydorError::MissingFields { .. } => {
+                Some("Every field must be given a value".to_string())
+            }
+            HydorError::DuplicateField { .. } => {
+                Some("Remove one of the values for this field".to_string())
+            }
+            HydorError::UnknownVariant { name, .. } => {
+                Some(format!("Check the declaration of '{}'", name))
+            }
+            HydorError::DuplicateVariant { .. } => {
+                Some("Remove or rename one of the variants".to_string())
+            }
+            HydorError::PayloadCountMismatch { .. } => {
+                Some("Give a pattern for every value, use '_' to ignore one".to_string())
+            }
+            HydorError::NonExhaustiveMatch { .. } => Some(
+                "Add an arm for the missing case, or a '_' arm to match everything else"
+                    .to_string(),
+            ),
+            HydorError::TypeArgumentConflict { parameter, .. } => Some(format!(
+                "Every value given for '{}' in one use must have the same type",
+                parameter
+            )),
+            HydorError::CannotInferTypeArgument { parameter, .. } => Some(format!(
+                "Nothing here decides '{}', annotate the variable this value is stored in",
+                parameter
+            )),
+            HydorError::TypeArgumentCountMismatch { expected: 0, .. } => {
+                Some("Remove the type arguments, this type is not generic".to_string())
+            }
+            HydorError::TypeArgumentCountMismatch { expected, .. } => Some(format!(
+                "Give exactly {} type argument(s) in '<...>'",
+                expected
+            )),
+            HydorError::UntypedNil { name, .. } => Some(format!(
+                "Annotate the variable with an optional type, e.g. 'let {}: Int? = nil'",
+                name
+            )),
+            HydorError::NotOptional { operator, .. } => match operator {
+                TokenType::QuestionDot => Some("This value is never nil, use '.' instead".to_string()),
+                _ => Some("This value is never nil, so it needs no default".to_string()),
+            },
+            HydorError::TupleDestructureMismatch { found, .. } => match found {
+                Type::Tuple(elements) => Some(format!(
+                    "Give exactly {} name(s), one for each element",
+                    elements.len()
+                )),
+                _ => Some("Only tuples can be destructured".to_string()),
+            },
+            HydorError::UnreachablePattern { .. } => {
+                Some("Earlier arms already match every value this arm would".to_string())
+            }
+            HydorError::UnknownMethod { found, .. } => match found {
+                Type::Map { .. } => Some("Maps support 'has', 'remove' and 'keys'".to_string()),
+                Type::Integer | Type::Int64 | Type::Byte => Some(
+                    "Integers support 'wrapping_add', 'wrapping_sub', 'wrapping_mul', \
+                     'saturating_add', 'saturating_sub' and 'saturating_mul'"
+                        .to_string(),
+                ),
+                _ => Some(format!("Type '{}' has no methods", found)),
+            },
 
             HydorError::UnknownAST { .. } => {
                 Some("This is a compiler bug. Please report this issue".to_string())
@@ -374,6 +1140,9 @@ impl HydorError {
                 "This is a compiler bug. The type checker should have caught this error"
                     .to_string(),
             ),
+            HydorError::JumpTooFar { .. } => Some(
+                "Programs are limited to 64 KB of bytecode, make this program smaller".to_string(),
+            ),
 
             HydorError::StackUnderflow { .. } => {
                 Some("This is a virtual machine bug. Please report this issue".to_string())
@@ -411,25 +1180,108 @@ impl HydorError {
             HydorError::ComparisonOperationError { .. } => {
                 Some("Comparison operators require integer or float operands".to_string())
             }
+            HydorError::IntegerOverflow {
+                operation,
+                operand_type,
+                ..
+            } => {
+                let method = match operation {
+                    TokenType::Plus => "add",
+                    TokenType::Minus => "sub",
+                    TokenType::Asterisk => "mul",
+                    _ => {
+                        let (min, max) = operand_type.integer_range()?;
+                        return Some(format!("The result must fit between {} and {}", min, max));
+                    }
+                };
+                Some(format!(
+                    "Use 'wrapping_{}' or 'saturating_{}' if the result may leave the {} range",
+                    method, method, operand_type
+                ))
+            }
+            HydorError::DivisionByZero { .. } => {
+                Some("Check that the divisor is not zero before dividing".to_string())
+            }
+            HydorError::NegativeExponent { .. } => Some(
+                "The result would be a fraction, cast both operands with 'as Float' to get one"
+                    .to_string(),
+            ),
+            HydorError::InvalidShift { bits, .. } => Some(format!(
+                "Shift amounts must be between 0 and {}",
+                bits - 1
+            )),
+
+            HydorError::InvalidCallTarget { .. } | HydorError::CallArityMismatch { .. } => Some(
+                "The type checker rejects this call, the bytecode may be corrupted".to_string(),
+            ),
+            HydorError::IndexOutOfBounds { length, .. } => {
+                if *length == 0 {
+                    Some("The array is empty, so it has no valid indices".to_string())
+                } else {
+                    Some(format!("Valid indices are 0 to {}", length - 1))
+                }
+            }
+            HydorError::KeyNotFound { .. } => {
+                Some("Check for the key with 'has' before reading it".to_string())
+            }
+            HydorError::InvalidFloatToInt { value, target, .. } => {
+                if value.is_nan() {
+                    Some("NaN has no integer value, check for it before casting".to_string())
+                } else {
+                    let (min, max) = target.integer_range()?;
+                    Some(format!("{} ranges from {} to {}", target, min, max))
+                }
+            }
+            HydorError::IntegerOutOfRange { target, .. } => {
+                let (min, max) = target.integer_range()?;
+                Some(format!(
+                    "{} ranges from {} to {}, check the value before casting",
+                    target, min, max
+                ))
+            }
+            HydorError::RangeStepNotPositive { .. } => {
+                Some("Ranges only count upwards, so the step must be greater than zero".to_string())
+            }
         }
     }
 
+    /// Warnings are reported like errors, but do not stop compilation
+    pub fn is_warning(&self) -> bool {
+        matches!(self, HydorError::UnreachablePattern { .. })
+    }
+
     pub fn report(&self, source: &str) {
         let span = self.span();
+        let (severity, message) = if self.is_warning() {
+            (
+                "Warning".yellow().dimmed().bold(),
+                format!(" -> {}", self.message()).bright_yellow(),
+            )
+        } else {
+            (
+                "Error".red().dimmed().bold(),
+                format!(" -> {}", self.message()).bright_red(),
+            )
+        };
 
         // Header: Category::Error -> message
         eprintln!(
             "{}{}{}{}",
             self.category().bright_white().bold(),
             "::".white().dimmed(),
-            "Error".red().dimmed().bold(),
-            format!(" -> {}", self.message()).bright_red()
+            severity,
+            message
         );
 
         eprintln!();
 
         // Error caused by section
-        eprintln!("{}", "Error caused by:".white().dimmed().bold());
+        let caused_by = if self.is_warning() {
+            "Warning caused by:"
+        } else {
+            "Error caused by:"
+        };
+        eprintln!("{}", caused_by.white().dimmed().bold());
 
         // Main error location
         self.print_code_snippet(source, span, true);
@@ -510,11 +1362,34 @@ impl HydorError {
 
     fn print_additional_context(&self, source: &str) {
         match self {
-            HydorError::VariableRedeclaration { original_span, .. } => {
+            HydorError::VariableRedeclaration { original_span, .. }
+            | HydorError::TypeRedeclaration { original_span, .. } => {
                 eprintln!();
                 eprintln!("{}", "Originally declared here:".white().dimmed());
                 self.print_code_snippet(source, *original_span, false);
             }
+            HydorError::ConstantReassignment {
+                declaration_span, ..
+            } => {
+                eprintln!();
+                eprintln!("{}", "Declared as immutable here:".white().dimmed());
+                self.print_code_snippet(source, *declaration_span, false);
+            }
+            HydorError::TypeArgumentConflict {
+                parameter,
+                first,
+                first_span,
+                ..
+            } => {
+                eprintln!();
+                eprintln!(
+                    "{}",
+                    format!("'{}' was inferred as '{}' here:", parameter, first)
+                        .white()
+                        .dimmed()
+                );
+                self.print_code_snippet(source, *first_span, false);
+            }
             _ => {}
         }
     }
@@ -1,6 +1,10 @@
 use crate::{
-    bytecode::bytecode::OpCode, errors::HydorError, hydor_vm::vm::HydorVM,
-    runtime_value::RuntimeValue, utils::Span,
+    bytecode::bytecode::OpCode,
+    errors::HydorError,
+    hydor_vm::{integers::Integer, vm::HydorVM},
+    runtime_value::RuntimeValue,
+    tokens::TokenType,
+    utils::Span,
 };
 
 impl HydorVM {
@@ -8,73 +12,192 @@ impl HydorVM {
         let (right, right_span) = self.pop_with_span()?;
         let (left, left_span) = self.pop_with_span()?;
 
-        let result = match opcode {
-            OpCode::AddInt => {
-                let a = left.as_int().unwrap();
-                let b = right.as_int().unwrap();
-                RuntimeValue::IntegerLiteral(a + b)
+        let result = match (left, right) {
+            (RuntimeValue::FloatLiteral(a), RuntimeValue::FloatLiteral(b)) => {
+                RuntimeValue::FloatLiteral(Self::float_op(opcode, a, b))
+            }
+            (RuntimeValue::IntegerLiteral(a), RuntimeValue::IntegerLiteral(b)) => {
+                Self::integer_op(opcode, a, b, span)?.into_value()
             }
-            OpCode::AddFloat => {
-                let a = left.as_float().unwrap();
-                let b = right.as_float().unwrap();
-                RuntimeValue::FloatLiteral(a + b)
+            (RuntimeValue::Int64Literal(a), RuntimeValue::Int64Literal(b)) => {
+                Self::integer_op(opcode, a, b, span)?.into_value()
             }
-            OpCode::SubtractInt => {
-                let a = left.as_int().unwrap();
-                let b = right.as_int().unwrap();
-                RuntimeValue::IntegerLiteral(a - b)
+            (RuntimeValue::ByteLiteral(a), RuntimeValue::ByteLiteral(b)) => {
+                Self::integer_op(opcode, a, b, span)?.into_value()
             }
-            OpCode::SubtractFloat => {
-                let a = left.as_float().unwrap();
-                let b = right.as_float().unwrap();
-                RuntimeValue::FloatLiteral(a - b)
+            _ => unreachable!("Type checker should catch invalid binary operations"),
+        };
+
+        let result_span = Span {
+            line: left_span.line,
+            start_column: left_span.start_column,
+            end_column: right_span.end_column,
+        };
+
+        self.push(result, result_span)?;
+        Ok(())
+    }
+
+    fn float_op(opcode: OpCode, a: f64, b: f64) -> f64 {
+        match opcode {
+            OpCode::AddFloat => a + b,
+            OpCode::SubtractFloat => a - b,
+            OpCode::MultiplyFloat => a * b,
+            OpCode::DivideFloat => a / b,
+            OpCode::ExponentFloat => a.powf(b),
+            _ => unreachable!("Type checker should catch invalid binary operations"),
+        }
+    }
+
+    /// The typed opcodes of every integer width share one implementation
+    fn integer_op<T: Integer>(opcode: OpCode, a: T, b: T, span: Span) -> Result<T, HydorError> {
+        let result = match opcode {
+            OpCode::AddInt | OpCode::AddInt64 | OpCode::AddByte => {
+                Self::checked(a.checked_add(b), TokenType::Plus, span)?
             }
-            OpCode::MultiplyInt => {
-                let a = left.as_int().unwrap();
-                let b = right.as_int().unwrap();
-                RuntimeValue::IntegerLiteral(a * b)
+            OpCode::SubtractInt | OpCode::SubtractInt64 | OpCode::SubtractByte => {
+                Self::checked(a.checked_sub(b), TokenType::Minus, span)?
             }
-            OpCode::MultiplyFloat => {
-                let a = left.as_float().unwrap();
-                let b = right.as_float().unwrap();
-                RuntimeValue::FloatLiteral(a * b)
+            OpCode::MultiplyInt | OpCode::MultiplyInt64 | OpCode::MultiplyByte => {
+                Self::checked(a.checked_mul(b), TokenType::Asterisk, span)?
             }
-            OpCode::DivideInt => {
-                let a = left.as_int().unwrap();
-                let b = right.as_int().unwrap();
-                RuntimeValue::IntegerLiteral(a / b)
+            OpCode::DivideInt | OpCode::DivideInt64 | OpCode::DivideByte => {
+                if b == T::ZERO {
+                    return Err(HydorError::DivisionByZero { span });
+                }
+                // Only `MIN / -1` can overflow
+                Self::checked(a.checked_div(b), TokenType::Slash, span)?
             }
-            OpCode::DivideFloat => {
-                let a = left.as_float().unwrap();
-                let b = right.as_float().unwrap();
-                RuntimeValue::FloatLiteral(a / b)
+            OpCode::ExponentInt | OpCode::ExponentInt64 | OpCode::ExponentByte => {
+                let exponent: i64 = b.into();
+                if exponent < 0 {
+                    return Err(HydorError::NegativeExponent { exponent, span });
+                }
+                Self::checked(Self::int_power(a, exponent), TokenType::Caret, span)?
             }
-            OpCode::ExponentInt => {
-                let a = left.as_int().unwrap() as f64;
-                let b = right.as_int().unwrap() as f64;
-                let result = a.powf(b);
-                if result.fract() == 0.0 {
-                    RuntimeValue::IntegerLiteral(result as i32)
+            OpCode::ModuloInt
+            | OpCode::ModuloInt64
+            | OpCode::ModuloByte
+            | OpCode::FloorDivideInt
+            | OpCode::FloorDivideInt64
+            | OpCode::FloorDivideByte => {
+                if b == T::ZERO {
+                    return Err(HydorError::DivisionByZero { span });
+                }
+                if matches!(
+                    opcode,
+                    OpCode::ModuloInt | OpCode::ModuloInt64 | OpCode::ModuloByte
+                ) {
+                    Self::floor_modulo(a, b)
                 } else {
-                    RuntimeValue::FloatLiteral(result)
+                    Self::checked(Self::floor_divide(a, b), TokenType::Div, span)?
                 }
             }
-            OpCode::ExponentFloat => {
-                let a = left.as_float().unwrap();
-                let b = right.as_float().unwrap();
-                RuntimeValue::FloatLiteral(a.powf(b))
+            OpCode::BitwiseAndInt | OpCode::BitwiseAndInt64 | OpCode::BitwiseAndByte => a & b,
+            OpCode::BitwiseOrInt | OpCode::BitwiseOrInt64 | OpCode::BitwiseOrByte => a | b,
+            OpCode::BitwiseXorInt | OpCode::BitwiseXorInt64 | OpCode::BitwiseXorByte => a ^ b,
+            OpCode::ShiftLeftInt
+            | OpCode::ShiftLeftInt64
+            | OpCode::ShiftLeftByte
+            | OpCode::ShiftRightInt
+            | OpCode::ShiftRightInt64
+            | OpCode::ShiftRightByte => {
+                let amount: i64 = b.into();
+                if !(0..T::BITS as i64).contains(&amount) {
+                    return Err(HydorError::InvalidShift {
+                        amount,
+                        bits: T::BITS,
+                        span,
+                    });
+                }
+                // Bits shifted out are dropped, `>>` keeps the sign of signed integers
+                if matches!(
+                    opcode,
+                    OpCode::ShiftLeftInt | OpCode::ShiftLeftInt64 | OpCode::ShiftLeftByte
+                ) {
+                    a << amount as u32
+                } else {
+                    a >> amount as u32
+                }
+            }
+            OpCode::WrappingAddInt | OpCode::WrappingAddInt64 | OpCode::WrappingAddByte => {
+                a.wrapping_add(b)
+            }
+            OpCode::WrappingSubtractInt
+            | OpCode::WrappingSubtractInt64
+            | OpCode::WrappingSubtractByte => a.wrapping_sub(b),
+            OpCode::WrappingMultiplyInt
+            | OpCode::WrappingMultiplyInt64
+            | OpCode::WrappingMultiplyByte => a.wrapping_mul(b),
+            OpCode::SaturatingAddInt | OpCode::SaturatingAddInt64 | OpCode::SaturatingAddByte => {
+                a.saturating_add(b)
             }
+            OpCode::SaturatingSubtractInt
+            | OpCode::SaturatingSubtractInt64
+            | OpCode::SaturatingSubtractByte => a.saturating_sub(b),
+            OpCode::SaturatingMultiplyInt
+            | OpCode::SaturatingMultiplyInt64
+            | OpCode::SaturatingMultiplyByte => a.saturating_mul(b),
             _ => unreachable!("Type checker should catch invalid binary operations"),
         };
 
-        let result_span = Span {
-            line: left_span.line,
-            start_column: left_span.start_column,
-            end_column: right_span.end_column,
-        };
+        Ok(result)
+    }
 
-        self.push(result, result_span)?;
-        Ok(())
+    /// Rounds toward negative infinity, so that `a == (a div b) * b + a % b`.
+    /// `None` if it overflows
+    fn floor_divide<T: Integer>(a: T, b: T) -> Option<T> {
+        let quotient = a.checked_div(b)?;
+
+        if a % b != T::ZERO && (a < T::ZERO) != (b < T::ZERO) {
+            Some(quotient - T::ONE)
+        } else {
+            Some(quotient)
+        }
+    }
+
+    /// The remainder of `div`, which takes the sign of the divisor
+    fn floor_modulo<T: Integer>(a: T, b: T) -> T {
+        let remainder = a.wrapping_rem(b);
+
+        if remainder != T::ZERO && (remainder < T::ZERO) != (b < T::ZERO) {
+            remainder + b
+        } else {
+            remainder
+        }
+    }
+
+    /// `base ^ exponent` by repeated squaring, `None` if it overflows
+    fn int_power<T: Integer>(base: T, exponent: i64) -> Option<T> {
+        let mut result = T::ONE;
+        let mut base = base;
+        let mut exponent = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exponent >>= 1;
+            // The last square is never used, so it must not count as an overflow
+            if exponent > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+
+        Some(result)
+    }
+
+    /// The result of a checked integer operation, or an overflow error at `span`
+    pub(crate) fn checked<T: Integer>(
+        result: Option<T>,
+        operation: TokenType,
+        span: Span,
+    ) -> Result<T, HydorError> {
+        result.ok_or(HydorError::IntegerOverflow {
+            operation,
+            operand_type: T::RUNTIME_TYPE,
+            span,
+        })
     }
 
     pub(crate) fn string_concat(&mut self, span: Span) -> Result<(), HydorError> {
@@ -0,0 +1,167 @@
+use crate::{
+    bytecode::bytecode::read_uint16,
+    errors::HydorError,
+    hydor_vm::{
+        heap::{HeapObject, Map},
+        vm::{BOOLEAN_FALSE, BOOLEAN_TRUE, HydorVM},
+    },
+    runtime_value::RuntimeValue,
+    utils::Span,
+};
+
+impl HydorVM {
+    /// Collect the top `count` values into a new array, first element deepest
+    pub(crate) fn build_array(&mut self, span: Span) -> Result<(), HydorError> {
+        let count = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let mut elements = Vec::with_capacity(count);
+        for _ in 0..count {
+            elements.push(self.pop_value()?);
+        }
+        elements.reverse();
+
+        let index = self.allocate(HeapObject::Array(elements));
+        self.push(RuntimeValue::Array(index), span)
+    }
+
+    /// Collect the top `count` key/value pairs into a new map.
+    /// Later duplicates of a key overwrite earlier ones
+    pub(crate) fn build_map(&mut self, span: Span) -> Result<(), HydorError> {
+        let count = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let mut entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            let value = self.pop_value()?;
+            let key = self.pop_value()?;
+            entries.push((key, value));
+        }
+
+        let mut map = Map::default();
+        for (key, value) in entries.into_iter().rev() {
+            map.insert(key, value);
+        }
+
+        let index = self.allocate(HeapObject::Map(map));
+        self.push(RuntimeValue::Map(index), span)
+    }
+
+    pub(crate) fn get_index(&mut self, span: Span) -> Result<(), HydorError> {
+        let (index, index_span) = self.pop_with_span()?;
+        let collection = self.pop_value()?;
+
+        let value = match collection {
+            RuntimeValue::Array(array) => {
+                let position = self.bounds_check(array, index, index_span)?;
+                self.array(array)[position]
+            }
+            RuntimeValue::Map(map) => match self.map(map).get(&index) {
+                Some(value) => value,
+                None => {
+                    return Err(HydorError::KeyNotFound {
+                        key: self.describe_key(index),
+                        span: index_span,
+                    });
+                }
+            },
+            other => unreachable!(
+                "Indexing non-collections should be caught in type checker, found {:?}",
+                other
+            ),
+        };
+
+        self.push(value, span)
+    }
+
+    /// Assignment is an expression, so the stored value is pushed back.
+    /// Maps insert missing keys instead of failing
+    pub(crate) fn set_index(&mut self, span: Span) -> Result<(), HydorError> {
+        let value = self.pop_value()?;
+        let (index, index_span) = self.pop_with_span()?;
+        let collection = self.pop_value()?;
+
+        match collection {
+            RuntimeValue::Array(array) => {
+                let position = self.bounds_check(array, index, index_span)?;
+                self.array_mut(array)[position] = value;
+            }
+            RuntimeValue::Map(map) => self.map_mut(map).insert(index, value),
+            other => unreachable!(
+                "Indexing non-collections should be caught in type checker, found {:?}",
+                other
+            ),
+        }
+
+        self.push(value, span)
+    }
+
+    pub(crate) fn map_has(&mut self, span: Span) -> Result<(), HydorError> {
+        let key = self.pop_value()?;
+        let map = self.pop_map()?;
+
+        let found = self.map(map).get(&key).is_some();
+        self.push(if found { BOOLEAN_TRUE } else { BOOLEAN_FALSE }, span)
+    }
+
+    pub(crate) fn map_remove(&mut self, span: Span) -> Result<(), HydorError> {
+        let key = self.pop_value()?;
+        let map = self.pop_map()?;
+
+        let removed = self.map_mut(map).remove(&key);
+        self.push(if removed { BOOLEAN_TRUE } else { BOOLEAN_FALSE }, span)
+    }
+
+    /// Keys are returned as a new array, in insertion order
+    pub(crate) fn map_keys(&mut self, span: Span) -> Result<(), HydorError> {
+        let map = self.pop_map()?;
+
+        let keys = self.map(map).keys().to_vec();
+        let index = self.allocate(HeapObject::Array(keys));
+        self.push(RuntimeValue::Array(index), span)
+    }
+
+    /// Element position `index` refers to in `array`
+    fn bounds_check(
+        &self,
+        array: usize,
+        index: RuntimeValue,
+        index_span: Span,
+    ) -> Result<usize, HydorError> {
+        let index = index
+            .as_int()
+            .expect("Non-integer indices should be caught in type checker");
+
+        let length = self.array(array).len();
+        match usize::try_from(index) {
+            Ok(position) if position < length => Ok(position),
+            _ => Err(HydorError::IndexOutOfBounds {
+                index,
+                length,
+                span: index_span,
+            }),
+        }
+    }
+
+    fn pop_map(&mut self) -> Result<usize, HydorError> {
+        match self.pop_value()? {
+            RuntimeValue::Map(map) => Ok(map),
+            other => unreachable!(
+                "Map methods on other types should be caught in type checker, found {:?}",
+                other
+            ),
+        }
+    }
+
+    /// Render a key the way it is written in source
+    fn describe_key(&self, key: RuntimeValue) -> String {
+        match key {
+            RuntimeValue::IntegerLiteral(_)
+            | RuntimeValue::Int64Literal(_)
+            | RuntimeValue::ByteLiteral(_) => key.as_integer().unwrap().to_string(),
+            RuntimeValue::BooleanLiteral(b) => b.to_string(),
+            RuntimeValue::StringLiteral(index) => format!("\"{}\"", self.resolve_string(index)),
+            other => unreachable!("Unhashable map key {:?}", other),
+        }
+    }
+}
@@ -16,9 +16,9 @@ impl HydorVM {
         let (left, _) = self.pop_with_span()?;
 
         let result = match opcode {
-            OpCode::CompareLessInt => {
-                let a = left.as_int().unwrap();
-                let b = right.as_int().unwrap();
+            OpCode::CompareLessInt | OpCode::CompareLessInt64 | OpCode::CompareLessByte => {
+                let a = left.as_integer().unwrap();
+                let b = right.as_integer().unwrap();
                 a < b
             }
             OpCode::CompareLessFloat => {
@@ -26,9 +26,11 @@ impl HydorVM {
                 let b = right.as_float().unwrap();
                 a < b
             }
-            OpCode::CompareLessEqualInt => {
-                let a = left.as_int().unwrap();
-                let b = right.as_int().unwrap();
+            OpCode::CompareLessEqualInt
+            | OpCode::CompareLessEqualInt64
+            | OpCode::CompareLessEqualByte => {
+                let a = left.as_integer().unwrap();
+                let b = right.as_integer().unwrap();
                 a <= b
             }
             OpCode::CompareLessEqualFloat => {
@@ -36,9 +38,11 @@ impl HydorVM {
                 let b = right.as_float().unwrap();
                 a <= b
             }
-            OpCode::CompareGreaterInt => {
-                let a = left.as_int().unwrap();
-                let b = right.as_int().unwrap();
+            OpCode::CompareGreaterInt
+            | OpCode::CompareGreaterInt64
+            | OpCode::CompareGreaterByte => {
+                let a = left.as_integer().unwrap();
+                let b = right.as_integer().unwrap();
                 a > b
             }
             OpCode::CompareGreaterFloat => {
@@ -46,9 +50,11 @@ impl HydorVM {
                 let b = right.as_float().unwrap();
                 a > b
             }
-            OpCode::CompareGreaterEqualInt => {
-                let a = left.as_int().unwrap();
-                let b = right.as_int().unwrap();
+            OpCode::CompareGreaterEqualInt
+            | OpCode::CompareGreaterEqualInt64
+            | OpCode::CompareGreaterEqualByte => {
+                let a = left.as_integer().unwrap();
+                let b = right.as_integer().unwrap();
                 a >= b
             }
             OpCode::CompareGreaterEqualFloat => {
@@ -68,12 +74,54 @@ impl HydorVM {
     fn values_equal(&self, left: RuntimeValue, right: RuntimeValue) -> bool {
         match (left, right) {
             (RuntimeValue::IntegerLiteral(a), RuntimeValue::IntegerLiteral(b)) => a == b,
+            (RuntimeValue::Int64Literal(a), RuntimeValue::Int64Literal(b)) => a == b,
+            (RuntimeValue::ByteLiteral(a), RuntimeValue::ByteLiteral(b)) => a == b,
             (RuntimeValue::FloatLiteral(a), RuntimeValue::FloatLiteral(b)) => a == b,
             (RuntimeValue::BooleanLiteral(a), RuntimeValue::BooleanLiteral(b)) => a == b,
             (RuntimeValue::StringLiteral(a), RuntimeValue::StringLiteral(b)) => {
                 self.resolve_string(a) == self.resolve_string(b)
             }
             (RuntimeValue::NilLiteral, RuntimeValue::NilLiteral) => true,
+            (RuntimeValue::Function { entry: a, .. }, RuntimeValue::Function { entry: b, .. }) => {
+                a == b
+            }
+            (RuntimeValue::Closure(a), RuntimeValue::Closure(b)) => a == b,
+            (RuntimeValue::Array(a), RuntimeValue::Array(b)) => {
+                let (a, b) = (self.array(a), self.array(b));
+
+                a.len() == b.len() && a.iter().zip(b).all(|(x, y)| self.values_equal(*x, *y))
+            }
+            (RuntimeValue::Struct(a), RuntimeValue::Struct(b)) => {
+                let (a, b) = (self.instance(a), self.instance(b));
+
+                a.fields
+                    .iter()
+                    .zip(&b.fields)
+                    .all(|(x, y)| self.values_equal(*x, *y))
+            }
+            (RuntimeValue::Enum(a), RuntimeValue::Enum(b)) => {
+                let (a, b) = (self.variant(a), self.variant(b));
+
+                a.tag == b.tag
+                    && a.payload
+                        .iter()
+                        .zip(&b.payload)
+                        .all(|(x, y)| self.values_equal(*x, *y))
+            }
+            (RuntimeValue::Tuple(a), RuntimeValue::Tuple(b)) => {
+                let (a, b) = (self.tuple(a), self.tuple(b));
+
+                a.iter().zip(b).all(|(x, y)| self.values_equal(*x, *y))
+            }
+            (RuntimeValue::Map(a), RuntimeValue::Map(b)) => {
+                let (a, b) = (self.map(a), self.map(b));
+
+                a.len() == b.len()
+                    && a.keys().iter().all(|key| match (a.get(key), b.get(key)) {
+                        (Some(x), Some(y)) => self.values_equal(x, y),
+                        _ => false,
+                    })
+            }
             _ => false,
         }
     }
@@ -0,0 +1,55 @@
+use crate::{
+    bytecode::bytecode::read_uint16, errors::HydorError, hydor_vm::vm::HydorVM,
+    runtime_value::RuntimeValue,
+};
+
+impl HydorVM {
+    /// Unconditionally move the instruction pointer to the jump target
+    pub(crate) fn jump(&mut self) {
+        self.ip = read_uint16(&self.instructions, self.ip + 1) as usize;
+    }
+
+    /// Pop the condition and jump if it is falsy.
+    /// Returns whether the jump was taken
+    pub(crate) fn jump_if_false(&mut self) -> Result<bool, HydorError> {
+        let condition = self.pop_value()?;
+
+        if self.is_truthy(condition) {
+            self.ip += 2;
+            return Ok(false);
+        }
+
+        self.jump();
+        Ok(true)
+    }
+
+    /// Jump if the top of the stack is nil, leaving it in place either way.
+    /// Returns whether the jump was taken
+    pub(crate) fn jump_if_nil(&mut self) -> Result<bool, HydorError> {
+        if !matches!(self.peek_offset(0)?, RuntimeValue::NilLiteral) {
+            self.ip += 2;
+            return Ok(false);
+        }
+
+        self.jump();
+        Ok(true)
+    }
+
+    /// Fail unless the step on top of the stack moves a range loop forward,
+    /// leaving it in place
+    pub(crate) fn check_range_step(&mut self) -> Result<(), HydorError> {
+        let step = self
+            .peek_offset(0)?
+            .as_integer()
+            .expect("Range steps are always integers");
+
+        if step <= 0 {
+            return Err(HydorError::RangeStepNotPositive {
+                step,
+                span: self.peek_span(0)?,
+            });
+        }
+
+        Ok(())
+    }
+}
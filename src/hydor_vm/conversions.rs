@@ -0,0 +1,70 @@
+use crate::{
+    bytecode::bytecode::OpCode,
+    errors::HydorError,
+    hydor_vm::{integers::Integer, vm::HydorVM},
+    runtime_value::RuntimeValue,
+    utils::Span,
+};
+
+impl HydorVM {
+    /// Conversions replace the value on top of the stack in place
+    pub(crate) fn cast(&mut self, opcode: OpCode, span: Span) -> Result<(), HydorError> {
+        let value = self.peek_offset(0)?;
+
+        let result = match opcode {
+            // Integers of every width
+            OpCode::CastIntToFloat => RuntimeValue::FloatLiteral(value.as_number().unwrap()),
+            OpCode::CastFloatToInt | OpCode::CastToInt => {
+                Self::to_integer::<i32>(value, span)?.into_value()
+            }
+            OpCode::CastToInt64 => Self::to_integer::<i64>(value, span)?.into_value(),
+            OpCode::CastToByte => Self::to_integer::<u8>(value, span)?.into_value(),
+            OpCode::CastToString => {
+                let text = match value {
+                    RuntimeValue::IntegerLiteral(_)
+                    | RuntimeValue::Int64Literal(_)
+                    | RuntimeValue::ByteLiteral(_) => value.as_integer().unwrap().to_string(),
+                    RuntimeValue::FloatLiteral(n) => format!("{:?}", n),
+                    RuntimeValue::BooleanLiteral(b) => b.to_string(),
+                    RuntimeValue::StringLiteral(_) => return Ok(()),
+                    _ => unreachable!("Type checker should catch invalid casts"),
+                };
+                RuntimeValue::StringLiteral(self.intern_string(text))
+            }
+            _ => unreachable!(),
+        };
+
+        self.set_offset_value(0, result)
+    }
+
+    /// An integer of another width, which has to fit, or a float with its
+    /// fractional part dropped, like casting toward zero
+    fn to_integer<T: Integer>(value: RuntimeValue, span: Span) -> Result<T, HydorError> {
+        let (min, max): (i64, i64) = (T::MIN.into(), T::MAX.into());
+
+        let integer = match value {
+            RuntimeValue::FloatLiteral(float) => {
+                let truncated = float.trunc();
+
+                // `max + 1` is exact even where `max` itself is not
+                if float.is_nan() || truncated < min as f64 || truncated >= max as f64 + 1.0 {
+                    return Err(HydorError::InvalidFloatToInt {
+                        value: float,
+                        target: T::RUNTIME_TYPE,
+                        span,
+                    });
+                }
+                truncated as i64
+            }
+            other => other.as_integer().unwrap(),
+        };
+
+        T::try_from(integer)
+            .ok()
+            .ok_or(HydorError::IntegerOutOfRange {
+                value: integer,
+                target: T::RUNTIME_TYPE,
+                span,
+            })
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{
+    bytecode::bytecode::read_uint16,
+    errors::HydorError,
+    hydor_vm::{
+        heap::{HeapObject, Variant},
+        vm::{BOOLEAN_FALSE, BOOLEAN_TRUE, HydorVM},
+    },
+    runtime_value::RuntimeValue,
+    utils::Span,
+};
+
+impl HydorVM {
+    /// Wrap the top `count` values in a variant with the given tag, first payload value deepest
+    pub(crate) fn build_variant(&mut self, span: Span) -> Result<(), HydorError> {
+        let name = read_uint16(&self.instructions, self.ip + 1) as usize;
+        let tag = read_uint16(&self.instructions, self.ip + 3) as usize;
+        let count = read_uint16(&self.instructions, self.ip + 5) as usize;
+        self.ip += 6;
+
+        let mut payload = Vec::with_capacity(count);
+        for _ in 0..count {
+            payload.push(self.pop_value()?);
+        }
+        payload.reverse();
+
+        let index = self.allocate(HeapObject::Enum(Variant { name, tag, payload }));
+        self.push(RuntimeValue::Enum(index), span)
+    }
+
+    /// Whether the enum on top of the stack has the given tag
+    pub(crate) fn is_variant(&mut self, span: Span) -> Result<(), HydorError> {
+        let tag = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let variant = self.pop_variant()?;
+        let matches = self.variant(variant).tag == tag;
+        self.push(if matches { BOOLEAN_TRUE } else { BOOLEAN_FALSE }, span)
+    }
+
+    pub(crate) fn get_payload(&mut self, span: Span) -> Result<(), HydorError> {
+        let index = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let variant = self.pop_variant()?;
+        let value = self.variant(variant).payload[index];
+        self.push(value, span)
+    }
+
+    fn pop_variant(&mut self) -> Result<usize, HydorError> {
+        match self.pop_value()? {
+            RuntimeValue::Enum(variant) => Ok(variant),
+            other => unreachable!(
+                "Variant patterns on non-enums should be caught in type checker, found {:?}",
+                other
+            ),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{
+    bytecode::bytecode::{read_uint8, read_uint16},
+    errors::HydorError,
+    hydor_vm::{
+        heap::{Closure, HeapObject, Upvalue},
+        vm::{CallFrame, HydorVM},
+    },
+    runtime_value::RuntimeValue,
+    utils::Span,
+};
+
+impl HydorVM {
+    /// Push a new call frame and jump to the body of the callee.
+    /// The callee sits below its arguments, so it becomes local slot 0
+    pub(crate) fn call(&mut self, span: Span) -> Result<(), HydorError> {
+        let argc = read_uint8(&self.instructions, self.ip + 1) as usize;
+        let callee = self.peek_offset(argc)?;
+
+        let (arity, entry, closure) = match callee {
+            RuntimeValue::Function { arity, entry, .. } => (arity, entry, None),
+            RuntimeValue::Closure(index) => {
+                let closure = self.closure(index);
+                (closure.arity, closure.entry, Some(index))
+            }
+            other => {
+                return Err(HydorError::InvalidCallTarget {
+                    callee_type: other.get_type(),
+                    span,
+                });
+            }
+        };
+
+        if arity != argc {
+            return Err(HydorError::CallArityMismatch {
+                expected: arity,
+                got: argc,
+                span,
+            });
+        }
+
+        self.frames.push(CallFrame {
+            return_ip: self.ip + 2,
+            base: self.stack_len() - argc - 1,
+            closure,
+        });
+        self.ip = entry;
+
+        Ok(())
+    }
+
+    /// Discard the current frame and hand the return value to the caller
+    pub(crate) fn return_from_call(&mut self, span: Span) -> Result<(), HydorError> {
+        let result = self.pop_value()?;
+        let frame = self
+            .frames
+            .pop()
+            .expect("Return outside of a function should be caught in type checker");
+
+        // Closures created in this frame keep its locals alive
+        self.close_upvalues(frame.base);
+
+        self.truncate_stack(frame.base);
+        self.push(result, span)?;
+        self.ip = frame.return_ip;
+
+        Ok(())
+    }
+
+    /// Wrap a function constant in a closure without any captures yet,
+    /// the `Capture*` instructions that follow fill them in
+    pub(crate) fn make_closure(&mut self, span: Span) -> Result<(), HydorError> {
+        let const_index = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let (name, arity, entry) = match self.constants[const_index] {
+            RuntimeValue::Function { name, arity, entry } => (name, arity, entry),
+            other => unreachable!("Closure operand must be a function, found {:?}", other),
+        };
+
+        let index = self.allocate(HeapObject::Closure(Closure {
+            name,
+            arity,
+            entry,
+            upvalues: Vec::new(),
+        }));
+        self.push(RuntimeValue::Closure(index), span)
+    }
+
+    /// Capture a local of the current frame into the closure on top of the stack
+    pub(crate) fn capture_local(&mut self) -> Result<(), HydorError> {
+        let slot = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let stack_slot = self.frame_base() + slot;
+
+        // Closures capturing the same variable share its upvalue
+        let existing = self
+            .open_upvalues
+            .iter()
+            .copied()
+            .find(|&index| matches!(self.upvalue(index), Upvalue::Open(s) if s == stack_slot));
+
+        let upvalue = match existing {
+            Some(index) => index,
+            None => {
+                let index = self.allocate(HeapObject::Upvalue(Upvalue::Open(stack_slot)));
+                self.open_upvalues.push(index);
+                index
+            }
+        };
+
+        self.add_capture(upvalue)
+    }
+
+    /// Share an upvalue of the running closure with the closure on top of the stack
+    pub(crate) fn capture_upvalue(&mut self) -> Result<(), HydorError> {
+        let index = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let upvalue = self.current_upvalue(index);
+        self.add_capture(upvalue)
+    }
+
+    pub(crate) fn get_upvalue(&mut self, span: Span) -> Result<(), HydorError> {
+        let index = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let value = match self.upvalue(self.current_upvalue(index)) {
+            Upvalue::Open(slot) => self.get_stack_slot(slot)?,
+            Upvalue::Closed(value) => value,
+        };
+        self.push(value, span)
+    }
+
+    pub(crate) fn store_upvalue(&mut self) -> Result<(), HydorError> {
+        let index = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        // Assignment is an expression, so the value stays on the stack
+        let value = self.peek_offset(0)?;
+        let upvalue = self.current_upvalue(index);
+
+        match self.upvalue(upvalue) {
+            Upvalue::Open(slot) => self.set_stack_slot(slot, value)?,
+            Upvalue::Closed(_) => self.set_upvalue(upvalue, Upvalue::Closed(value)),
+        }
+
+        Ok(())
+    }
+
+    /// Move every open upvalue at or above `stack_slot` off the stack
+    pub(crate) fn close_upvalues(&mut self, stack_slot: usize) {
+        let open = std::mem::take(&mut self.open_upvalues);
+
+        for index in open {
+            match self.upvalue(index) {
+                Upvalue::Open(slot) if slot >= stack_slot => {
+                    let value = self
+                        .get_stack_slot(slot)
+                        .expect("Open upvalues always point into the stack");
+                    self.set_upvalue(index, Upvalue::Closed(value));
+                }
+                _ => self.open_upvalues.push(index),
+            }
+        }
+    }
+
+    /// Heap index of upvalue `index` of the running closure
+    fn current_upvalue(&self, index: usize) -> usize {
+        let closure = self
+            .frames
+            .last()
+            .and_then(|frame| frame.closure)
+            .expect("Upvalues are only accessed from inside a closure");
+
+        self.closure(closure).upvalues[index]
+    }
+
+    fn add_capture(&mut self, upvalue: usize) -> Result<(), HydorError> {
+        match self.peek_offset(0)? {
+            RuntimeValue::Closure(index) => {
+                self.closure_mut(index).upvalues.push(upvalue);
+                Ok(())
+            }
+            other => unreachable!("Captures always follow a closure, found {:?}", other),
+        }
+    }
+}
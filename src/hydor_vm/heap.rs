@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use crate::{hydor_vm::vm::HydorVM, runtime_value::RuntimeValue};
+
+/// Values that do not fit in a `RuntimeValue`, referenced by their heap index
+#[derive(Debug, Clone)]
+pub enum HeapObject {
+    Closure(Closure),
+    Upvalue(Upvalue),
+    Array(Vec<RuntimeValue>),
+    Map(Map),
+    Struct(Instance),
+    Enum(Variant),
+    /// Never mutated after it is built
+    Tuple(Vec<RuntimeValue>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Closure {
+    pub name: usize, // Accessed via string table
+    pub arity: usize,
+    pub entry: usize,
+    /// Heap indices of the captured upvalues
+    pub upvalues: Vec<usize>,
+}
+
+/// A captured variable. It points into the stack while its frame is alive,
+/// and owns the value once the variable goes out of scope
+#[derive(Debug, Clone, Copy)]
+pub enum Upvalue {
+    Open(usize),
+    Closed(RuntimeValue),
+}
+
+/// A struct value, its fields are stored in declaration order
+#[derive(Debug, Clone)]
+pub struct Instance {
+    pub name: usize, // Accessed via string table
+    pub fields: Vec<RuntimeValue>,
+}
+
+/// An enum value, tagged with the position of its variant in the declaration
+#[derive(Debug, Clone)]
+pub struct Variant {
+    pub name: usize, // Accessed via string table
+    pub tag: usize,
+    pub payload: Vec<RuntimeValue>,
+}
+
+/// Hash map that remembers the order its keys were first inserted in
+#[derive(Debug, Clone, Default)]
+pub struct Map {
+    entries: HashMap<RuntimeValue, RuntimeValue>,
+    keys: Vec<RuntimeValue>,
+}
+
+impl Map {
+    pub fn get(&self, key: &RuntimeValue) -> Option<RuntimeValue> {
+        self.entries.get(key).copied()
+    }
+
+    pub fn insert(&mut self, key: RuntimeValue, value: RuntimeValue) {
+        if self.entries.insert(key, value).is_none() {
+            self.keys.push(key);
+        }
+    }
+
+    /// Whether the key was present
+    pub fn remove(&mut self, key: &RuntimeValue) -> bool {
+        if self.entries.remove(key).is_none() {
+            return false;
+        }
+
+        self.keys.retain(|k| k != key);
+        true
+    }
+
+    pub fn keys(&self) -> &[RuntimeValue] {
+        &self.keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl HydorVM {
+    pub(crate) fn allocate(&mut self, object: HeapObject) -> usize {
+        self.heap.push(object);
+        self.heap.len() - 1
+    }
+
+    pub(crate) fn closure(&self, index: usize) -> &Closure {
+        match &self.heap[index] {
+            HeapObject::Closure(closure) => closure,
+            other => unreachable!("Expected a closure on the heap, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn closure_mut(&mut self, index: usize) -> &mut Closure {
+        match &mut self.heap[index] {
+            HeapObject::Closure(closure) => closure,
+            other => unreachable!("Expected a closure on the heap, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn upvalue(&self, index: usize) -> Upvalue {
+        match &self.heap[index] {
+            HeapObject::Upvalue(upvalue) => *upvalue,
+            other => unreachable!("Expected an upvalue on the heap, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn set_upvalue(&mut self, index: usize, upvalue: Upvalue) {
+        self.heap[index] = HeapObject::Upvalue(upvalue);
+    }
+
+    pub(crate) fn array(&self, index: usize) -> &Vec<RuntimeValue> {
+        match &self.heap[index] {
+            HeapObject::Array(elements) => elements,
+            other => unreachable!("Expected an array on the heap, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn array_mut(&mut self, index: usize) -> &mut Vec<RuntimeValue> {
+        match &mut self.heap[index] {
+            HeapObject::Array(elements) => elements,
+            other => unreachable!("Expected an array on the heap, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn map(&self, index: usize) -> &Map {
+        match &self.heap[index] {
+            HeapObject::Map(map) => map,
+            other => unreachable!("Expected a map on the heap, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn map_mut(&mut self, index: usize) -> &mut Map {
+        match &mut self.heap[index] {
+            HeapObject::Map(map) => map,
+            other => unreachable!("Expected a map on the heap, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn instance(&self, index: usize) -> &Instance {
+        match &self.heap[index] {
+            HeapObject::Struct(instance) => instance,
+            other => unreachable!("Expected a struct on the heap, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn instance_mut(&mut self, index: usize) -> &mut Instance {
+        match &mut self.heap[index] {
+            HeapObject::Struct(instance) => instance,
+            other => unreachable!("Expected a struct on the heap, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn variant(&self, index: usize) -> &Variant {
+        match &self.heap[index] {
+            HeapObject::Enum(variant) => variant,
+            other => unreachable!("Expected an enum on the heap, found {:?}", other),
+        }
+    }
+
+    pub(crate) fn tuple(&self, index: usize) -> &Vec<RuntimeValue> {
+        match &self.heap[index] {
+            HeapObject::Tuple(elements) => elements,
+            other => unreachable!("Expected a tuple on the heap, found {:?}", other),
+        }
+    }
+}
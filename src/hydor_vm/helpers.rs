@@ -5,6 +5,8 @@ impl HydorVM {
         match rv {
             RuntimeValue::BooleanLiteral(b) => b,
             RuntimeValue::IntegerLiteral(n) => n != 0,
+            RuntimeValue::Int64Literal(n) => n != 0,
+            RuntimeValue::ByteLiteral(n) => n != 0,
             RuntimeValue::FloatLiteral(n) => n != 0.0,
             RuntimeValue::NilLiteral => false,
             RuntimeValue::StringLiteral(idx) => {
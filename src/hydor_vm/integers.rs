@@ -0,0 +1,119 @@
+use std::{
+    fmt::Display,
+    ops::{Add, BitAnd, BitOr, BitXor, Not, Rem, Shl, Shr, Sub},
+};
+
+use crate::runtime_value::{RuntimeType, RuntimeValue};
+
+/// The integer widths share one implementation of every operation,
+/// each computed and overflow checked in its own width
+pub(crate) trait Integer:
+    Copy
+    + Ord
+    + Display
+    + Into<i64>
+    + TryFrom<i64>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Rem<Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+{
+    const RUNTIME_TYPE: RuntimeType;
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+    const BITS: u32;
+
+    fn from_value(value: RuntimeValue) -> Option<Self>;
+    fn into_value(self) -> RuntimeValue;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_sub(self, other: Self) -> Self;
+    fn wrapping_mul(self, other: Self) -> Self;
+    fn wrapping_rem(self, other: Self) -> Self;
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_sub(self, other: Self) -> Self;
+    fn saturating_mul(self, other: Self) -> Self;
+}
+
+macro_rules! integer {
+    ($integer:ty, $variant:ident, $runtime_type:ident) => {
+        impl Integer for $integer {
+            const RUNTIME_TYPE: RuntimeType = RuntimeType::$runtime_type;
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MIN: Self = <$integer>::MIN;
+            const MAX: Self = <$integer>::MAX;
+            const BITS: u32 = <$integer>::BITS;
+
+            fn from_value(value: RuntimeValue) -> Option<Self> {
+                match value {
+                    RuntimeValue::$variant(n) => Some(n),
+                    _ => None,
+                }
+            }
+
+            fn into_value(self) -> RuntimeValue {
+                RuntimeValue::$variant(self)
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$integer>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$integer>::checked_sub(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$integer>::checked_mul(self, other)
+            }
+
+            fn checked_div(self, other: Self) -> Option<Self> {
+                <$integer>::checked_div(self, other)
+            }
+
+            fn wrapping_add(self, other: Self) -> Self {
+                <$integer>::wrapping_add(self, other)
+            }
+
+            fn wrapping_sub(self, other: Self) -> Self {
+                <$integer>::wrapping_sub(self, other)
+            }
+
+            fn wrapping_mul(self, other: Self) -> Self {
+                <$integer>::wrapping_mul(self, other)
+            }
+
+            fn wrapping_rem(self, other: Self) -> Self {
+                <$integer>::wrapping_rem(self, other)
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                <$integer>::saturating_add(self, other)
+            }
+
+            fn saturating_sub(self, other: Self) -> Self {
+                <$integer>::saturating_sub(self, other)
+            }
+
+            fn saturating_mul(self, other: Self) -> Self {
+                <$integer>::saturating_mul(self, other)
+            }
+        }
+    };
+}
+
+integer!(i32, IntegerLiteral, Integer);
+integer!(i64, Int64Literal, Int64);
+integer!(u8, ByteLiteral, Byte);
@@ -1,6 +1,16 @@
 pub mod arithmetic;
+pub mod collections;
 pub mod comparison;
+pub mod control_flow;
+pub mod conversions;
+pub mod enums;
+pub mod functions;
+pub mod heap;
 pub mod helpers;
+pub mod integers;
 pub mod loaders;
+pub mod structs;
+pub mod tuples;
 pub mod unary;
+pub mod variables;
 pub mod vm;
@@ -0,0 +1,59 @@
+use crate::{
+    bytecode::bytecode::read_uint16,
+    errors::HydorError,
+    hydor_vm::{
+        heap::{HeapObject, Instance},
+        vm::HydorVM,
+    },
+    runtime_value::RuntimeValue,
+    utils::Span,
+};
+
+impl HydorVM {
+    /// Collect the top `count` values into a new struct, first field deepest
+    pub(crate) fn build_struct(&mut self, span: Span) -> Result<(), HydorError> {
+        let name = read_uint16(&self.instructions, self.ip + 1) as usize;
+        let count = read_uint16(&self.instructions, self.ip + 3) as usize;
+        self.ip += 4;
+
+        let mut fields = Vec::with_capacity(count);
+        for _ in 0..count {
+            fields.push(self.pop_value()?);
+        }
+        fields.reverse();
+
+        let index = self.allocate(HeapObject::Struct(Instance { name, fields }));
+        self.push(RuntimeValue::Struct(index), span)
+    }
+
+    pub(crate) fn get_field(&mut self, span: Span) -> Result<(), HydorError> {
+        let field = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let instance = self.pop_instance()?;
+        let value = self.instance(instance).fields[field];
+        self.push(value, span)
+    }
+
+    /// Assignment is an expression, so the stored value is pushed back
+    pub(crate) fn set_field(&mut self, span: Span) -> Result<(), HydorError> {
+        let field = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let value = self.pop_value()?;
+        let instance = self.pop_instance()?;
+
+        self.instance_mut(instance).fields[field] = value;
+        self.push(value, span)
+    }
+
+    fn pop_instance(&mut self) -> Result<usize, HydorError> {
+        match self.pop_value()? {
+            RuntimeValue::Struct(instance) => Ok(instance),
+            other => unreachable!(
+                "Field access on non-structs should be caught in type checker, found {:?}",
+                other
+            ),
+        }
+    }
+}
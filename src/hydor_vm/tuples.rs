@@ -0,0 +1,54 @@
+use crate::{
+    bytecode::bytecode::read_uint16,
+    errors::HydorError,
+    hydor_vm::{heap::HeapObject, vm::HydorVM},
+    runtime_value::RuntimeValue,
+    utils::Span,
+};
+
+impl HydorVM {
+    /// Collect the top `count` values into a new tuple, first element deepest
+    pub(crate) fn build_tuple(&mut self, span: Span) -> Result<(), HydorError> {
+        let count = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let mut elements = Vec::with_capacity(count);
+        for _ in 0..count {
+            elements.push(self.pop_value()?);
+        }
+        elements.reverse();
+
+        let index = self.allocate(HeapObject::Tuple(elements));
+        self.push(RuntimeValue::Tuple(index), span)
+    }
+
+    pub(crate) fn get_element(&mut self, span: Span) -> Result<(), HydorError> {
+        let element = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let tuple = self.pop_tuple()?;
+        let value = self.tuple(tuple)[element];
+        self.push(value, span)
+    }
+
+    /// Replace the tuple with its elements, first element deepest
+    pub(crate) fn unpack_tuple(&mut self, span: Span) -> Result<(), HydorError> {
+        self.ip += 2; // The tuple itself knows how many elements it has
+
+        let tuple = self.pop_tuple()?;
+        for value in self.tuple(tuple).clone() {
+            self.push(value, span)?;
+        }
+        Ok(())
+    }
+
+    fn pop_tuple(&mut self) -> Result<usize, HydorError> {
+        match self.pop_value()? {
+            RuntimeValue::Tuple(tuple) => Ok(tuple),
+            other => unreachable!(
+                "Tuple operations on non-tuples should be caught in type checker, found {:?}",
+                other
+            ),
+        }
+    }
+}
@@ -1,18 +1,31 @@
 use crate::{
     bytecode::bytecode::OpCode,
     errors::HydorError,
-    hydor_vm::vm::{BOOLEAN_FALSE, BOOLEAN_TRUE, HydorVM},
+    hydor_vm::{
+        integers::Integer,
+        vm::{BOOLEAN_FALSE, BOOLEAN_TRUE, HydorVM},
+    },
     runtime_value::RuntimeValue,
+    tokens::TokenType,
     utils::Span,
 };
 
 impl HydorVM {
     pub(crate) fn unary_operation(&mut self, opcode: OpCode, span: Span) -> Result<(), HydorError> {
         match opcode {
-            OpCode::UnaryNegateInt | OpCode::UnaryNegateFloat => {
+            OpCode::UnaryNegateInt | OpCode::UnaryNegateInt64 | OpCode::UnaryNegateFloat => {
                 self.unary_negation_operation(span, opcode)
             }
             OpCode::UnaryNot => self.unary_not_operation(),
+            OpCode::BitwiseNotInt | OpCode::BitwiseNotInt64 | OpCode::BitwiseNotByte => {
+                let inverted = match self.peek_offset(0)? {
+                    RuntimeValue::IntegerLiteral(n) => RuntimeValue::IntegerLiteral(!n),
+                    RuntimeValue::Int64Literal(n) => RuntimeValue::Int64Literal(!n),
+                    RuntimeValue::ByteLiteral(n) => RuntimeValue::ByteLiteral(!n),
+                    _ => unreachable!("Type checker should catch bitwise not on non-integers"),
+                };
+                self.set_offset_value(0, inverted)
+            }
 
             _ => unreachable!(),
         }
@@ -32,7 +45,13 @@ impl HydorVM {
         match opcode {
             OpCode::UnaryNegateInt => {
                 let int = target.as_int().unwrap();
-                self.set_offset_value(0, RuntimeValue::IntegerLiteral(-int))?;
+                let negated = Self::checked(int.checked_neg(), TokenType::Minus, span)?;
+                self.set_offset_value(0, RuntimeValue::IntegerLiteral(negated))?;
+            }
+            OpCode::UnaryNegateInt64 => {
+                let int = i64::from_value(target).unwrap();
+                let negated = Self::checked(int.checked_neg(), TokenType::Minus, span)?;
+                self.set_offset_value(0, RuntimeValue::Int64Literal(negated))?;
             }
             OpCode::UnaryNegateFloat => {
                 let float = target.as_float().unwrap();
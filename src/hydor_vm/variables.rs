@@ -0,0 +1,69 @@
+use crate::{
+    bytecode::bytecode::read_uint16,
+    errors::HydorError,
+    hydor_vm::vm::{HydorVM, NIL_LITERAL},
+    utils::Span,
+};
+
+impl HydorVM {
+    pub(crate) fn define_global(&mut self) -> Result<(), HydorError> {
+        let slot = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let value = self.pop_value()?;
+
+        if slot >= self.globals.len() {
+            self.globals.resize(slot + 1, NIL_LITERAL);
+        }
+        self.globals[slot] = value;
+
+        Ok(())
+    }
+
+    pub(crate) fn load_global(&mut self, span: Span) -> Result<(), HydorError> {
+        let slot = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        // Slots that were never defined at runtime read as nil
+        let value = self.globals.get(slot).copied().unwrap_or(NIL_LITERAL);
+        self.push(value, span)?;
+
+        Ok(())
+    }
+
+    pub(crate) fn store_global(&mut self) -> Result<(), HydorError> {
+        let slot = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        // Assignment is an expression, so the value stays on the stack
+        let value = self.peek_offset(0)?;
+
+        if slot >= self.globals.len() {
+            self.globals.resize(slot + 1, NIL_LITERAL);
+        }
+        self.globals[slot] = value;
+
+        Ok(())
+    }
+
+    pub(crate) fn load_local(&mut self, span: Span) -> Result<(), HydorError> {
+        let slot = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        let value = self.get_stack_slot(self.frame_base() + slot)?;
+        self.push(value, span)?;
+
+        Ok(())
+    }
+
+    pub(crate) fn store_local(&mut self) -> Result<(), HydorError> {
+        let slot = read_uint16(&self.instructions, self.ip + 1) as usize;
+        self.ip += 2;
+
+        // Assignment is an expression, so the value stays on the stack
+        let value = self.peek_offset(0)?;
+        self.set_stack_slot(self.frame_base() + slot, value)?;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
-    bytecode::bytecode::{Instructions, OpCode, ToOpcode},
+    bytecode::bytecode::{Instructions, OpCode, ToOpcode, read_uint8},
     compiler::compiler::{Bytecode, DebugInfo},
     errors::HydorError,
+    hydor_vm::heap::HeapObject,
     runtime_value::RuntimeValue,
     utils::Span,
 };
@@ -17,10 +18,26 @@ pub struct HydorVM {
 
     pub string_table: Vec<String>,
     pub constants: Vec<RuntimeValue>,
+    pub globals: Vec<RuntimeValue>,
+    pub(crate) frames: Vec<CallFrame>,
+    pub(crate) heap: Vec<HeapObject>,
+    /// Heap indices of upvalues still pointing into the stack
+    pub(crate) open_upvalues: Vec<usize>,
 
     debug_info: DebugInfo,
 }
 
+/// Bookkeeping for an active function call
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CallFrame {
+    /// Instruction to resume at once the call returns
+    pub return_ip: usize,
+    /// Stack index of the callee, locals are addressed relative to it
+    pub base: usize,
+    /// Heap index of the running closure
+    pub closure: Option<usize>,
+}
+
 #[derive(Debug, Clone, Copy)]
 struct StackValue {
     value: RuntimeValue,
@@ -43,6 +60,10 @@ impl HydorVM {
             ip: 0,
 
             constants: bytecode.constants,
+            globals: Vec::new(),
+            frames: Vec::new(),
+            heap: Vec::new(),
+            open_upvalues: Vec::new(),
             debug_info: bytecode.debug_info,
         }
     }
@@ -65,13 +86,72 @@ impl HydorVM {
                 OpCode::MultiplyInt | OpCode::MultiplyFloat => self.binary_op(opcode, span)?,
                 OpCode::DivideInt | OpCode::DivideFloat => self.binary_op(opcode, span)?,
                 OpCode::ExponentInt | OpCode::ExponentFloat => self.binary_op(opcode, span)?,
+                OpCode::AddInt64
+                | OpCode::SubtractInt64
+                | OpCode::MultiplyInt64
+                | OpCode::DivideInt64
+                | OpCode::ExponentInt64
+                | OpCode::ModuloInt64
+                | OpCode::FloorDivideInt64
+                | OpCode::BitwiseAndInt64
+                | OpCode::BitwiseOrInt64
+                | OpCode::BitwiseXorInt64
+                | OpCode::ShiftLeftInt64
+                | OpCode::ShiftRightInt64
+                | OpCode::WrappingAddInt64
+                | OpCode::WrappingSubtractInt64
+                | OpCode::WrappingMultiplyInt64
+                | OpCode::SaturatingAddInt64
+                | OpCode::SaturatingSubtractInt64
+                | OpCode::SaturatingMultiplyInt64 => self.binary_op(opcode, span)?,
+                OpCode::AddByte
+                | OpCode::SubtractByte
+                | OpCode::MultiplyByte
+                | OpCode::DivideByte
+                | OpCode::ExponentByte
+                | OpCode::ModuloByte
+                | OpCode::FloorDivideByte
+                | OpCode::BitwiseAndByte
+                | OpCode::BitwiseOrByte
+                | OpCode::BitwiseXorByte
+                | OpCode::ShiftLeftByte
+                | OpCode::ShiftRightByte
+                | OpCode::WrappingAddByte
+                | OpCode::WrappingSubtractByte
+                | OpCode::WrappingMultiplyByte
+                | OpCode::SaturatingAddByte
+                | OpCode::SaturatingSubtractByte
+                | OpCode::SaturatingMultiplyByte => self.binary_op(opcode, span)?,
+                OpCode::WrappingAddInt
+                | OpCode::WrappingSubtractInt
+                | OpCode::WrappingMultiplyInt
+                | OpCode::SaturatingAddInt
+                | OpCode::SaturatingSubtractInt
+                | OpCode::SaturatingMultiplyInt => self.binary_op(opcode, span)?,
+                OpCode::ModuloInt
+                | OpCode::FloorDivideInt
+                | OpCode::BitwiseAndInt
+                | OpCode::BitwiseOrInt
+                | OpCode::BitwiseXorInt
+                | OpCode::ShiftLeftInt
+                | OpCode::ShiftRightInt => self.binary_op(opcode, span)?,
 
                 OpCode::ConcatString => self.string_concat(span)?,
 
-                OpCode::UnaryNegateInt | OpCode::UnaryNegateFloat => {
+                OpCode::CastIntToFloat | OpCode::CastFloatToInt | OpCode::CastToString => {
+                    self.cast(opcode, span)?
+                }
+                OpCode::CastToInt | OpCode::CastToInt64 | OpCode::CastToByte => {
+                    self.cast(opcode, span)?
+                }
+
+                OpCode::UnaryNegateInt | OpCode::UnaryNegateInt64 | OpCode::UnaryNegateFloat => {
                     self.unary_operation(opcode, span)?
                 }
-                OpCode::UnaryNot => self.unary_operation(opcode, span)?,
+                OpCode::UnaryNot
+                | OpCode::BitwiseNotInt
+                | OpCode::BitwiseNotInt64
+                | OpCode::BitwiseNotByte => self.unary_operation(opcode, span)?,
 
                 OpCode::CompareLessInt
                 | OpCode::CompareLessFloat
@@ -81,9 +161,83 @@ impl HydorVM {
                 | OpCode::CompareGreaterFloat
                 | OpCode::CompareGreaterEqualInt
                 | OpCode::CompareGreaterEqualFloat
+                | OpCode::CompareLessInt64
+                | OpCode::CompareLessEqualInt64
+                | OpCode::CompareGreaterInt64
+                | OpCode::CompareGreaterEqualInt64
+                | OpCode::CompareLessByte
+                | OpCode::CompareLessEqualByte
+                | OpCode::CompareGreaterByte
+                | OpCode::CompareGreaterEqualByte
                 | OpCode::CompareEqual
                 | OpCode::CompareNotEqual => self.compare_operation(opcode, span)?,
 
+                OpCode::DefineGlobal => self.define_global()?,
+                OpCode::LoadGlobal => self.load_global(span)?,
+                OpCode::StoreGlobal => self.store_global()?,
+                OpCode::LoadLocal => self.load_local(span)?,
+                OpCode::StoreLocal => self.store_local()?,
+
+                // Jumps set the instruction pointer themselves
+                OpCode::Jump | OpCode::Loop => {
+                    self.jump();
+                    continue;
+                }
+                OpCode::JumpIfFalse => {
+                    if self.jump_if_false()? {
+                        continue;
+                    }
+                }
+                OpCode::JumpIfNil => {
+                    if self.jump_if_nil()? {
+                        continue;
+                    }
+                }
+                OpCode::CheckRangeStepInt
+                | OpCode::CheckRangeStepInt64
+                | OpCode::CheckRangeStepByte => self.check_range_step()?,
+
+                // Calls and returns set the instruction pointer themselves
+                OpCode::Call => {
+                    self.call(span)?;
+                    continue;
+                }
+                OpCode::Return => {
+                    self.return_from_call(span)?;
+                    continue;
+                }
+
+                OpCode::Closure => self.make_closure(span)?,
+                OpCode::CaptureLocal => self.capture_local()?,
+                OpCode::CaptureUpvalue => self.capture_upvalue()?,
+                OpCode::GetUpvalue => self.get_upvalue(span)?,
+                OpCode::SetUpvalue => self.store_upvalue()?,
+                OpCode::CloseUpvalue => {
+                    self.close_upvalues(self.stack_len() - 1);
+                    self.pop_value()?;
+                }
+
+                OpCode::BuildArray => self.build_array(span)?,
+                OpCode::GetIndex => self.get_index(span)?,
+                OpCode::SetIndex => self.set_index(span)?,
+
+                OpCode::BuildMap => self.build_map(span)?,
+                OpCode::MapHas => self.map_has(span)?,
+                OpCode::MapRemove => self.map_remove(span)?,
+                OpCode::MapKeys => self.map_keys(span)?,
+
+                OpCode::BuildStruct => self.build_struct(span)?,
+                OpCode::GetField => self.get_field(span)?,
+                OpCode::SetField => self.set_field(span)?,
+
+                OpCode::BuildVariant => self.build_variant(span)?,
+                OpCode::IsVariant => self.is_variant(span)?,
+                OpCode::GetPayload => self.get_payload(span)?,
+                OpCode::BuildTuple => self.build_tuple(span)?,
+                OpCode::GetElement => self.get_element(span)?,
+                OpCode::UnpackTuple => self.unpack_tuple(span)?,
+
+                OpCode::Duplicate => self.duplicate()?,
                 OpCode::Pop => {
                     self.last_pop = Some(self.pop_value()?);
                 }
@@ -149,6 +303,61 @@ impl HydorVM {
         Ok(())
     }
 
+    /// Stack index where the locals of the current call frame begin
+    pub(crate) fn frame_base(&self) -> usize {
+        self.frames.last().map_or(0, |frame| frame.base)
+    }
+
+    /// Push a copy of the top `count` values, in the same order and
+    /// keeping their spans
+    pub(crate) fn duplicate(&mut self) -> Result<(), HydorError> {
+        let count = read_uint8(&self.instructions, self.ip + 1) as usize;
+        self.ip += 1;
+
+        for _ in 0..count {
+            let value = self.peek_offset(count - 1)?;
+            let span = self.peek_span(count - 1)?;
+            self.push(value, span)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn stack_len(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub(crate) fn truncate_stack(&mut self, len: usize) {
+        self.stack.truncate(len);
+    }
+
+    pub(crate) fn get_stack_slot(&self, slot: usize) -> Result<RuntimeValue, HydorError> {
+        match self.stack.get(slot) {
+            Some(sv) => Ok(sv.value),
+            None => Err(HydorError::StackUnderflow {
+                stack_length: self.stack.len(),
+                span: Span::default(),
+            }),
+        }
+    }
+
+    pub(crate) fn set_stack_slot(
+        &mut self,
+        slot: usize,
+        new_value: RuntimeValue,
+    ) -> Result<(), HydorError> {
+        let size = self.stack.len();
+        match self.stack.get_mut(slot) {
+            Some(sv) => {
+                sv.value = new_value;
+                Ok(())
+            }
+            None => Err(HydorError::StackUnderflow {
+                stack_length: size,
+                span: Span::default(),
+            }),
+        }
+    }
+
     pub(crate) fn pop_value(&mut self) -> Result<RuntimeValue, HydorError> {
         self.stack
             .pop()
@@ -65,12 +65,18 @@ impl Lexer {
     fn read_number(&mut self) -> Token {
         let mut num = String::new();
         let mut is_float = false;
+        // `pair.0.1` indexes twice, it is not the float `0.1`
+        let is_tuple_index = matches!(self.last_token, Some(Token::Dot | Token::QuestionDot));
 
         while let Some(ch) = self.current() {
             if ch.is_ascii_digit() {
                 num.push(ch);
                 self.advance();
-            } else if ch == '.' && !is_float && self.peek(1).map_or(false, |c| c.is_ascii_digit()) {
+            } else if ch == '.'
+                && !is_float
+                && !is_tuple_index
+                && self.peek(1).map_or(false, |c| c.is_ascii_digit())
+            {
                 is_float = true;
                 num.push(ch);
                 self.advance();
@@ -82,7 +88,7 @@ impl Lexer {
         if is_float {
             Token::Float(num.parse().unwrap_or(0.0))
         } else {
-            Token::Integer(num.parse().unwrap_or(0))
+            Token::Integer(num.parse().unwrap_or(u64::MAX))
         }
     }
 
@@ -187,10 +193,6 @@ impl Lexer {
                 self.advance();
                 Token::RightBracket
             }
-            Some('.') => {
-                self.advance();
-                Token::Dot
-            }
             Some(',') => {
                 self.advance();
                 Token::Comma
@@ -199,28 +201,98 @@ impl Lexer {
                 self.advance();
                 Token::Semicolon
             }
-            Some('+') => {
+            Some('&') => {
+                self.advance();
+                Token::Ampersand
+            }
+            Some('|') => {
+                self.advance();
+                Token::Pipe
+            }
+            Some('~') => {
                 self.advance();
-                Token::Plus
+                Token::Tilde
+            }
+
+            // Multi-char tokens
+            Some('.') => {
+                self.advance();
+                if self.current() == Some('.') {
+                    self.advance();
+                    if self.current() == Some('=') {
+                        self.advance();
+                        Token::DotDotEqual
+                    } else {
+                        Token::DotDot
+                    }
+                } else {
+                    Token::Dot
+                }
             }
+
             Some('-') => {
                 self.advance();
-                Token::Minus
+                if self.current() == Some('>') {
+                    self.advance();
+                    Token::Arrow
+                } else if self.current() == Some('=') {
+                    self.advance();
+                    Token::MinusAssign
+                } else {
+                    Token::Minus
+                }
             }
+
+            Some('+') => {
+                self.advance();
+                if self.current() == Some('=') {
+                    self.advance();
+                    Token::PlusAssign
+                } else {
+                    Token::Plus
+                }
+            }
+
             Some('*') => {
                 self.advance();
-                Token::Asterisk
+                if self.current() == Some('=') {
+                    self.advance();
+                    Token::AsteriskAssign
+                } else {
+                    Token::Asterisk
+                }
             }
+
             Some('/') => {
                 self.advance();
-                Token::Slash
+                if self.current() == Some('=') {
+                    self.advance();
+                    Token::SlashAssign
+                } else {
+                    Token::Slash
+                }
             }
+
             Some('^') => {
                 self.advance();
-                Token::Caret
+                if self.current() == Some('=') {
+                    self.advance();
+                    Token::CaretAssign
+                } else {
+                    Token::Caret
+                }
+            }
+
+            Some('%') => {
+                self.advance();
+                if self.current() == Some('=') {
+                    self.advance();
+                    Token::PercentAssign
+                } else {
+                    Token::Percent
+                }
             }
 
-            // Two-char tokens
             Some(':') => {
                 self.advance();
                 if self.current() == Some(':') {
@@ -236,6 +308,9 @@ impl Lexer {
                 if self.current() == Some('=') {
                     self.advance();
                     Token::Equal
+                } else if self.current() == Some('>') {
+                    self.advance();
+                    Token::FatArrow
                 } else {
                     Token::Assign
                 }
@@ -256,6 +331,9 @@ impl Lexer {
                 if self.current() == Some('=') {
                     self.advance();
                     Token::LessThanEqual
+                } else if self.current() == Some('<') {
+                    self.advance();
+                    Token::ShiftLeft
                 } else {
                     Token::LessThan
                 }
@@ -266,11 +344,27 @@ impl Lexer {
                 if self.current() == Some('=') {
                     self.advance();
                     Token::GreaterThanEqual
+                } else if self.current() == Some('>') {
+                    self.advance();
+                    Token::ShiftRight
                 } else {
                     Token::GreaterThan
                 }
             }
 
+            Some('?') => {
+                self.advance();
+                if self.current() == Some('?') {
+                    self.advance();
+                    Token::QuestionQuestion
+                } else if self.current() == Some('.') {
+                    self.advance();
+                    Token::QuestionDot
+                } else {
+                    Token::Question
+                }
+            }
+
             // String literals
             Some('"') | Some('\'') => self.read_string(),
 
@@ -11,9 +11,15 @@ pub enum Precedence {
     LogicalAnd,
     Equals,
     Comparison,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseAnd,
+    NilCoalescing,
+    Shift,
     Additive,
     Multiplicative,
     Exponent,
+    Cast,
     Unary,
     Call,
     Grouping,
@@ -22,7 +28,13 @@ pub enum Precedence {
 impl Precedence {
     pub fn get_token_precedence(token_type: &TokenType) -> Option<Precedence> {
         match token_type {
-            TokenType::Assign => Some(Precedence::Assignment),
+            TokenType::Assign
+            | TokenType::PlusAssign
+            | TokenType::MinusAssign
+            | TokenType::AsteriskAssign
+            | TokenType::SlashAssign
+            | TokenType::CaretAssign
+            | TokenType::PercentAssign => Some(Precedence::Assignment),
             TokenType::If => Some(Precedence::Ternary),
             TokenType::Or => Some(Precedence::LogicalOr),
             TokenType::And => Some(Precedence::LogicalAnd),
@@ -31,10 +43,22 @@ impl Precedence {
             | TokenType::LessThanEqual
             | TokenType::GreaterThan
             | TokenType::GreaterThanEqual => Some(Precedence::Comparison),
+            TokenType::Pipe => Some(Precedence::BitwiseOr),
+            TokenType::Xor => Some(Precedence::BitwiseXor),
+            TokenType::Ampersand => Some(Precedence::BitwiseAnd),
+            TokenType::QuestionQuestion => Some(Precedence::NilCoalescing),
+            TokenType::ShiftLeft | TokenType::ShiftRight => Some(Precedence::Shift),
             TokenType::Plus | TokenType::Minus => Some(Precedence::Additive),
-            TokenType::Asterisk | TokenType::Slash => Some(Precedence::Multiplicative),
+            TokenType::Asterisk | TokenType::Slash | TokenType::Percent | TokenType::Div => {
+                Some(Precedence::Multiplicative)
+            }
             TokenType::Caret => Some(Precedence::Exponent),
-            TokenType::LeftParenthesis | TokenType::BoxColon => Some(Precedence::Call),
+            TokenType::As => Some(Precedence::Cast),
+            TokenType::LeftParenthesis
+            | TokenType::LeftBracket
+            | TokenType::Dot
+            | TokenType::QuestionDot
+            | TokenType::BoxColon => Some(Precedence::Call),
             TokenType::Not => Some(Precedence::Unary),
             _ => None,
         }
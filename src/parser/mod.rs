@@ -1,3 +1,4 @@
 pub mod lookups;
 pub mod parser;
+pub mod pattern_parser;
 pub mod type_parser;
@@ -1,7 +1,13 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, mem};
 
 use crate::{
-    ast::ast::{Expr, Expression, Program, Statement, Stmt},
+    ast::{
+        ast::{
+            ChainAccess, Expr, Expression, Field, FieldInit, MatchArm, Parameter, Program,
+            Statement, Stmt, Variant,
+        },
+        type_annotation::TypeAnnotation,
+    },
     errors::{ErrorCollector, HydorError},
     parser::lookups::Precedence,
     tokens::{Token, TokenInfo, TokenType},
@@ -15,7 +21,10 @@ type StatementParseFn = fn(&mut Parser) -> Option<Statement>;
 pub struct Parser {
     tokens: Vec<TokenInfo>,
     current: usize,
-    delimiter_stack: Vec<TokenType>,
+    pub(crate) delimiter_stack: Vec<TokenType>,
+    /// Cleared while parsing the expression in front of a block, so that
+    /// `if ready { ... }` is not read as a struct literal
+    struct_literals_allowed: bool,
 
     pub led_parse_fns: HashMap<TokenType, InfixParseFn>,
     pub nud_parse_fns: HashMap<TokenType, PrefixParseFn>,
@@ -31,6 +40,7 @@ impl Parser {
             current: 0,
             errors: ErrorCollector::new(),
             delimiter_stack: Vec::new(),
+            struct_literals_allowed: true,
 
             led_parse_fns: HashMap::new(),
             nud_parse_fns: HashMap::new(),
@@ -47,12 +57,24 @@ impl Parser {
 
         parser.register_nud(TokenType::Minus, Parser::parse_unary_expr);
         parser.register_nud(TokenType::Not, Parser::parse_unary_expr);
+        parser.register_nud(TokenType::Tilde, Parser::parse_unary_expr);
         parser.register_nud(TokenType::LeftParenthesis, Parser::parse_grouping_expr);
+        parser.register_nud(TokenType::Function, Parser::parse_function_expr);
+        parser.register_nud(TokenType::LeftBracket, Parser::parse_array_literal);
+        parser.register_nud(TokenType::LeftBrace, Parser::parse_map_literal);
+        parser.register_nud(TokenType::Match, Parser::parse_match_expr);
 
         parser.register_led(TokenType::Plus, Parser::parse_binary_expr);
         parser.register_led(TokenType::Minus, Parser::parse_binary_expr);
         parser.register_led(TokenType::Asterisk, Parser::parse_binary_expr);
         parser.register_led(TokenType::Slash, Parser::parse_binary_expr);
+        parser.register_led(TokenType::Percent, Parser::parse_binary_expr);
+        parser.register_led(TokenType::Div, Parser::parse_binary_expr);
+        parser.register_led(TokenType::Ampersand, Parser::parse_binary_expr);
+        parser.register_led(TokenType::Pipe, Parser::parse_binary_expr);
+        parser.register_led(TokenType::Xor, Parser::parse_binary_expr);
+        parser.register_led(TokenType::ShiftLeft, Parser::parse_binary_expr);
+        parser.register_led(TokenType::ShiftRight, Parser::parse_binary_expr);
         parser.register_led(TokenType::Caret, Parser::parse_exponent_expr);
 
         parser.register_led(TokenType::LessThan, Parser::parse_binary_expr);
@@ -62,7 +84,37 @@ impl Parser {
         parser.register_led(TokenType::Equal, Parser::parse_binary_expr);
         parser.register_led(TokenType::NotEqual, Parser::parse_binary_expr);
 
+        parser.register_led(TokenType::And, Parser::parse_binary_expr);
+        parser.register_led(TokenType::Or, Parser::parse_binary_expr);
+        parser.register_led(TokenType::QuestionQuestion, Parser::parse_binary_expr);
+
+        parser.register_led(TokenType::Assign, Parser::parse_assignment_expr);
+        parser.register_led(TokenType::PlusAssign, Parser::parse_assignment_expr);
+        parser.register_led(TokenType::MinusAssign, Parser::parse_assignment_expr);
+        parser.register_led(TokenType::AsteriskAssign, Parser::parse_assignment_expr);
+        parser.register_led(TokenType::SlashAssign, Parser::parse_assignment_expr);
+        parser.register_led(TokenType::CaretAssign, Parser::parse_assignment_expr);
+        parser.register_led(TokenType::PercentAssign, Parser::parse_assignment_expr);
+        parser.register_led(TokenType::As, Parser::parse_cast_expr);
+        parser.register_led(TokenType::If, Parser::parse_conditional_expr);
+        parser.register_led(TokenType::LeftParenthesis, Parser::parse_call_expr);
+        parser.register_led(TokenType::LeftBracket, Parser::parse_index_expr);
+        parser.register_led(TokenType::Dot, Parser::parse_member_expr);
+        parser.register_led(TokenType::QuestionDot, Parser::parse_member_expr);
+        parser.register_led(TokenType::BoxColon, Parser::parse_variant_expr);
+
         parser.register_stmt(TokenType::Let, Parser::parse_variable_decl);
+        parser.register_stmt(TokenType::Const, Parser::parse_variable_decl);
+        parser.register_stmt(TokenType::LeftBrace, Parser::parse_block_statement);
+        parser.register_stmt(TokenType::If, Parser::parse_if_statement);
+        parser.register_stmt(TokenType::While, Parser::parse_while_statement);
+        parser.register_stmt(TokenType::For, Parser::parse_for_statement);
+        parser.register_stmt(TokenType::Break, Parser::parse_break_statement);
+        parser.register_stmt(TokenType::Continue, Parser::parse_continue_statement);
+        parser.register_stmt(TokenType::Function, Parser::parse_function_declaration);
+        parser.register_stmt(TokenType::Return, Parser::parse_return_statement);
+        parser.register_stmt(TokenType::Struct, Parser::parse_struct_declaration);
+        parser.register_stmt(TokenType::Enum, Parser::parse_enum_declaration);
 
         parser
     }
@@ -122,7 +174,7 @@ impl Parser {
         }
     }
 
-    fn expect(&mut self, token_type: TokenType) -> bool {
+    pub(crate) fn expect(&mut self, token_type: TokenType) -> bool {
         if self.current_token().token.get_token_type() != token_type {
             self.errors.add(HydorError::ExpectedToken {
                 expected: token_type,
@@ -136,6 +188,20 @@ impl Parser {
         true
     }
 
+    /// Expect the `>` closing a list of type arguments. In `Box<Box<Int>>`
+    /// the lexer reads `>>` as one token, so only its first half is eaten
+    pub(crate) fn expect_closing_angle(&mut self) -> bool {
+        if let Some(current) = self.tokens.get_mut(self.current)
+            && current.token == Token::ShiftRight
+        {
+            current.token = Token::GreaterThan;
+            current.span.start_column += 1;
+            return true;
+        }
+
+        self.expect(TokenType::GreaterThan)
+    }
+
     fn expect_delimiter(&mut self) -> bool {
         // If we're inside delimiters (parentheses, etc.), delimiters are optional
         if !self.delimiter_stack.is_empty() {
@@ -155,6 +221,9 @@ impl Parser {
                 true
             }
 
+            // The closing brace of a block also ends its last statement
+            TokenType::RightBrace => true,
+
             _ => {
                 self.errors.add(HydorError::ExpectedToken {
                     expected: TokenType::Semicolon,
@@ -166,7 +235,7 @@ impl Parser {
         }
     }
 
-    fn skip_newlines_in_delimiters(&mut self) {
+    pub(crate) fn skip_newlines_in_delimiters(&mut self) {
         if !self.delimiter_stack.is_empty() {
             self.skip_delimiters();
         }
@@ -190,6 +259,37 @@ impl Parser {
             .unwrap_or_else(|| self.tokens.last().expect("Token vector is empty!"))
     }
 
+    /// Peek past any newlines and return the type of the next meaningful token
+    fn peek_past_newlines(&self) -> TokenType {
+        self.tokens[self.current..]
+            .iter()
+            .map(|t| t.token.get_token_type())
+            .find(|t| *t != TokenType::Newline)
+            .unwrap_or(TokenType::EndOfFile)
+    }
+
+    pub(crate) fn peek_token_type(&self, offset: usize) -> TokenType {
+        self.tokens
+            .get(self.current + offset)
+            .map(|t| t.token.get_token_type())
+            .unwrap_or(TokenType::EndOfFile)
+    }
+
+    /// Extend `start` up to the end of the most recently consumed token
+    pub(crate) fn span_to_previous(&self, start: Span) -> Span {
+        let end_column = self
+            .tokens
+            .get(self.current.saturating_sub(1))
+            .map(|t| t.span.end_column)
+            .unwrap_or(start.end_column);
+
+        Span {
+            line: start.line,
+            start_column: start.start_column,
+            end_column,
+        }
+    }
+
     fn is_eof(&self) -> bool {
         self.current_token().token == Token::EndOfFile || self.current >= self.tokens.len()
     }
@@ -240,8 +340,22 @@ impl Parser {
     fn try_parse_statement(&mut self) -> Option<Statement> {
         let stmt_type = self.current_token().token.get_token_type();
 
+        // Labeled loops look like `outer: while ...`
+        if stmt_type == TokenType::Identifier
+            && self.peek_token_type(1) == TokenType::Colon
+            && matches!(self.peek_token_type(2), TokenType::While | TokenType::For)
+        {
+            return self.parse_labeled_loop();
+        }
+
+        // `fn (...)` starts an anonymous function expression, not a declaration
+        let is_function_expr = stmt_type == TokenType::Function
+            && self.peek_token_type(1) == TokenType::LeftParenthesis;
+
         // Try to parse as a statement keyword
-        if let Some(stmt_fn) = self.stmt_parse_fns.get(&stmt_type) {
+        if let Some(stmt_fn) = self.stmt_parse_fns.get(&stmt_type)
+            && !is_function_expr
+        {
             return stmt_fn(self);
         }
 
@@ -270,16 +384,37 @@ impl Parser {
 impl Parser {
     // ------------------- Null Denoted Expressions -------------------
     pub fn parse_integer_literal(&mut self) -> Option<Expression> {
-        let token_info = self.current_token();
-        let value = match token_info.token {
+        let span = self.current_token().span;
+        self.parse_signed_integer_literal(false, span)
+    }
+
+    /// The literal under the current token, `start` is where its sign begins
+    fn parse_signed_integer_literal(&mut self, negative: bool, start: Span) -> Option<Expression> {
+        let digits = match self.current_token().token {
             Token::Integer(n) => n,
             _ => unreachable!(),
         };
 
-        let expr = Expr::IntegerLiteral(value).spanned(token_info.span);
+        let value = self.integer_literal_value(digits, negative)?;
 
         self.advance();
-        Some(expr)
+        Some(Expr::IntegerLiteral(value).spanned(self.span_to_previous(start)))
+    }
+
+    /// The value of an integer literal, which has to fit in an Int64
+    pub(crate) fn integer_literal_value(&mut self, digits: u64, negative: bool) -> Option<i64> {
+        let value = if negative {
+            0i64.checked_sub_unsigned(digits)
+        } else {
+            i64::try_from(digits).ok()
+        };
+
+        if value.is_none() {
+            self.errors.add(HydorError::IntegerLiteralTooLarge {
+                span: self.current_token().span,
+            });
+        }
+        value
     }
 
     pub fn parse_float_literal(&mut self) -> Option<Expression> {
@@ -316,12 +451,78 @@ impl Parser {
             _ => unreachable!(),
         };
 
+        if self.struct_literals_allowed && self.peek_token_type(1) == TokenType::LeftBrace {
+            return self.parse_struct_literal(ident);
+        }
+
         let expr = Expr::Identifier(ident).spanned(token_info.span);
 
         self.advance();
         Some(expr)
     }
 
+    /// `Point { x: 1.0, y: 2.0 }`
+    fn parse_struct_literal(&mut self, name: String) -> Option<Expression> {
+        let start_span = self.current_token().span;
+        self.advance(); // Eat struct name
+        self.advance(); // Eat '{'
+
+        self.delimiter_stack.push(TokenType::LeftBrace);
+        let fields = self.parse_field_inits();
+        self.delimiter_stack.pop();
+
+        let fields = fields?;
+        if !self.expect(TokenType::RightBrace) {
+            return None;
+        }
+
+        Some(Expr::StructLiteral { name, fields }.spanned(self.span_to_previous(start_span)))
+    }
+
+    fn parse_field_inits(&mut self) -> Option<Vec<FieldInit>> {
+        let mut fields = Vec::new();
+
+        loop {
+            self.skip_newlines_in_delimiters();
+
+            let field_tok = self.current_token().clone();
+            let name = match field_tok.token {
+                Token::Identifier(name) => name,
+                _ => break,
+            };
+            self.advance();
+
+            if !self.expect(TokenType::Colon) {
+                return None;
+            }
+            self.skip_newlines_in_delimiters();
+
+            let value = self.try_parse_expression(Precedence::Default.into())?;
+            fields.push(FieldInit {
+                name,
+                value,
+                span: field_tok.span,
+            });
+
+            self.skip_newlines_in_delimiters();
+            if self.current_token().token != Token::Comma {
+                break;
+            }
+            self.advance(); // Eat ','
+        }
+
+        Some(fields)
+    }
+
+    /// Parse the expression in front of a block, like an `if` condition
+    fn parse_block_header_expr(&mut self) -> Option<Expression> {
+        let allowed = mem::replace(&mut self.struct_literals_allowed, false);
+        let expr = self.try_parse_expression(Precedence::Default.into());
+        self.struct_literals_allowed = allowed;
+
+        expr
+    }
+
     pub fn parse_string_literal(&mut self) -> Option<Expression> {
         let token_info = self.current_token();
         let ident = match token_info.token.clone() {
@@ -345,8 +546,20 @@ impl Parser {
 
     pub fn parse_unary_expr(&mut self) -> Option<Expression> {
         let operator_info = self.current_token().clone();
+
+        // A negative literal is range checked with its sign, so the smallest
+        // value of every width can be written. A literal that is called or
+        // indexed, like `-5.wrapping_add(1)`, is negated afterwards instead
+        let negative_literal = operator_info.token == Token::Minus
+            && self.peek_token_type(1) == TokenType::Integer
+            && Precedence::get_token_precedence(&self.peek_token_type(2)) != Some(Precedence::Call);
+
         self.advance(); // Eat operator
 
+        if negative_literal {
+            return self.parse_signed_integer_literal(true, operator_info.span);
+        }
+
         let value = self.try_parse_expression(Precedence::Unary.into())?;
         let val_span = value.span;
 
@@ -363,6 +576,167 @@ impl Parser {
         Some(expr)
     }
 
+    /// `[1, 2, 3]`
+    pub fn parse_array_literal(&mut self) -> Option<Expression> {
+        let start_span = self.current_token().span;
+        self.advance(); // Eat '['
+
+        self.delimiter_stack.push(TokenType::LeftBracket);
+        let elements = self.parse_expression_list(TokenType::RightBracket);
+        self.delimiter_stack.pop();
+
+        let elements = elements?;
+        if !self.expect(TokenType::RightBracket) {
+            return None;
+        }
+
+        Some(Expr::ArrayLiteral { elements }.spanned(self.span_to_previous(start_span)))
+    }
+
+    /// `match value { pattern => expr, ... }`, arms are separated by commas or newlines
+    pub fn parse_match_expr(&mut self) -> Option<Expression> {
+        let start_span = self.current_token().span;
+        self.advance(); // Eat 'match'
+
+        let subject = self.parse_block_header_expr()?;
+        if !self.expect(TokenType::LeftBrace) {
+            return None;
+        }
+
+        // Like a block, newlines end the arms even inside parentheses
+        let saved_delimiters = mem::take(&mut self.delimiter_stack);
+        let allowed = mem::replace(&mut self.struct_literals_allowed, true);
+        let arms = self.parse_match_arms();
+        self.delimiter_stack = saved_delimiters;
+        self.struct_literals_allowed = allowed;
+
+        let arms = arms?;
+        if !self.expect(TokenType::RightBrace) {
+            return None;
+        }
+
+        Some(
+            Expr::Match {
+                subject: Box::new(subject),
+                arms,
+            }
+            .spanned(self.span_to_previous(start_span)),
+        )
+    }
+
+    fn parse_match_arms(&mut self) -> Option<Vec<MatchArm>> {
+        let mut arms = Vec::new();
+
+        loop {
+            self.skip_delimiters();
+
+            if self.is_eof() || self.current_token().token == Token::RightBrace {
+                break;
+            }
+
+            let pattern = self.parse_pattern()?;
+
+            let guard = if self.current_token().token == Token::If {
+                self.advance(); // Eat 'if'
+                Some(self.try_parse_expression(Precedence::Default.into())?)
+            } else {
+                None
+            };
+
+            if !self.expect(TokenType::FatArrow) {
+                return None;
+            }
+            let body = self.try_parse_expression(Precedence::Default.into())?;
+
+            arms.push(MatchArm {
+                pattern,
+                guard,
+                body,
+            });
+
+            match self.current_token().token {
+                Token::Comma => self.advance(), // Eat ','
+                Token::Newline | Token::Semicolon | Token::RightBrace => {}
+                _ => {
+                    self.errors.add(HydorError::ExpectedToken {
+                        expected: TokenType::Comma,
+                        got: self.current_token().token.get_token_type(),
+                        span: self.current_token().span,
+                    });
+                    return None;
+                }
+            }
+        }
+
+        Some(arms)
+    }
+
+    /// `{"a": 1, "b": 2}`, only in expression position since a
+    /// statement starting with `{` is a block
+    pub fn parse_map_literal(&mut self) -> Option<Expression> {
+        let start_span = self.current_token().span;
+        self.advance(); // Eat '{'
+
+        self.delimiter_stack.push(TokenType::LeftBrace);
+        let entries = self.parse_map_entries();
+        self.delimiter_stack.pop();
+
+        let entries = entries?;
+        if !self.expect(TokenType::RightBrace) {
+            return None;
+        }
+
+        Some(Expr::MapLiteral { entries }.spanned(self.span_to_previous(start_span)))
+    }
+
+    fn parse_map_entries(&mut self) -> Option<Vec<(Expression, Expression)>> {
+        let mut entries = Vec::new();
+
+        loop {
+            self.skip_newlines_in_delimiters();
+
+            if self.current_token().token == Token::RightBrace {
+                break;
+            }
+
+            let key = self.try_parse_expression(Precedence::Default.into())?;
+            if !self.expect(TokenType::Colon) {
+                return None;
+            }
+            self.skip_newlines_in_delimiters();
+
+            let value = self.try_parse_expression(Precedence::Default.into())?;
+            entries.push((key, value));
+            self.skip_newlines_in_delimiters();
+
+            if self.current_token().token != Token::Comma {
+                break;
+            }
+            self.advance(); // Eat ','
+        }
+
+        Some(entries)
+    }
+
+    /// `fn(x: Int) -> Int { ... }`
+    pub fn parse_function_expr(&mut self) -> Option<Expression> {
+        let fn_tok = self.current_token().clone();
+        self.advance(); // Eat 'fn'
+
+        let (parameters, return_type) = self.parse_function_signature()?;
+        let signature_span = self.span_to_previous(fn_tok.span);
+        let body = self.parse_block()?;
+
+        Some(
+            Expr::Function {
+                parameters,
+                return_type,
+                body,
+            }
+            .spanned(signature_span),
+        )
+    }
+
     pub fn parse_grouping_expr(&mut self) -> Option<Expression> {
         let left_paren_span = self.current_token().span;
         self.advance(); // Eat '('
@@ -371,18 +745,14 @@ impl Parser {
         // Skip newlines after (
         self.skip_newlines_in_delimiters();
 
-        let expr = match self.try_parse_expression(Precedence::Default.into()) {
-            Some(e) => e,
-            None => {
-                self.delimiter_stack.pop();
-                return None;
-            }
-        };
-
-        self.skip_newlines_in_delimiters();
+        // Parentheses make struct literals unambiguous again
+        let allowed = mem::replace(&mut self.struct_literals_allowed, true);
+        let expr = self.parse_grouping_contents();
+        self.struct_literals_allowed = allowed;
 
         self.delimiter_stack.pop(); // Remove (
 
+        let expr = expr?;
         if !self.expect(TokenType::RightParenthesis) {
             return None;
         }
@@ -404,6 +774,26 @@ impl Parser {
         })
     }
 
+    /// A single expression, or a tuple if a comma follows it.
+    /// `(x,)` is a one element tuple
+    fn parse_grouping_contents(&mut self) -> Option<Expression> {
+        let first = self.try_parse_expression(Precedence::Default.into())?;
+        self.skip_newlines_in_delimiters();
+
+        if self.current_token().token != Token::Comma {
+            return Some(first);
+        }
+        self.advance(); // Eat ','
+
+        let start = first.span;
+        let mut elements = vec![first];
+        elements.extend(self.parse_expressions_until(TokenType::RightParenthesis)?);
+        self.skip_newlines_in_delimiters();
+
+        let span = self.span_to_previous(start);
+        Some(Expr::TupleLiteral { elements }.spanned(span))
+    }
+
     // ------------------- Left Denoted Expressions -------------------
     pub fn parse_binary_expr(&mut self, left: Expression) -> Option<Expression> {
         let operator_info = self.current_token().clone();
@@ -462,15 +852,148 @@ impl Parser {
 
         Some(expr)
     }
-}
 
-// Statements
-impl Parser {
-    pub fn parse_variable_decl(&mut self) -> Option<Statement> {
-        let let_tok = self.current_token().clone();
-        self.advance();
+    pub fn parse_assignment_expr(&mut self, target: Expression) -> Option<Expression> {
+        if !matches!(
+            target.node,
+            Expr::Identifier(_) | Expr::Index { .. } | Expr::FieldAccess { .. }
+        ) {
+            self.errors
+                .add(HydorError::InvalidAssignmentTarget { span: target.span });
+            return None;
+        }
+
+        // `+=` and friends apply the operator they start with
+        let operator = match self.current_token().token {
+            Token::PlusAssign => Some(Token::Plus),
+            Token::MinusAssign => Some(Token::Minus),
+            Token::AsteriskAssign => Some(Token::Asterisk),
+            Token::SlashAssign => Some(Token::Slash),
+            Token::CaretAssign => Some(Token::Caret),
+            Token::PercentAssign => Some(Token::Percent),
+            _ => None,
+        };
+        self.advance(); // Eat '=' or '+=' and friends
+
+        // Parse right-associative so `a = b = c` assigns `c` to both
+        let value = self.try_parse_expression(u8::from(Precedence::Assignment) - 1)?;
+
+        let full_span = Span {
+            line: target.span.line,
+            start_column: target.span.start_column,
+            end_column: value.span.end_column,
+        };
+
+        let expr = match operator {
+            Some(operator) => Expr::CompoundAssign {
+                target: Box::new(target),
+                operator,
+                value: Box::new(value),
+            },
+            None => Expr::Assign {
+                target: Box::new(target),
+                value: Box::new(value),
+            },
+        }
+        .spanned(full_span);
+
+        Some(expr)
+    }
+
+    /// `value as Int`
+    pub fn parse_cast_expr(&mut self, value: Expression) -> Option<Expression> {
+        self.advance(); // Eat 'as'
+
+        let target = self.try_parse_type()?;
+        let full_span = self.span_to_previous(value.span);
+
+        let expr = Expr::Cast {
+            value: Box::new(value),
+            target,
+        }
+        .spanned(full_span);
+
+        Some(expr)
+    }
+
+    pub fn parse_conditional_expr(&mut self, then_expr: Expression) -> Option<Expression> {
+        self.advance(); // Eat 'if'
+
+        let condition = self.try_parse_expression(Precedence::Ternary.into())?;
+
+        self.skip_newlines_in_delimiters();
+        if !self.expect(TokenType::Else) {
+            return None;
+        }
+
+        // Parse right-associative so `a if x else b if y else c` chains
+        let else_expr = self.try_parse_expression(u8::from(Precedence::Ternary) - 1)?;
+
+        let full_span = Span {
+            line: then_expr.span.line,
+            start_column: then_expr.span.start_column,
+            end_column: else_expr.span.end_column,
+        };
+
+        let expr = Expr::Conditional {
+            then_expr: Box::new(then_expr),
+            condition: Box::new(condition),
+            else_expr: Box::new(else_expr),
+        }
+        .spanned(full_span);
+
+        Some(expr)
+    }
+
+    /// `arr[i]`
+    pub fn parse_index_expr(&mut self, object: Expression) -> Option<Expression> {
+        self.advance(); // Eat '['
+
+        self.delimiter_stack.push(TokenType::LeftBracket);
+        let index = self.try_parse_expression(Precedence::Default.into());
+        self.skip_newlines_in_delimiters();
+        self.delimiter_stack.pop();
+
+        let index = index?;
+        if !self.expect(TokenType::RightBracket) {
+            return None;
+        }
+
+        let span = self.span_to_previous(object.span);
+        let expr = Expr::Index {
+            object: Box::new(object),
+            index: Box::new(index),
+        }
+        .spanned(span);
+
+        Some(expr)
+    }
+
+    /// `p.x` field access, `pair.0` tuple access or `m.has(k)` method call.
+    /// With `?.` instead of `.` the access is skipped when the object is nil
+    pub fn parse_member_expr(&mut self, object: Expression) -> Option<Expression> {
+        let optional = self.current_token().token == Token::QuestionDot;
+        self.advance(); // Eat '.' or '?.'
+
+        if let Token::Integer(index) = self.current_token().token {
+            self.advance(); // Eat index
+            let span = self.span_to_previous(object.span);
+            let index = index as usize;
+
+            let expr = if optional {
+                Expr::OptionalChain {
+                    object: Box::new(object),
+                    access: ChainAccess::Element(index),
+                }
+            } else {
+                Expr::TupleAccess {
+                    object: Box::new(object),
+                    index,
+                }
+            };
+            return Some(expr.spanned(span));
+        }
 
-        // No synchronize calls needed anywhere!
         if self.current_token().token.get_token_type() != TokenType::Identifier {
             self.errors.add(HydorError::ExpectedToken {
                 expected: TokenType::Identifier,
@@ -479,35 +1002,812 @@ impl Parser {
             });
             return None;
         }
-        let ident = self.parse_identifier_literal()?;
+        let member = match &self.current_token().token {
+            Token::Identifier(name) => name.clone(),
+            _ => unreachable!("Already checked it's an Identifier"),
+        };
+        self.advance(); // Eat member name
+
+        if self.current_token().token != Token::LeftParenthesis {
+            let span = self.span_to_previous(object.span);
+
+            let expr = if optional {
+                Expr::OptionalChain {
+                    object: Box::new(object),
+                    access: ChainAccess::Field(member),
+                }
+            } else {
+                Expr::FieldAccess {
+                    object: Box::new(object),
+                    field: member,
+                }
+            };
+            return Some(expr.spanned(span));
+        }
+        self.advance(); // Eat '('
+
+        self.delimiter_stack.push(TokenType::LeftParenthesis);
+        let arguments = self.parse_expression_list(TokenType::RightParenthesis);
+        self.delimiter_stack.pop();
 
-        if !self.expect(TokenType::Colon) {
+        let arguments = arguments?;
+        if !self.expect(TokenType::RightParenthesis) {
             return None;
         }
-        let an_type = self.try_parse_type()?;
-        if !self.expect(TokenType::Assign) {
+
+        let span = self.span_to_previous(object.span);
+        let expr = if optional {
+            Expr::OptionalChain {
+                object: Box::new(object),
+                access: ChainAccess::Method {
+                    name: member,
+                    arguments,
+                },
+            }
+        } else {
+            Expr::MethodCall {
+                object: Box::new(object),
+                method: member,
+                arguments,
+            }
+        };
+
+        Some(expr.spanned(span))
+    }
+
+    pub fn parse_call_expr(&mut self, callee: Expression) -> Option<Expression> {
+        self.advance(); // Eat '('
+
+        self.delimiter_stack.push(TokenType::LeftParenthesis);
+        let arguments = self.parse_expression_list(TokenType::RightParenthesis);
+        self.delimiter_stack.pop();
+
+        let arguments = arguments?;
+        if !self.expect(TokenType::RightParenthesis) {
             return None;
         }
 
-        let value = self.try_parse_expression(Precedence::Default.into())?;
-        if !self.expect_delimiter() {
-            return None;
+        let span = self.span_to_previous(callee.span);
+        let expr = Expr::Call {
+            callee: Box::new(callee),
+            arguments,
         }
+        .spanned(span);
 
-        let val_span = value.span.clone();
+        Some(expr)
+    }
 
-        Some(
-            Stmt::VariableDeclaration {
-                identifier: ident,
-                value,
-                annotated_type: an_type,
-                span: Span {
-                    line: let_tok.span.line,
-                    start_column: let_tok.span.start_column,
-                    end_column: val_span.end_column,
-                },
-            }
+    /// Parse comma separated expressions up to (but not including) `closing`
+    /// `Enum::Variant`, with a parenthesized payload if the variant carries one
+    pub fn parse_variant_expr(&mut self, path: Expression) -> Option<Expression> {
+        let enum_name = match path.node {
+            Expr::Identifier(name) => name,
+            _ => {
+                self.errors
+                    .add(HydorError::InvalidEnumPath { span: path.span });
+                return None;
+            }
+        };
+        self.advance(); // Eat '::'
+
+        let variant = match &self.current_token().token {
+            Token::Identifier(name) => name.clone(),
+            other => {
+                self.errors.add(HydorError::ExpectedToken {
+                    expected: TokenType::Identifier,
+                    got: other.get_token_type(),
+                    span: self.current_token().span,
+                });
+                return None;
+            }
+        };
+        self.advance(); // Eat variant name
+
+        let mut arguments = Vec::new();
+        if self.current_token().token == Token::LeftParenthesis {
+            self.advance(); // Eat '('
+
+            self.delimiter_stack.push(TokenType::LeftParenthesis);
+            let parsed = self.parse_expression_list(TokenType::RightParenthesis);
+            self.delimiter_stack.pop();
+
+            arguments = parsed?;
+            if !self.expect(TokenType::RightParenthesis) {
+                return None;
+            }
+        }
+
+        let span = self.span_to_previous(path.span);
+        Some(
+            Expr::EnumVariant {
+                enum_name,
+                variant,
+                arguments,
+            }
+            .spanned(span),
+        )
+    }
+
+    pub(crate) fn parse_expression_list(&mut self, closing: TokenType) -> Option<Vec<Expression>> {
+        let allowed = mem::replace(&mut self.struct_literals_allowed, true);
+        let expressions = self.parse_expressions_until(closing);
+        self.struct_literals_allowed = allowed;
+
+        expressions
+    }
+
+    fn parse_expressions_until(&mut self, closing: TokenType) -> Option<Vec<Expression>> {
+        let mut expressions = Vec::new();
+
+        loop {
+            self.skip_newlines_in_delimiters();
+
+            if self.current_token().token.get_token_type() == closing {
+                break;
+            }
+
+            expressions.push(self.try_parse_expression(Precedence::Default.into())?);
+            self.skip_newlines_in_delimiters();
+
+            if self.current_token().token.get_token_type() != TokenType::Comma {
+                break;
+            }
+            self.advance(); // Eat ','
+        }
+
+        Some(expressions)
+    }
+}
+
+// Statements
+impl Parser {
+    pub fn parse_variable_decl(&mut self) -> Option<Statement> {
+        let let_tok = self.current_token().clone();
+        // `const` bindings cannot be reassigned
+        let mutable = let_tok.token == Token::Let;
+        self.advance();
+
+        if self.current_token().token == Token::LeftParenthesis {
+            return self.parse_tuple_destructuring(let_tok, mutable);
+        }
+
+        // No synchronize calls needed anywhere!
+        if self.current_token().token.get_token_type() != TokenType::Identifier {
+            self.errors.add(HydorError::ExpectedToken {
+                expected: TokenType::Identifier,
+                got: self.current_token().token.get_token_type(),
+                span: self.current_token().span,
+            });
+            return None;
+        }
+        let ident = self.parse_identifier_literal()?;
+
+        let an_type = if self.current_token().token == Token::Colon {
+            self.advance(); // Eat ':'
+            Some(self.try_parse_type()?)
+        } else {
+            None
+        };
+        if !self.expect(TokenType::Assign) {
+            return None;
+        }
+
+        let value = self.try_parse_expression(Precedence::Default.into())?;
+        if !self.expect_delimiter() {
+            return None;
+        }
+
+        let val_span = value.span.clone();
+
+        Some(
+            Stmt::VariableDeclaration {
+                identifier: ident,
+                value,
+                annotated_type: an_type,
+                mutable,
+                span: Span {
+                    line: let_tok.span.line,
+                    start_column: let_tok.span.start_column,
+                    end_column: val_span.end_column,
+                },
+            }
+            .spanned(let_tok.span),
+        )
+    }
+
+    /// `let (a, b) = pair`
+    fn parse_tuple_destructuring(
+        &mut self,
+        let_tok: TokenInfo,
+        mutable: bool,
+    ) -> Option<Statement> {
+        self.advance(); // Eat '('
+
+        let mut identifiers = Vec::new();
+        loop {
+            if self.current_token().token.get_token_type() != TokenType::Identifier {
+                self.errors.add(HydorError::ExpectedToken {
+                    expected: TokenType::Identifier,
+                    got: self.current_token().token.get_token_type(),
+                    span: self.current_token().span,
+                });
+                return None;
+            }
+            identifiers.push(self.parse_identifier_literal()?);
+
+            if self.current_token().token != Token::Comma {
+                break;
+            }
+            self.advance(); // Eat ','
+
+            if self.current_token().token == Token::RightParenthesis {
+                break;
+            }
+        }
+
+        if !self.expect(TokenType::RightParenthesis) || !self.expect(TokenType::Assign) {
+            return None;
+        }
+
+        let value = self.try_parse_expression(Precedence::Default.into())?;
+        if !self.expect_delimiter() {
+            return None;
+        }
+
+        let span = Span {
+            line: let_tok.span.line,
+            start_column: let_tok.span.start_column,
+            end_column: value.span.end_column,
+        };
+
+        Some(
+            Stmt::TupleDestructuring {
+                identifiers,
+                value,
+                mutable,
+                span,
+            }
             .spanned(let_tok.span),
         )
     }
+
+    pub fn parse_if_statement(&mut self) -> Option<Statement> {
+        let if_tok = self.current_token().clone();
+        self.advance(); // Eat 'if'
+
+        let condition = self.parse_block_header_expr()?;
+        let then_branch = self.parse_block()?;
+
+        // Allow `else` to start on the line after the closing brace
+        if self.peek_past_newlines() == TokenType::Else {
+            self.skip_delimiters();
+        }
+
+        let else_branch = if self.current_token().token == Token::Else {
+            self.advance(); // Eat 'else'
+
+            if self.current_token().token == Token::If {
+                // `else if` chains nest as a single if statement
+                Some(vec![self.parse_if_statement()?])
+            } else {
+                Some(self.parse_block()?)
+            }
+        } else {
+            None
+        };
+
+        Some(
+            Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+            }
+            .spanned(if_tok.span),
+        )
+    }
+
+    /// Parse a `{ ... }` block of statements
+    pub(crate) fn parse_block(&mut self) -> Option<Vec<Statement>> {
+        if !self.expect(TokenType::LeftBrace) {
+            return None;
+        }
+
+        // Newlines separate statements inside a block, even when
+        // the block itself sits inside parentheses
+        let saved_delimiters = std::mem::take(&mut self.delimiter_stack);
+        let allowed = mem::replace(&mut self.struct_literals_allowed, true);
+        let mut statements: Vec<Statement> = Vec::new();
+
+        loop {
+            self.skip_delimiters();
+
+            if self.is_eof() || self.current_token().token == Token::RightBrace {
+                break;
+            }
+
+            match self.try_parse_statement() {
+                Some(stmt) => statements.push(stmt),
+                None => self.synchronize(),
+            }
+        }
+
+        self.delimiter_stack = saved_delimiters;
+        self.struct_literals_allowed = allowed;
+
+        if !self.expect(TokenType::RightBrace) {
+            return None;
+        }
+
+        Some(statements)
+    }
+
+    pub fn parse_block_statement(&mut self) -> Option<Statement> {
+        let brace_tok = self.current_token().clone();
+        let body = self.parse_block()?;
+
+        Some(Stmt::Block { body }.spanned(brace_tok.span))
+    }
+
+    pub fn parse_while_statement(&mut self) -> Option<Statement> {
+        self.parse_while_loop(None)
+    }
+
+    fn parse_labeled_loop(&mut self) -> Option<Statement> {
+        let label = match self.current_token().token.clone() {
+            Token::Identifier(name) => name,
+            _ => unreachable!("Loop labels are always identifiers"),
+        };
+        self.advance(); // Eat label
+        self.advance(); // Eat ':'
+
+        match self.current_token().token {
+            Token::While => self.parse_while_loop(Some(label)),
+            Token::For => self.parse_for_loop(Some(label)),
+            _ => unreachable!("Labels are only recognized in front of loops"),
+        }
+    }
+
+    fn parse_while_loop(&mut self, label: Option<String>) -> Option<Statement> {
+        let while_tok = self.current_token().clone();
+        self.advance(); // Eat 'while'
+
+        let condition = self.parse_block_header_expr()?;
+        let body = self.parse_block()?;
+
+        Some(
+            Stmt::While {
+                condition,
+                body,
+                label,
+            }
+            .spanned(while_tok.span),
+        )
+    }
+
+    pub fn parse_for_statement(&mut self) -> Option<Statement> {
+        self.parse_for_loop(None)
+    }
+
+    /// `for i in start..end step n { ... }`, where `..=` includes `end`.
+    /// `for i: Int64 in ...` gives the loop variable and literal bounds a width
+    fn parse_for_loop(&mut self, label: Option<String>) -> Option<Statement> {
+        let for_tok = self.current_token().clone();
+        self.advance(); // Eat 'for'
+
+        if self.current_token().token.get_token_type() != TokenType::Identifier {
+            self.errors.add(HydorError::ExpectedToken {
+                expected: TokenType::Identifier,
+                got: self.current_token().token.get_token_type(),
+                span: self.current_token().span,
+            });
+            return None;
+        }
+        let variable = self.parse_identifier_literal()?;
+
+        let annotated_type = if self.current_token().token == Token::Colon {
+            self.advance(); // Eat ':'
+            Some(self.try_parse_type()?)
+        } else {
+            None
+        };
+
+        if !self.expect(TokenType::In) {
+            return None;
+        }
+
+        let start = self.try_parse_expression(Precedence::Default.into())?;
+
+        let inclusive = match self.current_token().token {
+            Token::DotDot => false,
+            Token::DotDotEqual => true,
+            _ => {
+                self.errors.add(HydorError::ExpectedToken {
+                    expected: TokenType::DotDot,
+                    got: self.current_token().token.get_token_type(),
+                    span: self.current_token().span,
+                });
+                return None;
+            }
+        };
+        self.advance(); // Eat range operator
+
+        let end = self.parse_block_header_expr()?;
+
+        // `step` is contextual so it stays usable as a variable name
+        let step = if self.current_token().token == Token::Identifier("step".to_string()) {
+            self.advance();
+            Some(Box::new(self.parse_block_header_expr()?))
+        } else {
+            None
+        };
+
+        let body = self.parse_block()?;
+
+        Some(
+            Stmt::For {
+                variable,
+                annotated_type,
+                start: Box::new(start),
+                end: Box::new(end),
+                inclusive,
+                step,
+                body,
+                label,
+            }
+            .spanned(for_tok.span),
+        )
+    }
+
+    /// `fn name(a: Int, b: Float) -> Int { ... }`
+    pub fn parse_function_declaration(&mut self) -> Option<Statement> {
+        let fn_tok = self.current_token().clone();
+        self.advance(); // Eat 'fn'
+
+        if self.current_token().token.get_token_type() != TokenType::Identifier {
+            self.errors.add(HydorError::ExpectedToken {
+                expected: TokenType::Identifier,
+                got: self.current_token().token.get_token_type(),
+                span: self.current_token().span,
+            });
+            return None;
+        }
+        let identifier = self.parse_identifier_literal()?;
+        let type_params = self.parse_type_params()?;
+        let (parameters, return_type) = self.parse_function_signature()?;
+
+        let signature_span = self.span_to_previous(fn_tok.span);
+        let body = self.parse_block()?;
+
+        Some(
+            Stmt::FunctionDeclaration {
+                identifier,
+                type_params,
+                parameters,
+                return_type,
+                body,
+            }
+            .spanned(signature_span),
+        )
+    }
+
+    /// `struct Point { x: Float, y: Float }`, fields are separated by commas or newlines
+    pub fn parse_struct_declaration(&mut self) -> Option<Statement> {
+        let struct_tok = self.current_token().clone();
+        self.advance(); // Eat 'struct'
+
+        let name_tok = self.current_token().clone();
+        let name = match name_tok.token {
+            Token::Identifier(name) => name,
+            _ => {
+                self.errors.add(HydorError::ExpectedToken {
+                    expected: TokenType::Identifier,
+                    got: name_tok.token.get_token_type(),
+                    span: name_tok.span,
+                });
+                return None;
+            }
+        };
+        self.advance(); // Eat struct name
+        let identifier = Expr::Identifier(name).spanned(name_tok.span);
+        let type_params = self.parse_type_params()?;
+
+        if !self.expect(TokenType::LeftBrace) {
+            return None;
+        }
+
+        self.delimiter_stack.push(TokenType::LeftBrace);
+        let fields = self.parse_struct_fields();
+        self.delimiter_stack.pop();
+
+        let fields = fields?;
+        if !self.expect(TokenType::RightBrace) {
+            return None;
+        }
+
+        Some(
+            Stmt::StructDeclaration {
+                identifier,
+                type_params,
+                fields,
+            }
+            .spanned(self.span_to_previous(struct_tok.span)),
+        )
+    }
+
+    fn parse_struct_fields(&mut self) -> Option<Vec<Field>> {
+        let mut fields = Vec::new();
+
+        loop {
+            self.skip_newlines_in_delimiters();
+
+            let field_tok = self.current_token().clone();
+            let name = match field_tok.token {
+                Token::Identifier(name) => name,
+                _ => break,
+            };
+            self.advance();
+
+            if !self.expect(TokenType::Colon) {
+                return None;
+            }
+            let annotated_type = self.try_parse_type()?;
+
+            fields.push(Field {
+                name,
+                annotated_type,
+                span: self.span_to_previous(field_tok.span),
+            });
+
+            // A newline is enough to separate fields
+            if self.current_token().token == Token::Comma {
+                self.advance(); // Eat ','
+            } else if self.current_token().token != Token::Newline {
+                break;
+            }
+        }
+
+        Some(fields)
+    }
+
+    pub fn parse_enum_declaration(&mut self) -> Option<Statement> {
+        let enum_tok = self.current_token().clone();
+        self.advance(); // Eat 'enum'
+
+        let name_tok = self.current_token().clone();
+        let name = match name_tok.token {
+            Token::Identifier(name) => name,
+            _ => {
+                self.errors.add(HydorError::ExpectedToken {
+                    expected: TokenType::Identifier,
+                    got: name_tok.token.get_token_type(),
+                    span: name_tok.span,
+                });
+                return None;
+            }
+        };
+        self.advance(); // Eat enum name
+        let identifier = Expr::Identifier(name).spanned(name_tok.span);
+        let type_params = self.parse_type_params()?;
+
+        if !self.expect(TokenType::LeftBrace) {
+            return None;
+        }
+
+        self.delimiter_stack.push(TokenType::LeftBrace);
+        let variants = self.parse_enum_variants();
+        self.delimiter_stack.pop();
+
+        let variants = variants?;
+        if !self.expect(TokenType::RightBrace) {
+            return None;
+        }
+
+        Some(
+            Stmt::EnumDeclaration {
+                identifier,
+                type_params,
+                variants,
+            }
+            .spanned(self.span_to_previous(enum_tok.span)),
+        )
+    }
+
+    fn parse_enum_variants(&mut self) -> Option<Vec<Variant>> {
+        let mut variants = Vec::new();
+
+        loop {
+            self.skip_newlines_in_delimiters();
+
+            let variant_tok = self.current_token().clone();
+            let name = match variant_tok.token {
+                Token::Identifier(name) => name,
+                _ => break,
+            };
+            self.advance();
+
+            let mut payload = Vec::new();
+            if self.current_token().token == Token::LeftParenthesis {
+                self.advance(); // Eat '('
+
+                loop {
+                    payload.push(self.try_parse_type()?);
+
+                    if self.current_token().token != Token::Comma {
+                        break;
+                    }
+                    self.advance(); // Eat ','
+                }
+
+                if !self.expect(TokenType::RightParenthesis) {
+                    return None;
+                }
+            }
+
+            variants.push(Variant {
+                name,
+                payload,
+                span: self.span_to_previous(variant_tok.span),
+            });
+
+            // A newline is enough to separate variants
+            if self.current_token().token == Token::Comma {
+                self.advance(); // Eat ','
+            } else if self.current_token().token != Token::Newline {
+                break;
+            }
+        }
+
+        Some(variants)
+    }
+
+    /// `(a: Int, b: Float) -> Int`, shared by declarations and function expressions
+    /// `<T, U>` after the name of a generic declaration, or nothing
+    fn parse_type_params(&mut self) -> Option<Vec<String>> {
+        let mut type_params = Vec::new();
+        if self.current_token().token != Token::LessThan {
+            return Some(type_params);
+        }
+        self.advance(); // Eat '<'
+
+        loop {
+            let param_tok = self.current_token().clone();
+            match param_tok.token {
+                Token::Identifier(name) => type_params.push(name),
+                other => {
+                    self.errors.add(HydorError::ExpectedToken {
+                        expected: TokenType::Identifier,
+                        got: other.get_token_type(),
+                        span: param_tok.span,
+                    });
+                    return None;
+                }
+            }
+            self.advance(); // Eat type parameter
+
+            if self.current_token().token != Token::Comma {
+                break;
+            }
+            self.advance(); // Eat ','
+        }
+
+        if !self.expect(TokenType::GreaterThan) {
+            return None;
+        }
+        Some(type_params)
+    }
+
+    fn parse_function_signature(&mut self) -> Option<(Vec<Parameter>, Option<TypeAnnotation>)> {
+        if !self.expect(TokenType::LeftParenthesis) {
+            return None;
+        }
+
+        self.delimiter_stack.push(TokenType::LeftParenthesis);
+        let parameters = self.parse_parameters();
+        self.delimiter_stack.pop();
+
+        let parameters = parameters?;
+        if !self.expect(TokenType::RightParenthesis) {
+            return None;
+        }
+
+        let return_type = if self.current_token().token == Token::Arrow {
+            self.advance(); // Eat '->'
+            Some(self.try_parse_type()?)
+        } else {
+            None
+        };
+
+        Some((parameters, return_type))
+    }
+
+    fn parse_parameters(&mut self) -> Option<Vec<Parameter>> {
+        let mut parameters = Vec::new();
+
+        loop {
+            self.skip_newlines_in_delimiters();
+
+            let param_tok = self.current_token().clone();
+            let name = match param_tok.token {
+                Token::Identifier(name) => name,
+                _ => break,
+            };
+            self.advance();
+
+            if !self.expect(TokenType::Colon) {
+                return None;
+            }
+            let annotated_type = self.try_parse_type()?;
+
+            parameters.push(Parameter {
+                name,
+                annotated_type,
+                span: self.span_to_previous(param_tok.span),
+            });
+
+            self.skip_newlines_in_delimiters();
+            if self.current_token().token != Token::Comma {
+                break;
+            }
+            self.advance(); // Eat ','
+        }
+
+        Some(parameters)
+    }
+
+    pub fn parse_return_statement(&mut self) -> Option<Statement> {
+        let return_tok = self.current_token().clone();
+        self.advance(); // Eat 'return'
+
+        let value = match self.current_token().token.get_token_type() {
+            TokenType::Newline
+            | TokenType::Semicolon
+            | TokenType::RightBrace
+            | TokenType::EndOfFile => None,
+            _ => Some(self.try_parse_expression(Precedence::Default.into())?),
+        };
+
+        let span = self.span_to_previous(return_tok.span);
+        if !self.expect_delimiter() {
+            return None;
+        }
+
+        Some(Stmt::Return { value }.spanned(span))
+    }
+
+    pub fn parse_break_statement(&mut self) -> Option<Statement> {
+        let break_tok = self.current_token().clone();
+        self.advance(); // Eat 'break'
+
+        let label = self.parse_optional_label();
+        let span = self.span_to_previous(break_tok.span);
+        if !self.expect_delimiter() {
+            return None;
+        }
+
+        Some(Stmt::Break { label }.spanned(span))
+    }
+
+    pub fn parse_continue_statement(&mut self) -> Option<Statement> {
+        let continue_tok = self.current_token().clone();
+        self.advance(); // Eat 'continue'
+
+        let label = self.parse_optional_label();
+        let span = self.span_to_previous(continue_tok.span);
+        if !self.expect_delimiter() {
+            return None;
+        }
+
+        Some(Stmt::Continue { label }.spanned(span))
+    }
+
+    /// `break` and `continue` may name the loop they target
+    fn parse_optional_label(&mut self) -> Option<String> {
+        match self.current_token().token.clone() {
+            Token::Identifier(name) => {
+                self.advance();
+                Some(name)
+            }
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,204 @@
+use crate::{
+    ast::ast::{Expr, FieldPattern, Pat, Pattern},
+    errors::HydorError,
+    parser::parser::Parser,
+    tokens::{Token, TokenType},
+};
+
+impl Parser {
+    /// A single `match` arm pattern
+    pub(crate) fn parse_pattern(&mut self) -> Option<Pattern> {
+        let start = self.current_token().clone();
+
+        let name = match start.token {
+            Token::Identifier(name) => name,
+            _ => return self.parse_literal_pattern(),
+        };
+
+        match self.peek_token_type(1) {
+            TokenType::BoxColon => self.parse_variant_pattern(name),
+            TokenType::LeftBrace => self.parse_struct_pattern(name),
+            _ => {
+                self.advance(); // Eat name
+                let pattern = if name == "_" {
+                    Pat::Wildcard
+                } else {
+                    Pat::Binding(name)
+                };
+                Some(pattern.spanned(start.span))
+            }
+        }
+    }
+
+    /// `1`, `"a"`, `-2.5`, or a range like `1..10` and `'a'..='z'`
+    fn parse_literal_pattern(&mut self) -> Option<Pattern> {
+        let start_span = self.current_token().span;
+        let start = self.parse_pattern_literal()?;
+
+        let inclusive = match self.current_token().token {
+            Token::DotDot => false,
+            Token::DotDotEqual => true,
+            _ => return Some(Pat::Literal(start).spanned(start_span)),
+        };
+        self.advance(); // Eat range operator
+
+        let end = self.parse_pattern_literal()?;
+        Some(
+            Pat::Range {
+                start,
+                end,
+                inclusive,
+            }
+            .spanned(self.span_to_previous(start_span)),
+        )
+    }
+
+    fn parse_pattern_literal(&mut self) -> Option<Expr> {
+        // Negative numbers are folded into the literal
+        let negative = self.current_token().token == Token::Minus;
+        if negative {
+            self.advance(); // Eat '-'
+        }
+
+        let literal = match (&self.current_token().token, negative) {
+            (Token::Integer(n), _) => {
+                let digits = *n;
+                Expr::IntegerLiteral(self.integer_literal_value(digits, negative)?)
+            }
+            (Token::Float(n), _) => Expr::FloatLiteral(if negative { -n } else { *n }),
+            (Token::String(s), false) => Expr::StringLiteral(s.clone()),
+            (Token::True, false) => Expr::BooleanLiteral(true),
+            (Token::False, false) => Expr::BooleanLiteral(false),
+            (Token::Nil, false) => Expr::NilLiteral,
+            (other, _) => {
+                self.errors.add(HydorError::InvalidPattern {
+                    token: other.get_token_type(),
+                    span: self.current_token().span,
+                });
+                return None;
+            }
+        };
+        self.advance(); // Eat literal
+
+        Some(literal)
+    }
+
+    /// `Shape::Circle(r)`, the payload can be left out for variants without one
+    fn parse_variant_pattern(&mut self, enum_name: String) -> Option<Pattern> {
+        let start_span = self.current_token().span;
+        self.advance(); // Eat enum name
+        self.advance(); // Eat '::'
+
+        let variant = match &self.current_token().token {
+            Token::Identifier(name) => name.clone(),
+            other => {
+                self.errors.add(HydorError::ExpectedToken {
+                    expected: TokenType::Identifier,
+                    got: other.get_token_type(),
+                    span: self.current_token().span,
+                });
+                return None;
+            }
+        };
+        self.advance(); // Eat variant name
+
+        let mut payload = Vec::new();
+        if self.current_token().token == Token::LeftParenthesis {
+            self.advance(); // Eat '('
+
+            self.delimiter_stack.push(TokenType::LeftParenthesis);
+            let parsed = self.parse_pattern_list(TokenType::RightParenthesis);
+            self.delimiter_stack.pop();
+
+            payload = parsed?;
+            if !self.expect(TokenType::RightParenthesis) {
+                return None;
+            }
+        }
+
+        Some(
+            Pat::Variant {
+                enum_name,
+                variant,
+                payload,
+            }
+            .spanned(self.span_to_previous(start_span)),
+        )
+    }
+
+    /// `Point { x: 0, y }`, where a lone field name binds the field to that name
+    fn parse_struct_pattern(&mut self, name: String) -> Option<Pattern> {
+        let start_span = self.current_token().span;
+        self.advance(); // Eat struct name
+        self.advance(); // Eat '{'
+
+        self.delimiter_stack.push(TokenType::LeftBrace);
+        let fields = self.parse_field_patterns();
+        self.delimiter_stack.pop();
+
+        let fields = fields?;
+        if !self.expect(TokenType::RightBrace) {
+            return None;
+        }
+
+        Some(Pat::Struct { name, fields }.spanned(self.span_to_previous(start_span)))
+    }
+
+    fn parse_field_patterns(&mut self) -> Option<Vec<FieldPattern>> {
+        let mut fields = Vec::new();
+
+        loop {
+            self.skip_newlines_in_delimiters();
+
+            let field_tok = self.current_token().clone();
+            let name = match field_tok.token {
+                Token::Identifier(name) => name,
+                _ => break,
+            };
+            self.advance(); // Eat field name
+
+            let pattern = if self.current_token().token == Token::Colon {
+                self.advance(); // Eat ':'
+                self.parse_pattern()?
+            } else {
+                Pat::Binding(name.clone()).spanned(field_tok.span)
+            };
+
+            fields.push(FieldPattern {
+                name,
+                pattern,
+                span: field_tok.span,
+            });
+
+            self.skip_newlines_in_delimiters();
+            if self.current_token().token != Token::Comma {
+                break;
+            }
+            self.advance(); // Eat ','
+        }
+
+        Some(fields)
+    }
+
+    fn parse_pattern_list(&mut self, closing: TokenType) -> Option<Vec<Pattern>> {
+        let mut patterns = Vec::new();
+
+        loop {
+            self.skip_newlines_in_delimiters();
+
+            if self.current_token().token.get_token_type() == closing {
+                break;
+            }
+
+            patterns.push(self.parse_pattern()?);
+            self.skip_newlines_in_delimiters();
+
+            if self.current_token().token != Token::Comma {
+                break;
+            }
+            self.advance(); // Eat ','
+        }
+
+        Some(patterns)
+    }
+}
@@ -1,13 +1,52 @@
 use crate::{
-    ast::type_annotation::TypeAnnotation, errors::HydorError, parser::parser::Parser,
-    tokens::TokenType,
+    ast::type_annotation::TypeAnnotation,
+    errors::HydorError,
+    parser::parser::Parser,
+    tokens::{Token, TokenType},
+    type_checker::type_checker::Type,
 };
 
 impl Parser {
+    /// A type followed by any number of `?`, which all mean the same as one
     pub(crate) fn try_parse_type(&mut self) -> Option<TypeAnnotation> {
+        let mut annotation = self.try_parse_base_type()?;
+
+        while matches!(
+            self.current_token().token,
+            Token::Question | Token::QuestionQuestion
+        ) {
+            self.advance(); // Eat '?'
+            if !matches!(annotation, TypeAnnotation::OptionalType(_)) {
+                annotation = TypeAnnotation::OptionalType(Box::new(annotation));
+            }
+        }
+
+        Some(annotation)
+    }
+
+    fn try_parse_base_type(&mut self) -> Option<TypeAnnotation> {
         let current_token = self.current_token();
         let current_token_type = current_token.token.get_token_type();
 
+        if current_token_type == TokenType::Function {
+            return self.try_parse_function_type();
+        }
+
+        // `[Int]` is shorthand for `Array<Int>`
+        if current_token_type == TokenType::LeftBracket {
+            self.advance(); // Eat '['
+            let element_type = self.try_parse_type()?;
+
+            if !self.expect(TokenType::RightBracket) {
+                return None;
+            }
+            return Some(TypeAnnotation::ArrayType(Box::new(element_type)));
+        }
+
+        if current_token_type == TokenType::LeftParenthesis {
+            return self.try_parse_tuple_type();
+        }
+
         // check if is identifier
         if current_token_type != TokenType::Identifier {
             self.errors.add(HydorError::ExpectedToken {
@@ -21,23 +60,159 @@ impl Parser {
 
         // take type name
         let type_name = match &current_token.token {
-            crate::tokens::Token::Identifier(name) => name,
+            Token::Identifier(name) => name,
             _ => unreachable!("Already checked it's an Identifier"),
         };
 
-        match TypeAnnotation::from_identifier(type_name) {
-            Some(t) => {
-                self.advance();
-                Some(t)
+        if self.peek_token_type(1) == TokenType::LessThan {
+            match type_name.as_str() {
+                "Array" => return self.try_parse_array_type(),
+                "Map" => return self.try_parse_map_type(),
+                _ => {}
+            }
+        }
+
+        if let Some(annotation) = TypeAnnotation::from_identifier(type_name) {
+            self.advance(); // Eat type name
+            return Some(annotation);
+        }
+
+        let type_name = type_name.clone();
+        self.advance(); // Eat type name
+        let type_args = self.try_parse_type_args()?;
+
+        Some(TypeAnnotation::NamedType(type_name, type_args))
+    }
+
+    /// `<Int, String>` after the name of a generic type, or nothing
+    fn try_parse_type_args(&mut self) -> Option<Vec<TypeAnnotation>> {
+        let mut type_args = Vec::new();
+        if self.current_token().token != Token::LessThan {
+            return Some(type_args);
+        }
+        self.advance(); // Eat '<'
+
+        loop {
+            type_args.push(self.try_parse_type()?);
+
+            if self.current_token().token != Token::Comma {
+                break;
+            }
+            self.advance(); // Eat ','
+        }
+
+        if !self.expect_closing_angle() {
+            return None;
+        }
+        Some(type_args)
+    }
+
+    /// `Array<Int>`
+    fn try_parse_array_type(&mut self) -> Option<TypeAnnotation> {
+        self.advance(); // Eat 'Array'
+        self.advance(); // Eat '<'
+        let element_type = self.try_parse_type()?;
+
+        if !self.expect_closing_angle() {
+            return None;
+        }
+        Some(TypeAnnotation::ArrayType(Box::new(element_type)))
+    }
+
+    /// `Map<String, Int>`, keys must be hashable at runtime
+    fn try_parse_map_type(&mut self) -> Option<TypeAnnotation> {
+        self.advance(); // Eat 'Map'
+        self.advance(); // Eat '<'
+
+        let key_start = self.current_token().span;
+        let key_type = self.try_parse_type()?;
+        let key_span = self.span_to_previous(key_start);
+
+        if !self.expect(TokenType::Comma) {
+            return None;
+        }
+        let value_type = self.try_parse_type()?;
+
+        if !self.expect_closing_angle() {
+            return None;
+        }
+
+        let resolved_key = Type::from_anotated_type(&key_type);
+        if !resolved_key.is_hashable() {
+            self.errors.add(HydorError::UnhashableKeyType {
+                found: resolved_key,
+                span: key_span,
+            });
+            return None;
+        }
+
+        Some(TypeAnnotation::MapType {
+            key: Box::new(key_type),
+            value: Box::new(value_type),
+        })
+    }
+
+    /// `(Int, String)`. A single type in parentheses is just that type,
+    /// unless a trailing comma makes it a one element tuple
+    fn try_parse_tuple_type(&mut self) -> Option<TypeAnnotation> {
+        self.advance(); // Eat '('
+
+        let mut elements = Vec::new();
+        let trailing_comma = loop {
+            elements.push(self.try_parse_type()?);
+
+            if self.current_token().token != Token::Comma {
+                break false;
             }
-            None => {
-                self.errors.add(HydorError::InvalidTypeName {
-                    got: type_name.clone(),
-                    span: current_token.span,
-                });
-                self.advance(); // consume bad token
-                None
+            self.advance(); // Eat ','
+
+            if self.current_token().token == Token::RightParenthesis {
+                break true;
             }
+        };
+
+        if !self.expect(TokenType::RightParenthesis) {
+            return None;
+        }
+
+        if elements.len() == 1 && !trailing_comma {
+            return elements.pop();
+        }
+        Some(TypeAnnotation::TupleType(elements))
+    }
+
+    /// `fn(Int, Float) -> Int`, the return type defaults to nil
+    fn try_parse_function_type(&mut self) -> Option<TypeAnnotation> {
+        self.advance(); // Eat 'fn'
+
+        if !self.expect(TokenType::LeftParenthesis) {
+            return None;
+        }
+
+        let mut params = Vec::new();
+        while self.current_token().token != Token::RightParenthesis {
+            params.push(self.try_parse_type()?);
+
+            if self.current_token().token != Token::Comma {
+                break;
+            }
+            self.advance(); // Eat ','
         }
+
+        if !self.expect(TokenType::RightParenthesis) {
+            return None;
+        }
+
+        let return_type = if self.current_token().token == Token::Arrow {
+            self.advance(); // Eat '->'
+            Some(Box::new(self.try_parse_type()?))
+        } else {
+            None
+        };
+
+        Some(TypeAnnotation::FunctionType {
+            params,
+            return_type,
+        })
     }
 }
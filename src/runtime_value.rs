@@ -1,32 +1,119 @@
 use core::fmt;
-use std::fmt::{Display, Formatter};
+use std::{
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+    mem,
+};
 
 #[derive(Clone, Copy, Debug)]
 pub enum RuntimeValue {
     IntegerLiteral(i32),
+    Int64Literal(i64),
+    ByteLiteral(u8),
     FloatLiteral(f64),
     BooleanLiteral(bool),
     StringLiteral(usize), // Accessed via string table
     NilLiteral,
+    Function {
+        name: usize, // Accessed via string table
+        arity: usize,
+        entry: usize, // Offset of the first instruction of the body
+    },
+    Closure(usize), // Accessed via the VM heap
+    Array(usize),   // Accessed via the VM heap
+    Map(usize),     // Accessed via the VM heap
+    Struct(usize),  // Accessed via the VM heap
+    Enum(usize),    // Accessed via the VM heap
+    Tuple(usize),   // Accessed via the VM heap
+}
+
+/// Identity used for map keys, not language level equality.
+/// Strings are interned, so their table index stands for their contents,
+/// and floats compare by bit pattern so that equal keys always hash equally
+impl PartialEq for RuntimeValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (RuntimeValue::IntegerLiteral(a), RuntimeValue::IntegerLiteral(b)) => a == b,
+            (RuntimeValue::Int64Literal(a), RuntimeValue::Int64Literal(b)) => a == b,
+            (RuntimeValue::ByteLiteral(a), RuntimeValue::ByteLiteral(b)) => a == b,
+            (RuntimeValue::FloatLiteral(a), RuntimeValue::FloatLiteral(b)) => {
+                a.to_bits() == b.to_bits()
+            }
+            (RuntimeValue::BooleanLiteral(a), RuntimeValue::BooleanLiteral(b)) => a == b,
+            (RuntimeValue::StringLiteral(a), RuntimeValue::StringLiteral(b)) => a == b,
+            (RuntimeValue::NilLiteral, RuntimeValue::NilLiteral) => true,
+            (RuntimeValue::Function { entry: a, .. }, RuntimeValue::Function { entry: b, .. }) => {
+                a == b
+            }
+            (RuntimeValue::Closure(a), RuntimeValue::Closure(b)) => a == b,
+            (RuntimeValue::Array(a), RuntimeValue::Array(b)) => a == b,
+            (RuntimeValue::Map(a), RuntimeValue::Map(b)) => a == b,
+            (RuntimeValue::Struct(a), RuntimeValue::Struct(b)) => a == b,
+            (RuntimeValue::Enum(a), RuntimeValue::Enum(b)) => a == b,
+            (RuntimeValue::Tuple(a), RuntimeValue::Tuple(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for RuntimeValue {}
+
+impl Hash for RuntimeValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+
+        match self {
+            RuntimeValue::IntegerLiteral(n) => n.hash(state),
+            RuntimeValue::Int64Literal(n) => n.hash(state),
+            RuntimeValue::ByteLiteral(n) => n.hash(state),
+            RuntimeValue::FloatLiteral(n) => n.to_bits().hash(state),
+            RuntimeValue::BooleanLiteral(b) => b.hash(state),
+            RuntimeValue::StringLiteral(index) => index.hash(state),
+            RuntimeValue::NilLiteral => {}
+            RuntimeValue::Function { entry, .. } => entry.hash(state),
+            RuntimeValue::Closure(index)
+            | RuntimeValue::Array(index)
+            | RuntimeValue::Map(index)
+            | RuntimeValue::Struct(index)
+            | RuntimeValue::Enum(index)
+            | RuntimeValue::Tuple(index) => index.hash(state),
+        }
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum RuntimeType {
     Integer,
+    Int64,
+    Byte,
     Float,
     Boolean,
     String,
     Nil,
+    Function,
+    Array,
+    Map,
+    Struct,
+    Enum,
+    Tuple,
 }
 
 impl Display for RuntimeType {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             RuntimeType::Integer => write!(f, "Integer"),
+            RuntimeType::Int64 => write!(f, "Int64"),
+            RuntimeType::Byte => write!(f, "Byte"),
             RuntimeType::Float => write!(f, "Float"),
             RuntimeType::Boolean => write!(f, "Boolean"),
             RuntimeType::String => write!(f, "String"),
             RuntimeType::Nil => write!(f, "Nil"),
+            RuntimeType::Function => write!(f, "Function"),
+            RuntimeType::Array => write!(f, "Array"),
+            RuntimeType::Map => write!(f, "Map"),
+            RuntimeType::Struct => write!(f, "Struct"),
+            RuntimeType::Enum => write!(f, "Enum"),
+            RuntimeType::Tuple => write!(f, "Tuple"),
         }
     }
 }
@@ -35,10 +122,28 @@ impl RuntimeType {
     pub fn to_string(&self) -> &'static str {
         match self {
             RuntimeType::Integer => "integer",
+            RuntimeType::Int64 => "int64",
+            RuntimeType::Byte => "byte",
             RuntimeType::Float => "float",
             RuntimeType::Boolean => "boolean",
             RuntimeType::String => "string",
             RuntimeType::Nil => "nil",
+            RuntimeType::Function => "function",
+            RuntimeType::Array => "array",
+            RuntimeType::Map => "map",
+            RuntimeType::Struct => "struct",
+            RuntimeType::Enum => "enum",
+            RuntimeType::Tuple => "tuple",
+        }
+    }
+
+    /// The smallest and largest value of an integer type
+    pub fn integer_range(&self) -> Option<(i64, i64)> {
+        match self {
+            RuntimeType::Integer => Some((i32::MIN.into(), i32::MAX.into())),
+            RuntimeType::Int64 => Some((i64::MIN, i64::MAX)),
+            RuntimeType::Byte => Some((u8::MIN.into(), u8::MAX.into())),
+            _ => None,
         }
     }
 }
@@ -51,6 +156,16 @@ impl RuntimeValue {
         }
     }
 
+    /// The value of an integer of any width
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            RuntimeValue::IntegerLiteral(n) => Some((*n).into()),
+            RuntimeValue::Int64Literal(n) => Some(*n),
+            RuntimeValue::ByteLiteral(n) => Some((*n).into()),
+            _ => None,
+        }
+    }
+
     pub fn as_float(&self) -> Option<f64> {
         match self {
             RuntimeValue::FloatLiteral(n) => Some(*n),
@@ -79,6 +194,8 @@ impl RuntimeValue {
     pub fn as_number(&self) -> Option<f64> {
         match self {
             RuntimeValue::IntegerLiteral(n) => Some(*n as f64),
+            RuntimeValue::Int64Literal(n) => Some(*n as f64),
+            RuntimeValue::ByteLiteral(n) => Some(*n as f64),
             RuntimeValue::FloatLiteral(n) => Some(*n),
             _ => None,
         }
@@ -87,17 +204,28 @@ impl RuntimeValue {
     pub fn get_type(&self) -> RuntimeType {
         match self {
             RuntimeValue::IntegerLiteral(_) => RuntimeType::Integer,
+            RuntimeValue::Int64Literal(_) => RuntimeType::Int64,
+            RuntimeValue::ByteLiteral(_) => RuntimeType::Byte,
             RuntimeValue::FloatLiteral(_) => RuntimeType::Float,
             RuntimeValue::BooleanLiteral(_) => RuntimeType::Boolean,
             RuntimeValue::StringLiteral(_) => RuntimeType::String,
             RuntimeValue::NilLiteral => RuntimeType::Nil,
+            RuntimeValue::Function { .. } | RuntimeValue::Closure(_) => RuntimeType::Function,
+            RuntimeValue::Array(_) => RuntimeType::Array,
+            RuntimeValue::Map(_) => RuntimeType::Map,
+            RuntimeValue::Struct(_) => RuntimeType::Struct,
+            RuntimeValue::Enum(_) => RuntimeType::Enum,
+            RuntimeValue::Tuple(_) => RuntimeType::Tuple,
         }
     }
 
     pub fn is_number(&self) -> bool {
         matches!(
             self,
-            RuntimeValue::IntegerLiteral(_) | RuntimeValue::FloatLiteral(_)
+            RuntimeValue::IntegerLiteral(_)
+                | RuntimeValue::Int64Literal(_)
+                | RuntimeValue::ByteLiteral(_)
+                | RuntimeValue::FloatLiteral(_)
         )
     }
 
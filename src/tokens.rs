@@ -10,7 +10,7 @@ pub struct TokenInfo {
 #[derive(Debug, Clone, PartialEq)]
 pub enum Token {
     // Literals
-    Integer(i32),
+    Integer(u64), // Checked against the Int64 range by the parser
     Float(f64),
     String(String),
     Identifier(String),
@@ -23,6 +23,22 @@ pub enum Token {
     Slash,
     Caret,
     Bang,
+    Question,         // ?
+    QuestionQuestion, // ??
+    Percent,          // %
+    Ampersand,        // &
+    Pipe,             // |
+    Tilde,            // ~
+    ShiftLeft,        // <<
+    ShiftRight,       // >>
+
+    // Compound assignment
+    PlusAssign,     // +=
+    MinusAssign,    // -=
+    AsteriskAssign, // *=
+    SlashAssign,    // /=
+    CaretAssign,    // ^=
+    PercentAssign,  // %=
 
     // Comparison
     LessThan,
@@ -38,7 +54,12 @@ pub enum Token {
     Colon,
     Newline,
     Dot,
-    BoxColon, // ::
+    Arrow,       // ->
+    FatArrow,    // =>
+    DotDot,      // ..
+    DotDotEqual, // ..=
+    BoxColon,    // ::
+    QuestionDot, // ?.
 
     // Grouping
     LeftParenthesis,
@@ -55,6 +76,7 @@ pub enum Token {
     // Keywords
     Function,
     Let,
+    Const,
     True,
     False,
     If,
@@ -64,6 +86,17 @@ pub enum Token {
     And,
     Not,
     Return,
+    While,
+    For,
+    In,
+    Break,
+    Continue,
+    Struct,
+    Enum,
+    Match,
+    As,
+    Xor,
+    Div,
 }
 
 #[derive(Eq, PartialEq, Hash, Debug, Clone)]
@@ -82,6 +115,22 @@ pub enum TokenType {
     Slash,
     Caret,
     Bang,
+    Question,         // ?
+    QuestionQuestion, // ??
+    Percent,          // %
+    Ampersand,        // &
+    Pipe,             // |
+    Tilde,            // ~
+    ShiftLeft,        // <<
+    ShiftRight,       // >>
+
+    // Compound assignment
+    PlusAssign,     // +=
+    MinusAssign,    // -=
+    AsteriskAssign, // *=
+    SlashAssign,    // /=
+    CaretAssign,    // ^=
+    PercentAssign,  // %=
 
     // Comparison
     LessThan,
@@ -97,7 +146,12 @@ pub enum TokenType {
     Colon,
     Newline,
     Dot,
-    BoxColon, // ::
+    Arrow,       // ->
+    FatArrow,    // =>
+    DotDot,      // ..
+    DotDotEqual, // ..=
+    BoxColon,    // ::
+    QuestionDot, // ?.
 
     // Grouping
     LeftParenthesis,
@@ -114,6 +168,7 @@ pub enum TokenType {
     // Keywords
     Function,
     Let,
+    Const,
     True,
     False,
     If,
@@ -123,6 +178,17 @@ pub enum TokenType {
     And,
     Not,
     Return,
+    While,
+    For,
+    In,
+    Break,
+    Continue,
+    Struct,
+    Enum,
+    Match,
+    As,
+    Xor,
+    Div,
 }
 
 impl fmt::Display for TokenType {
@@ -142,6 +208,22 @@ impl fmt::Display for TokenType {
             TokenType::Slash => write!(f, "/"),
             TokenType::Caret => write!(f, "^"),
             TokenType::Bang => write!(f, "!"),
+            TokenType::Question => write!(f, "?"),
+            TokenType::QuestionQuestion => write!(f, "??"),
+            TokenType::Percent => write!(f, "%"),
+            TokenType::Ampersand => write!(f, "&"),
+            TokenType::Pipe => write!(f, "|"),
+            TokenType::Tilde => write!(f, "~"),
+            TokenType::ShiftLeft => write!(f, "<<"),
+            TokenType::ShiftRight => write!(f, ">>"),
+
+            // Compound assignment
+            TokenType::PlusAssign => write!(f, "+="),
+            TokenType::MinusAssign => write!(f, "-="),
+            TokenType::AsteriskAssign => write!(f, "*="),
+            TokenType::SlashAssign => write!(f, "/="),
+            TokenType::CaretAssign => write!(f, "^="),
+            TokenType::PercentAssign => write!(f, "%="),
 
             // Comparison
             TokenType::LessThan => write!(f, "<"),
@@ -157,7 +239,12 @@ impl fmt::Display for TokenType {
             TokenType::Colon => write!(f, ":"),
             TokenType::Newline => write!(f, "newline"),
             TokenType::Dot => write!(f, "."),
+            TokenType::Arrow => write!(f, "->"),
+            TokenType::FatArrow => write!(f, "=>"),
+            TokenType::DotDot => write!(f, ".."),
+            TokenType::DotDotEqual => write!(f, "..="),
             TokenType::BoxColon => write!(f, "::"),
+            TokenType::QuestionDot => write!(f, "?."),
 
             // Grouping
             TokenType::LeftParenthesis => write!(f, "("),
@@ -174,6 +261,7 @@ impl fmt::Display for TokenType {
             // Keywords
             TokenType::Function => write!(f, "fn"),
             TokenType::Let => write!(f, "let"),
+            TokenType::Const => write!(f, "const"),
             TokenType::True => write!(f, "true"),
             TokenType::False => write!(f, "false"),
             TokenType::If => write!(f, "if"),
@@ -183,6 +271,17 @@ impl fmt::Display for TokenType {
             TokenType::And => write!(f, "and"),
             TokenType::Not => write!(f, "not"),
             TokenType::Return => write!(f, "return"),
+            TokenType::While => write!(f, "while"),
+            TokenType::For => write!(f, "for"),
+            TokenType::In => write!(f, "in"),
+            TokenType::Break => write!(f, "break"),
+            TokenType::Continue => write!(f, "continue"),
+            TokenType::Struct => write!(f, "struct"),
+            TokenType::Enum => write!(f, "enum"),
+            TokenType::Match => write!(f, "match"),
+            TokenType::As => write!(f, "as"),
+            TokenType::Xor => write!(f, "xor"),
+            TokenType::Div => write!(f, "div"),
         }
     }
 }
@@ -192,6 +291,7 @@ impl Token {
         match identifier {
             "fn" => Token::Function,
             "let" => Token::Let,
+            "const" => Token::Const,
             "true" => Token::True,
             "false" => Token::False,
             "if" => Token::If,
@@ -201,6 +301,17 @@ impl Token {
             "not" => Token::Not,
             "else" => Token::Else,
             "return" => Token::Return,
+            "while" => Token::While,
+            "for" => Token::For,
+            "in" => Token::In,
+            "break" => Token::Break,
+            "continue" => Token::Continue,
+            "struct" => Token::Struct,
+            "enum" => Token::Enum,
+            "match" => Token::Match,
+            "as" => Token::As,
+            "xor" => Token::Xor,
+            "div" => Token::Div,
             _ => Token::Identifier(identifier.to_string()),
         }
     }
@@ -221,6 +332,20 @@ impl Token {
             Token::Slash => TokenType::Slash,
             Token::Caret => TokenType::Caret,
             Token::Bang => TokenType::Bang,
+            Token::Question => TokenType::Question,
+            Token::QuestionQuestion => TokenType::QuestionQuestion,
+            Token::Percent => TokenType::Percent,
+            Token::Ampersand => TokenType::Ampersand,
+            Token::Pipe => TokenType::Pipe,
+            Token::Tilde => TokenType::Tilde,
+            Token::ShiftLeft => TokenType::ShiftLeft,
+            Token::ShiftRight => TokenType::ShiftRight,
+            Token::PlusAssign => TokenType::PlusAssign,
+            Token::MinusAssign => TokenType::MinusAssign,
+            Token::AsteriskAssign => TokenType::AsteriskAssign,
+            Token::SlashAssign => TokenType::SlashAssign,
+            Token::CaretAssign => TokenType::CaretAssign,
+            Token::PercentAssign => TokenType::PercentAssign,
 
             // Comparison
             Token::LessThan => TokenType::LessThan,
@@ -236,7 +361,12 @@ impl Token {
             Token::Colon => TokenType::Colon,
             Token::Newline => TokenType::Newline,
             Token::Dot => TokenType::Dot,
+            Token::Arrow => TokenType::Arrow,
+            Token::FatArrow => TokenType::FatArrow,
+            Token::DotDot => TokenType::DotDot,
+            Token::DotDotEqual => TokenType::DotDotEqual,
             Token::BoxColon => TokenType::BoxColon,
+            Token::QuestionDot => TokenType::QuestionDot,
 
             // Grouping
             Token::LeftParenthesis => TokenType::LeftParenthesis,
@@ -253,6 +383,7 @@ impl Token {
             // Keywords
             Token::Function => TokenType::Function,
             Token::Let => TokenType::Let,
+            Token::Const => TokenType::Const,
             Token::True => TokenType::True,
             Token::False => TokenType::False,
             Token::If => TokenType::If,
@@ -262,6 +393,17 @@ impl Token {
             Token::And => TokenType::And,
             Token::Not => TokenType::Not,
             Token::Return => TokenType::Return,
+            Token::While => TokenType::While,
+            Token::For => TokenType::For,
+            Token::In => TokenType::In,
+            Token::Break => TokenType::Break,
+            Token::Continue => TokenType::Continue,
+            Token::Struct => TokenType::Struct,
+            Token::Enum => TokenType::Enum,
+            Token::Match => TokenType::Match,
+            Token::As => TokenType::As,
+            Token::Xor => TokenType::Xor,
+            Token::Div => TokenType::Div,
         }
     }
 }
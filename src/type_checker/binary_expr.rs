@@ -1,5 +1,5 @@
 use crate::{
-    ast::ast::Expression,
+    ast::ast::{Expr, Expression},
     errors::HydorError,
     tokens::{Token, TokenType},
     type_checker::type_checker::{Type, TypeChecker},
@@ -14,9 +14,34 @@ impl TypeChecker {
         right: &Expression,
         span: Span,
     ) -> Result<Type, ()> {
+        // Logical operators only accept booleans on both sides
+        if matches!(operator.get_token_type(), TokenType::And | TokenType::Or) {
+            let left_result = self.check_condition(left);
+
+            // The right side of `x != nil and ...` only runs when x is not nil
+            let (when_true, when_false) = self.nil_checks(left);
+            let narrowings = if *operator == Token::And {
+                when_true
+            } else {
+                when_false
+            };
+
+            self.symbol_type_table.begin_scope();
+            self.narrow_all(narrowings);
+            let right_result = self.check_condition(right);
+            self.symbol_type_table.end_scope();
+
+            left_result?;
+            right_result?;
+            return Ok(Type::Bool);
+        }
+
+        if *operator == Token::QuestionQuestion {
+            return self.check_nil_coalescing(left, right, span);
+        }
+
         // If either side has an error, propagate it (stops cascading errors!)
-        let left_type = self.check_expression(left)?;
-        let right_type = self.check_expression(right)?;
+        let (left_type, right_type) = self.check_operands(left, right)?;
         let op_tok = operator.get_token_type();
 
         match op_tok {
@@ -33,10 +58,7 @@ impl TypeChecker {
                 }
 
                 // Both must be numeric or string
-                if left_type == Type::Integer
-                    || left_type == Type::Float
-                    || left_type == Type::String
-                {
+                if left_type.is_integer() || left_type == Type::Float || left_type == Type::String {
                     return Ok(left_type);
                 }
 
@@ -53,6 +75,26 @@ impl TypeChecker {
                 self.require_numeric_types(op_tok, left_type, right_type, span)
             }
 
+            // Integer only
+            TokenType::Percent
+            | TokenType::Div
+            | TokenType::Ampersand
+            | TokenType::Pipe
+            | TokenType::Xor
+            | TokenType::ShiftLeft
+            | TokenType::ShiftRight => {
+                if !left_type.is_integer() || left_type != right_type {
+                    self.throw_error(HydorError::InvalidBinaryOp {
+                        operator: op_tok,
+                        left_type,
+                        right_type,
+                        span,
+                    });
+                    return Err(());
+                }
+                Ok(left_type)
+            }
+
             // Comparison - returns Bool, not the operand type!
             TokenType::LessThan
             | TokenType::LessThanEqual
@@ -62,9 +104,9 @@ impl TypeChecker {
                 Ok(Type::Bool)
             }
 
-            // Equality
+            // Equality, an optional can be compared with its inner type and nil
             TokenType::Equal | TokenType::NotEqual => {
-                if left_type != right_type {
+                if !left_type.accepts(&right_type) && !right_type.accepts(&left_type) {
                     self.throw_error(HydorError::InvalidBinaryOp {
                         operator: op_tok,
                         left_type,
@@ -81,6 +123,37 @@ impl TypeChecker {
         }
     }
 
+    /// An integer literal on one side takes the width of the other side,
+    /// so `total + 1` works whichever integer type `total` has
+    fn check_operands(
+        &mut self,
+        left: &Expression,
+        right: &Expression,
+    ) -> Result<(Type, Type), ()> {
+        if Self::is_integer_literal(left) && !Self::is_integer_literal(right) {
+            let right_type = self.check_expression(right)?;
+            let left_type = self.check_expression_as(left, &right_type)?;
+            return Ok((left_type, right_type));
+        }
+
+        let left_type = self.check_expression(left)?;
+        let right_type = if Self::is_integer_literal(right) {
+            self.check_expression_as(right, &left_type)?
+        } else {
+            self.check_expression(right)?
+        };
+        Ok((left_type, right_type))
+    }
+
+    /// `5`, or a literal under a unary operator like `-5`
+    pub(crate) fn is_integer_literal(expr: &Expression) -> bool {
+        match &expr.node {
+            Expr::IntegerLiteral(_) => true,
+            Expr::Unary { right, .. } => Self::is_integer_literal(right),
+            _ => false,
+        }
+    }
+
     fn require_numeric_types(
         &mut self,
         op: TokenType,
@@ -98,7 +171,7 @@ impl TypeChecker {
             return Err(());
         }
 
-        if left != Type::Integer && left != Type::Float {
+        if !left.is_integer() && left != Type::Float {
             self.throw_error(HydorError::InvalidBinaryOp {
                 operator: op,
                 left_type: left.clone(),
@@ -0,0 +1,45 @@
+use crate::{
+    ast::{ast::Expression, type_annotation::TypeAnnotation},
+    errors::HydorError,
+    type_checker::type_checker::{Type, TypeChecker},
+    utils::Span,
+};
+
+impl Type {
+    /// Whether `value as target` is allowed. Numbers of every width convert
+    /// to each other, and numbers and bools can be written out as strings
+    pub fn casts_to(&self, target: &Type) -> bool {
+        let is_number = |t: &Type| t.is_integer() || *t == Type::Float;
+
+        match (self, target) {
+            _ if self == target => true,
+            _ if is_number(self) && is_number(target) => true,
+            (_, Type::String) => is_number(self) || *self == Type::Bool,
+            _ => false,
+        }
+    }
+}
+
+impl TypeChecker {
+    pub(crate) fn check_cast(
+        &mut self,
+        value: &Expression,
+        target: &TypeAnnotation,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let target = self.resolve_annotation(target);
+        self.check_type_exists(&target, span)?;
+        let value_type = self.check_expression(value)?;
+
+        if !value_type.casts_to(&target) {
+            self.throw_error(HydorError::InvalidCast {
+                from: value_type,
+                to: target,
+                span,
+            });
+            return Err(());
+        }
+
+        Ok(target)
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{
+    ast::ast::{Expr, Expression},
+    errors::HydorError,
+    type_checker::type_checker::{Type, TypeChecker},
+    utils::Span,
+};
+
+impl TypeChecker {
+    /// Check a value that is stored into a slot of type `expected`.
+    /// Collection literals take their element types from it, so `[]` and `{}` can be typed
+    pub(crate) fn check_expression_as(
+        &mut self,
+        expr: &Expression,
+        expected: &Type,
+    ) -> Result<Type, ()> {
+        match (&expr.node, expected) {
+            (Expr::ArrayLiteral { elements }, Type::Array(element_type)) => {
+                self.check_array_literal(elements, Some(element_type), expr.span)
+            }
+            (Expr::MapLiteral { entries }, Type::Map { key, value }) => {
+                self.check_map_literal(entries, Some((key, value)), expr.span)
+            }
+            (Expr::TupleLiteral { elements }, Type::Tuple(element_types))
+                if elements.len() == element_types.len() =>
+            {
+                self.check_tuple_literal(elements, Some(element_types))
+            }
+            // Literals inside an optional are typed like the inner type
+            (_, Type::Optional(inner)) => self.check_expression_as(expr, inner),
+            (Expr::IntegerLiteral(value), _) => {
+                self.check_integer_literal(*value, Some(expected), expr.span)
+            }
+            (Expr::Unary { operator, right }, _) => {
+                self.check_unary(operator, right, Some(expected), expr.span)
+            }
+            // Type arguments that the values do not pin down come from the context
+            (Expr::Call { callee, arguments }, _) => {
+                self.check_call(callee, arguments, Some(expected), expr.span)
+            }
+            (Expr::StructLiteral { name, fields }, _) => {
+                self.check_struct_literal(name, fields, Some(expected), expr.span)
+            }
+            (
+                Expr::Conditional {
+                    then_expr,
+                    condition,
+                    else_expr,
+                },
+                _,
+            ) => self.check_conditional(then_expr, condition, else_expr, Some(expected), expr.span),
+            (Expr::Match { subject, arms }, _) => {
+                self.check_match(subject, arms, Some(expected), expr.span)
+            }
+            (
+                Expr::EnumVariant {
+                    enum_name,
+                    variant,
+                    arguments,
+                },
+                _,
+            ) => self.check_enum_variant(enum_name, variant, arguments, Some(expected), expr.span),
+            _ => self.check_expression(expr),
+        }
+    }
+
+    /// Every element must share one type, taken from the first element that
+    /// is not an integer literal unless the context already expects one
+    pub(crate) fn check_array_literal(
+        &mut self,
+        elements: &[Expression],
+        expected: Option<&Type>,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let deciding = match expected {
+            Some(_) => None,
+            None => Self::deciding_element(elements.iter()),
+        };
+
+        let element_type = match (expected, deciding) {
+            (Some(expected), _) => expected.clone(),
+            (None, Some(deciding)) => self.check_expression(&elements[deciding])?,
+            (None, None) => {
+                self.throw_error(HydorError::UntypedEmptyArray { span });
+                return Err(());
+            }
+        };
+
+        let rest = Self::all_but(elements.iter(), deciding);
+        let result = self.check_all_as(rest, &element_type);
+
+        result.map(|()| Type::Array(Box::new(element_type)))
+    }
+
+    /// Like array literals, the first key and the first value that are not
+    /// integer literals decide the key and value types, unless the context
+    /// already expects them
+    pub(crate) fn check_map_literal(
+        &mut self,
+        entries: &[(Expression, Expression)],
+        expected: Option<(&Type, &Type)>,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let keys = entries.iter().map(|(key, _)| key);
+        let values = entries.iter().map(|(_, value)| value);
+
+        let (deciding_key, deciding_value) = match expected {
+            Some(_) => (None, None),
+            None => (
+                Self::deciding_element(keys.clone()),
+                Self::deciding_element(values.clone()),
+            ),
+        };
+
+        let (key_type, value_type) = match (expected, deciding_key, deciding_value) {
+            (Some((key, value)), _, _) => (key.clone(), value.clone()),
+            (None, Some(deciding_key), Some(deciding_value)) => {
+                let key = &entries[deciding_key].0;
+                let key_type = self.check_expression(key)?;
+                if !key_type.is_hashable() {
+                    self.throw_error(HydorError::UnhashableKeyType {
+                        found: key_type,
+                        span: key.span,
+                    });
+                    return Err(());
+                }
+                (key_type, self.check_expression(&entries[deciding_value].1)?)
+            }
+            _ => {
+                self.throw_error(HydorError::UntypedEmptyMap { span });
+                return Err(());
+            }
+        };
+
+        let keys_result = self.check_all_as(Self::all_but(keys, deciding_key), &key_type);
+        let values_result = self.check_all_as(Self::all_but(values, deciding_value), &value_type);
+
+        keys_result.and(values_result).map(|()| Type::Map {
+            key: Box::new(key_type),
+            value: Box::new(value_type),
+        })
+    }
+
+    /// `array[index]` is the element type, `map[key]` the value type
+    pub(crate) fn check_index(
+        &mut self,
+        object: &Expression,
+        index: &Expression,
+    ) -> Result<Type, ()> {
+        let object_type = self.check_expression(object)?;
+
+        let (expected_index, element_type) = match object_type {
+            Type::Array(element_type) => (Type::Integer, *element_type),
+            Type::Map { key, value } => (*key, *value),
+            found => {
+                self.throw_error(HydorError::NotIndexable {
+                    found,
+                    span: object.span,
+                });
+                return Err(());
+            }
+        };
+
+        // `m[3]` on a `Map<Byte, Int>` is a Byte key
+        let index_type = self.check_expression_as(index, &expected_index)?;
+        if index_type != expected_index {
+            self.throw_error(HydorError::TypeMismatch {
+                expected: vec![expected_index],
+                found: index_type,
+                span: index.span,
+            });
+            return Err(());
+        }
+
+        Ok(element_type)
+    }
+
+    pub(crate) fn check_method_call(
+        &mut self,
+        object: &Expression,
+        method: &str,
+        arguments: &[Expression],
+        span: Span,
+    ) -> Result<Type, ()> {
+        let object_type = self.check_expression(object)?;
+        self.method_call_type(object_type, method, arguments, span)
+    }
+
+    pub(crate) fn method_call_type(
+        &mut self,
+        object_type: Type,
+        method: &str,
+        arguments: &[Expression],
+        span: Span,
+    ) -> Result<Type, ()> {
+        let (params, return_type) = match Self::method_signature(&object_type, method) {
+            Some(signature) => signature,
+            None => {
+                self.throw_error(HydorError::UnknownMethod {
+                    found: object_type,
+                    method: method.to_string(),
+                    span,
+                });
+                return Err(());
+            }
+        };
+
+        self.check_arguments(params, arguments, span)?;
+        Ok(return_type)
+    }
+
+    /// Parameter and return types of the built in methods of `object_type`
+    pub(crate) fn method_signature(object_type: &Type, method: &str) -> Option<(Vec<Type>, Type)> {
+        match (object_type, method) {
+            (Type::Map { key, .. }, "has") => Some((vec![*key.clone()], Type::Bool)),
+            (Type::Map { key, .. }, "remove") => Some((vec![*key.clone()], Type::Bool)),
+            (Type::Map { key, .. }, "keys") => Some((vec![], Type::Array(key.clone()))),
+            (
+                integer_type,
+                "wrapping_add" | "wrapping_sub" | "wrapping_mul" | "saturating_add"
+                | "saturating_sub" | "saturating_mul",
+            ) if integer_type.is_integer() => {
+                Some((vec![integer_type.clone()], integer_type.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Index of the element whose type a collection literal takes: the first
+    /// one that is not an integer literal, since the width of those is up to
+    /// the context, or else the first one
+    pub(crate) fn deciding_element<'a>(
+        elements: impl Iterator<Item = &'a Expression> + Clone,
+    ) -> Option<usize> {
+        elements
+            .clone()
+            .position(|element| !Self::is_integer_literal(element))
+            .or_else(|| elements.clone().next().map(|_| 0))
+    }
+
+    /// Every element except the one at `skipped`
+    pub(crate) fn all_but<'a>(
+        elements: impl Iterator<Item = &'a Expression>,
+        skipped: Option<usize>,
+    ) -> impl Iterator<Item = &'a Expression> {
+        elements
+            .enumerate()
+            .filter(move |(i, _)| Some(*i) != skipped)
+            .map(|(_, element)| element)
+    }
+
+    /// Check each expression against `expected`, reporting every mismatch
+    pub(crate) fn check_all_as<'a>(
+        &mut self,
+        exprs: impl Iterator<Item = &'a Expression>,
+        expected: &Type,
+    ) -> Result<(), ()> {
+        let mut result = Ok(());
+
+        for expr in exprs {
+            let found = match self.check_expression_as(expr, expected) {
+                Ok(t) => t,
+                Err(()) => {
+                    result = Err(());
+                    continue;
+                }
+            };
+
+            if !expected.accepts(&found) {
+                self.throw_error(HydorError::TypeMismatch {
+                    expected: vec![expected.clone()],
+                    found,
+                    span: expr.span,
+                });
+                result = Err(());
+            }
+        }
+
+        result
+    }
+}
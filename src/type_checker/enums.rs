@@ -0,0 +1,239 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    ast::{
+        ast::{Expr, Expression, Variant},
+        type_annotation::TypeAnnotation,
+    },
+    errors::HydorError,
+    type_checker::type_checker::{Type, TypeChecker},
+    utils::Span,
+};
+
+#[derive(Debug, Clone)]
+pub struct EnumDefinition {
+    pub type_params: Vec<String>,
+    /// Variants and their payload types in declaration order,
+    /// a variant's position is its runtime tag
+    pub variants: Vec<(String, Vec<Type>)>,
+    pub span: Span,
+}
+
+impl EnumDefinition {
+    /// Tag and payload types of `name`
+    pub fn variant(&self, name: &str) -> Option<(usize, &[Type])> {
+        self.variants
+            .iter()
+            .enumerate()
+            .find(|(_, (variant, _))| variant == name)
+            .map(|(tag, (_, payload))| (tag, payload.as_slice()))
+    }
+
+    /// Type of a value of this enum before its type arguments are known
+    pub fn declared_type(&self, name: &str) -> Type {
+        let type_args = self.type_params.iter().cloned().map(Type::TypeParameter);
+        Type::Enum(name.to_string(), type_args.collect())
+    }
+}
+
+impl Type {
+    /// An annotation alone cannot tell a struct name from an enum name or a
+    /// type parameter, so named types start out as structs and are corrected here
+    pub fn resolve_names(
+        self,
+        enums: &HashMap<String, EnumDefinition>,
+        type_parameters: &[String],
+    ) -> Self {
+        let resolve = |t: Type| t.resolve_names(enums, type_parameters);
+
+        match self {
+            Type::Struct(name, type_args)
+                if type_args.is_empty() && type_parameters.contains(&name) =>
+            {
+                Type::TypeParameter(name)
+            }
+            Type::Struct(name, type_args) => {
+                let type_args = type_args.into_iter().map(resolve).collect();
+                if enums.contains_key(&name) {
+                    Type::Enum(name, type_args)
+                } else {
+                    Type::Struct(name, type_args)
+                }
+            }
+            Type::Array(element_type) => Type::Array(Box::new(resolve(*element_type))),
+            Type::Optional(inner) => Type::Optional(Box::new(resolve(*inner))),
+            Type::Map { key, value } => Type::Map {
+                key: Box::new(resolve(*key)),
+                value: Box::new(resolve(*value)),
+            },
+            Type::Tuple(elements) => Type::Tuple(elements.into_iter().map(resolve).collect()),
+            Type::Function {
+                params,
+                return_type,
+            } => Type::Function {
+                params: params.into_iter().map(resolve).collect(),
+                return_type: Box::new(resolve(*return_type)),
+            },
+            other => other,
+        }
+    }
+}
+
+impl TypeChecker {
+    /// Type an annotation refers to, given the enums declared so far
+    /// and the type parameters in scope
+    pub(crate) fn resolve_annotation(&self, an_type: &TypeAnnotation) -> Type {
+        Type::from_anotated_type(an_type).resolve_names(&self.enums, &self.type_parameters)
+    }
+
+    pub(crate) fn check_enum_declaration(
+        &mut self,
+        identifier: &Expression,
+        type_params: &[String],
+        variants: &[Variant],
+    ) -> Result<(), ()> {
+        self.declare_enum(identifier, type_params)?;
+        self.define_enum(identifier, type_params, variants)
+    }
+
+    /// Register the name of an enum without its variants, so annotations
+    /// resolve it to an enum and payload types can refer to it
+    pub(crate) fn declare_enum(
+        &mut self,
+        identifier: &Expression,
+        type_params: &[String],
+    ) -> Result<(), ()> {
+        let name = match &identifier.node {
+            Expr::Identifier(name) => name.clone(),
+            _ => unreachable!("Enum names are always identifiers"),
+        };
+
+        if let Some(original_span) = self.declared_type_span(&name) {
+            self.throw_error(HydorError::TypeRedeclaration {
+                name,
+                original_span,
+                redeclaration_span: identifier.span,
+            });
+            return Err(());
+        }
+
+        self.enums.insert(
+            name,
+            EnumDefinition {
+                type_params: type_params.to_vec(),
+                variants: Vec::new(),
+                span: identifier.span,
+            },
+        );
+        Ok(())
+    }
+
+    /// Resolve and check the payloads of an enum that was already declared
+    pub(crate) fn define_enum(
+        &mut self,
+        identifier: &Expression,
+        type_params: &[String],
+        variants: &[Variant],
+    ) -> Result<(), ()> {
+        let name = match &identifier.node {
+            Expr::Identifier(name) => name.clone(),
+            _ => unreachable!("Enum names are always identifiers"),
+        };
+
+        let mut result = Ok(());
+        let mut seen = HashSet::new();
+        for variant in variants {
+            if !seen.insert(&variant.name) {
+                self.throw_error(HydorError::DuplicateVariant {
+                    variant: variant.name.clone(),
+                    span: variant.span,
+                });
+                result = Err(());
+            }
+        }
+
+        let resolved: Vec<(String, Vec<Type>)> =
+            self.with_type_parameters(type_params, |checker| {
+                variants
+                    .iter()
+                    .map(|v| {
+                        let payload = v.payload.iter().map(|t| checker.resolve_annotation(t));
+                        (v.name.clone(), payload.collect())
+                    })
+                    .collect()
+            });
+
+        for (variant, (_, payload)) in variants.iter().zip(&resolved) {
+            for payload_type in payload {
+                result = result.and(self.check_type_exists(payload_type, variant.span));
+            }
+        }
+
+        if let Some(definition) = self.enums.get_mut(&name) {
+            definition.variants = resolved;
+        }
+
+        result
+    }
+
+    /// `Enum::Variant(...)`, the payload is checked like call arguments.
+    /// The type arguments of a generic enum are inferred from the payload and
+    /// the `expected` type
+    pub(crate) fn check_enum_variant(
+        &mut self,
+        enum_name: &str,
+        variant: &str,
+        arguments: &[Expression],
+        expected: Option<&Type>,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let definition = match self.enums.get(enum_name) {
+            Some(definition) => definition.clone(),
+            None => {
+                self.throw_error(HydorError::InvalidTypeName {
+                    got: enum_name.to_string(),
+                    span,
+                });
+                return Err(());
+            }
+        };
+
+        let payload = match definition.variant(variant) {
+            Some((_, payload)) => payload.to_vec(),
+            None => {
+                self.throw_error(HydorError::UnknownVariant {
+                    name: enum_name.to_string(),
+                    variant: variant.to_string(),
+                    span,
+                });
+                return Err(());
+            }
+        };
+
+        if definition.type_params.is_empty() {
+            self.check_arguments(payload, arguments, span)?;
+            return Ok(Type::Enum(enum_name.to_string(), Vec::new()));
+        }
+
+        let declared_type = definition.declared_type(enum_name);
+        let type_args = self.check_generic_arguments(
+            &definition.type_params,
+            payload,
+            arguments,
+            expected.map(|expected| (&declared_type, expected)),
+            span,
+        )?;
+
+        let enum_type = declared_type.substitute(&type_args);
+        self.expr_types.insert(span, enum_type.clone());
+        Ok(enum_type)
+    }
+
+    /// Where the struct or enum called `name` was declared, if it exists
+    pub(crate) fn declared_type_span(&self, name: &str) -> Option<Span> {
+        self.structs
+            .get(name)
+            .map(|definition| definition.span)
+            .or_else(|| self.enums.get(name).map(|definition| definition.span))
+    }
+}
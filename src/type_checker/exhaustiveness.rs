@@ -0,0 +1,384 @@
+use std::iter;
+
+use crate::{
+    ast::ast::{Expr, MatchArm, Pat, Pattern},
+    errors::HydorError,
+    type_checker::type_checker::{Type, TypeChecker},
+    utils::Span,
+};
+
+/// A pattern reduced to what matters for exhaustiveness, bindings become wildcards
+#[derive(Debug, Clone)]
+enum Space {
+    Wildcard,
+    Constructor(Constructor, Vec<Space>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Constructor {
+    Bool(bool),
+    Nil,
+    /// Inclusive bounds, a literal is a range of a single value
+    IntRange(i64, i64),
+    /// Float and string literals and float ranges, which only cover themselves
+    Opaque(String),
+    Struct,
+    /// Tag of the variant
+    Variant(usize),
+}
+
+impl Constructor {
+    /// Whether every value matched by `other` is also matched by `self`
+    fn covers(&self, other: &Constructor) -> bool {
+        match (self, other) {
+            (Constructor::IntRange(start, end), Constructor::IntRange(other_start, other_end)) => {
+                start <= other_start && other_end <= end
+            }
+            _ => self == other,
+        }
+    }
+}
+
+/// One arm, or the remaining columns of one while the patterns are taken apart
+type Row = Vec<Space>;
+
+impl Space {
+    fn is_wildcard(&self) -> bool {
+        matches!(self, Space::Wildcard)
+    }
+}
+
+/// Usefulness checking over a matrix of patterns, as described in
+/// "Warnings for pattern matching" (Maranget, 2007)
+impl TypeChecker {
+    /// The arms without a guard must match every value of the subject,
+    /// and every arm must match some value the arms before it do not
+    pub(crate) fn check_exhaustiveness(
+        &mut self,
+        subject_type: &Type,
+        arms: &[MatchArm],
+        span: Span,
+    ) -> Result<(), ()> {
+        let types = vec![subject_type.clone()];
+        let mut rows: Vec<Row> = Vec::new();
+
+        for arm in arms {
+            let row = vec![self.to_space(&arm.pattern)];
+
+            if !self.is_useful(&rows, &row, &types) {
+                self.warnings.add(HydorError::UnreachablePattern {
+                    span: arm.pattern.span,
+                });
+            }
+
+            // A guard can fail, so its arm does not count towards exhaustiveness
+            if arm.guard.is_none() {
+                rows.push(row);
+            }
+        }
+
+        if let Some(mut missing) = self.find_missing(&rows, &types) {
+            self.throw_error(HydorError::NonExhaustiveMatch {
+                missing: missing.remove(0),
+                span,
+            });
+            return Err(());
+        }
+
+        Ok(())
+    }
+
+    fn to_space(&self, pattern: &Pattern) -> Space {
+        match &pattern.node {
+            Pat::Wildcard | Pat::Binding(_) => Space::Wildcard,
+
+            Pat::Literal(literal) => {
+                let constructor = match literal {
+                    Expr::IntegerLiteral(n) => Constructor::IntRange(*n, *n),
+                    Expr::BooleanLiteral(b) => Constructor::Bool(*b),
+                    Expr::NilLiteral => Constructor::Nil,
+                    Expr::FloatLiteral(n) => Constructor::Opaque(n.to_string()),
+                    Expr::StringLiteral(s) => Constructor::Opaque(format!("{:?}", s)),
+                    _ => unreachable!("Literal patterns always hold a literal"),
+                };
+                Space::Constructor(constructor, Vec::new())
+            }
+
+            Pat::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                // An exclusive range ending at the smallest Int64 has no last value
+                let last = match end {
+                    Expr::IntegerLiteral(end) if *inclusive => Some(*end),
+                    Expr::IntegerLiteral(end) => end.checked_sub(1),
+                    _ => None,
+                };
+                let constructor = match (start, last) {
+                    (Expr::IntegerLiteral(start), Some(last)) => {
+                        Constructor::IntRange(*start, last)
+                    }
+                    _ => Constructor::Opaque(format!("{:?}..{:?}{}", start, end, inclusive)),
+                };
+                Space::Constructor(constructor, Vec::new())
+            }
+
+            // Fields that are left out of the pattern match anything
+            Pat::Struct { name, fields } => {
+                let fields = self.structs[name]
+                    .fields
+                    .iter()
+                    .map(|(field, _)| {
+                        fields
+                            .iter()
+                            .find(|f| f.name == *field)
+                            .map_or(Space::Wildcard, |f| self.to_space(&f.pattern))
+                    })
+                    .collect();
+                Space::Constructor(Constructor::Struct, fields)
+            }
+
+            Pat::Variant {
+                enum_name,
+                variant,
+                payload,
+            } => {
+                let (tag, _) = self.enums[enum_name]
+                    .variant(variant)
+                    .expect("Unknown variants are reported before exhaustiveness");
+                let payload = payload.iter().map(|p| self.to_space(p)).collect();
+                Space::Constructor(Constructor::Variant(tag), payload)
+            }
+        }
+    }
+
+    /// Whether `row` matches a value that none of `rows` match
+    fn is_useful(&self, rows: &[Row], row: &[Space], types: &[Type]) -> bool {
+        if rows.is_empty() {
+            return true;
+        }
+        if row.is_empty() || rows.iter().any(|r| r.iter().all(Space::is_wildcard)) {
+            return false;
+        }
+
+        match &row[0] {
+            // Earlier ranges may each cover only part of this one
+            Space::Constructor(Constructor::IntRange(start, end), _) => {
+                Self::split_range(rows, *start, *end).iter().any(|part| {
+                    let specialized = Self::specialize(rows, part, 0);
+                    self.is_useful(&specialized, &row[1..], &types[1..])
+                })
+            }
+
+            Space::Constructor(constructor, fields) => {
+                let specialized = Self::specialize(rows, constructor, fields.len());
+                let row: Row = fields.iter().chain(&row[1..]).cloned().collect();
+                let types = self.with_field_types(constructor, types);
+
+                self.is_useful(&specialized, &row, &types)
+            }
+
+            Space::Wildcard => match self.complete_signature(rows, &types[0]) {
+                Some(constructors) => constructors.iter().any(|constructor| {
+                    let expanded = self.with_field_types(constructor, types);
+                    let arity = expanded.len() - (types.len() - 1);
+                    let specialized = Self::specialize(rows, constructor, arity);
+
+                    let row: Row = iter::repeat_n(Space::Wildcard, arity)
+                        .chain(row[1..].iter().cloned())
+                        .collect();
+                    self.is_useful(&specialized, &row, &expanded)
+                }),
+                None => self.is_useful(&Self::default_rows(rows), &row[1..], &types[1..]),
+            },
+        }
+    }
+
+    /// Example values, one per column, that none of `rows` match
+    fn find_missing(&self, rows: &[Row], types: &[Type]) -> Option<Vec<String>> {
+        if rows.is_empty() {
+            return Some(vec!["_".to_string(); types.len()]);
+        }
+        if types.is_empty() || rows.iter().any(|r| r.iter().all(Space::is_wildcard)) {
+            return None;
+        }
+
+        let rest = &types[1..];
+
+        if let Some(constructors) = self.complete_signature(rows, &types[0]) {
+            return constructors.iter().find_map(|constructor| {
+                let expanded = self.with_field_types(constructor, types);
+                let arity = expanded.len() - rest.len();
+                let specialized = Self::specialize(rows, constructor, arity);
+
+                let mut missing = self.find_missing(&specialized, &expanded)?;
+                let fields: Vec<String> = missing.drain(..arity).collect();
+                missing.insert(0, self.describe(constructor, &types[0], &fields));
+                Some(missing)
+            });
+        }
+
+        let mut missing = self.find_missing(&Self::default_rows(rows), rest)?;
+
+        // Name a constructor no arm mentions, when the type has finitely many
+        let example = self
+            .all_constructors(rows, &types[0])
+            .and_then(|all| {
+                all.into_iter()
+                    .find(|c| !rows.iter().any(|r| Self::head_covers(r, c)))
+            })
+            .map_or("_".to_string(), |constructor| {
+                let arity = self.with_field_types(&constructor, &types[..1]).len();
+                self.describe(&constructor, &types[0], &vec!["_".to_string(); arity])
+            });
+
+        missing.insert(0, example);
+        Some(missing)
+    }
+
+    /// Every constructor of `ty` if each one is covered by the first column of `rows`
+    fn complete_signature(&self, rows: &[Row], ty: &Type) -> Option<Vec<Constructor>> {
+        let all = self.all_constructors(rows, ty)?;
+        let complete = all
+            .iter()
+            .all(|c| rows.iter().any(|r| Self::head_covers(r, c)));
+
+        complete.then_some(all)
+    }
+
+    /// Constructors of `ty`, if it has finitely many. Integers are split
+    /// into the ranges that the first column of `rows` tells apart
+    fn all_constructors(&self, rows: &[Row], ty: &Type) -> Option<Vec<Constructor>> {
+        if let Some((min, max)) = ty.integer_range() {
+            return Some(Self::split_range(rows, min, max));
+        }
+
+        match ty {
+            Type::Bool => Some(vec![Constructor::Bool(true), Constructor::Bool(false)]),
+            Type::Nil => Some(vec![Constructor::Nil]),
+            Type::Struct(..) => Some(vec![Constructor::Struct]),
+            Type::Enum(name, _) => Some(
+                (0..self.enums[name].variants.len())
+                    .map(Constructor::Variant)
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Cut `start..=end` wherever a range in the first column of `rows` starts
+    /// or ends, so each part is either inside or outside every one of them
+    fn split_range(rows: &[Row], start: i64, end: i64) -> Vec<Constructor> {
+        let mut cuts: Vec<i64> = rows
+            .iter()
+            .filter_map(|row| match &row[0] {
+                Space::Constructor(Constructor::IntRange(first, last), _) => {
+                    Some([Some(*first), last.checked_add(1)])
+                }
+                _ => None,
+            })
+            .flatten()
+            .flatten()
+            .filter(|&cut| start < cut && cut <= end)
+            .collect();
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        let starts = iter::once(start).chain(cuts.iter().copied());
+        let ends = cuts.iter().map(|cut| cut - 1).chain(iter::once(end));
+        starts
+            .zip(ends)
+            .map(|(first, last)| Constructor::IntRange(first, last))
+            .collect()
+    }
+
+    /// Column types once the first column is replaced by the fields of `constructor`
+    fn with_field_types(&self, constructor: &Constructor, types: &[Type]) -> Vec<Type> {
+        let fields = match (&types[0], constructor) {
+            (Type::Struct(name, type_args), Constructor::Struct) => {
+                let definition = &self.structs[name];
+                definition
+                    .fields
+                    .iter()
+                    .map(|(_, field_type)| {
+                        field_type.instantiate(&definition.type_params, type_args)
+                    })
+                    .collect()
+            }
+            (Type::Enum(name, type_args), Constructor::Variant(tag)) => {
+                let definition = &self.enums[name];
+                definition.variants[*tag]
+                    .1
+                    .iter()
+                    .map(|t| t.instantiate(&definition.type_params, type_args))
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+
+        fields
+            .into_iter()
+            .chain(types[1..].iter().cloned())
+            .collect()
+    }
+
+    /// Rows that match `constructor`, with its fields spliced in place of the first column
+    fn specialize(rows: &[Row], constructor: &Constructor, arity: usize) -> Vec<Row> {
+        rows.iter()
+            .filter_map(|row| {
+                let mut fields = match &row[0] {
+                    Space::Wildcard => vec![Space::Wildcard; arity],
+                    Space::Constructor(c, fields) if c.covers(constructor) => fields.clone(),
+                    Space::Constructor(..) => return None,
+                };
+                fields.extend_from_slice(&row[1..]);
+                Some(fields)
+            })
+            .collect()
+    }
+
+    /// Rows that match anything in the first column, without that column
+    fn default_rows(rows: &[Row]) -> Vec<Row> {
+        rows.iter()
+            .filter(|row| row[0].is_wildcard())
+            .map(|row| row[1..].to_vec())
+            .collect()
+    }
+
+    fn head_covers(row: &Row, constructor: &Constructor) -> bool {
+        match &row[0] {
+            Space::Wildcard => false,
+            Space::Constructor(c, _) => c.covers(constructor),
+        }
+    }
+
+    /// Render `constructor` the way it would be written in a pattern
+    fn describe(&self, constructor: &Constructor, ty: &Type, fields: &[String]) -> String {
+        match (constructor, ty) {
+            (Constructor::Bool(b), _) => b.to_string(),
+            (Constructor::Nil, _) => "nil".to_string(),
+            (Constructor::IntRange(first, last), _) if first == last => first.to_string(),
+            (Constructor::IntRange(first, last), _) => format!("{}..={}", first, last),
+            (Constructor::Struct, Type::Struct(name, _)) => {
+                let fields = self.structs[name]
+                    .fields
+                    .iter()
+                    .zip(fields)
+                    .map(|((field, _), pattern)| format!("{}: {}", field, pattern))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!("{} {{ {} }}", name, fields)
+            }
+            (Constructor::Variant(tag), Type::Enum(name, _)) => {
+                let variant = &self.enums[name].variants[*tag].0;
+                if fields.is_empty() {
+                    format!("{}::{}", name, variant)
+                } else {
+                    format!("{}::{}({})", name, variant, fields.join(", "))
+                }
+            }
+            _ => "_".to_string(),
+        }
+    }
+}
@@ -0,0 +1,402 @@
+use std::{collections::HashMap, mem};
+
+use crate::{
+    ast::{
+        ast::{Expr, Expression, Parameter, Statement, Stmt},
+        type_annotation::TypeAnnotation,
+    },
+    errors::HydorError,
+    type_checker::type_checker::{Type, TypeChecker},
+    utils::Span,
+};
+
+/// `Call` and function objects store their arity in a single byte
+const MAX_ARITY: usize = u8::MAX as usize;
+
+impl TypeChecker {
+    pub(crate) fn check_function_declaration(
+        &mut self,
+        identifier: &Expression,
+        type_params: &[String],
+        parameters: &[Parameter],
+        return_type: Option<&TypeAnnotation>,
+        body: &[Statement],
+        span: Span,
+    ) -> Result<(), ()> {
+        let name = match &identifier.node {
+            Expr::Identifier(name) => name.clone(),
+            _ => unreachable!("Function names are always identifiers"),
+        };
+
+        // Top-level functions were declared by the first pass, a missing entry
+        // means that declaration clashed with an earlier one
+        if self.symbol_type_table.in_global_scope() {
+            if self.hoisted_functions.get(&name) != Some(&identifier.span) {
+                return Err(());
+            }
+            self.hoisted_functions.remove(&name);
+        }
+
+        self.with_type_parameters(type_params, |checker| {
+            if !checker.symbol_type_table.in_global_scope() {
+                // Declared before the body is checked so the function can call itself
+                let function_type = checker.resolve_signature(parameters, return_type);
+                checker.symbol_type_table.declare_identifier(
+                    name.clone(),
+                    Type::generic(type_params, function_type),
+                    identifier.span,
+                    false,
+                    &mut checker.errors,
+                )?;
+            }
+
+            checker.check_function_body(&name, parameters, return_type, body, span)?;
+            Ok(())
+        })
+    }
+
+    /// Declare every top-level type name, then their fields and payloads, then
+    /// every top-level function signature, before any statement is checked, so
+    /// top-level types and functions can refer to each other regardless of
+    /// their order
+    pub(crate) fn declare_top_level(&mut self, statements: &[Statement]) {
+        // Errors are reported, the rest of the program is still checked
+        let declared: Vec<&Statement> = statements
+            .iter()
+            .filter(|stmt| match &stmt.node {
+                Stmt::StructDeclaration {
+                    identifier,
+                    type_params,
+                    ..
+                } => self.declare_struct(identifier, type_params).is_ok(),
+                Stmt::EnumDeclaration {
+                    identifier,
+                    type_params,
+                    ..
+                } => self.declare_enum(identifier, type_params).is_ok(),
+                _ => false,
+            })
+            .collect();
+
+        for stmt in declared {
+            let _ = match &stmt.node {
+                Stmt::StructDeclaration {
+                    identifier,
+                    type_params,
+                    fields,
+                } => self.define_struct(identifier, type_params, fields),
+                Stmt::EnumDeclaration {
+                    identifier,
+                    type_params,
+                    variants,
+                } => self.define_enum(identifier, type_params, variants),
+                _ => unreachable!("Only type declarations were declared"),
+            };
+        }
+
+        for stmt in statements {
+            let Stmt::FunctionDeclaration {
+                identifier,
+                type_params,
+                parameters,
+                return_type,
+                ..
+            } = &stmt.node
+            else {
+                continue;
+            };
+
+            let name = match &identifier.node {
+                Expr::Identifier(name) => name.clone(),
+                _ => unreachable!("Function names are always identifiers"),
+            };
+
+            let function_type = self.with_type_parameters(type_params, |checker| {
+                checker.resolve_signature(parameters, return_type.as_ref())
+            });
+            let declared = self.symbol_type_table.declare_identifier(
+                name.clone(),
+                Type::generic(type_params, function_type),
+                identifier.span,
+                false,
+                &mut self.errors,
+            );
+
+            if declared.is_ok() {
+                self.hoisted_functions.insert(name, identifier.span);
+            }
+        }
+    }
+
+    /// Top-level code runs in order, so it cannot use a function before the
+    /// function's declaration has run. Function bodies only run once called
+    pub(crate) fn check_hoisted_function_use(&mut self, name: &str, span: Span) -> Result<(), ()> {
+        let not_yet_declared = self.return_types.is_empty()
+            && self.hoisted_functions.contains_key(name)
+            && self.symbol_type_table.is_global(name);
+
+        if not_yet_declared {
+            self.throw_error(HydorError::UndefinedVariable {
+                name: name.to_string(),
+                span,
+            });
+            return Err(());
+        }
+
+        Ok(())
+    }
+
+    fn resolve_signature(
+        &self,
+        parameters: &[Parameter],
+        return_type: Option<&TypeAnnotation>,
+    ) -> Type {
+        Type::from_signature(parameters, return_type)
+            .resolve_names(&self.enums, &self.type_parameters)
+    }
+
+    /// Check the body of a declared or anonymous function, returning its type
+    pub(crate) fn check_function_body(
+        &mut self,
+        name: &str,
+        parameters: &[Parameter],
+        return_type: Option<&TypeAnnotation>,
+        body: &[Statement],
+        span: Span,
+    ) -> Result<Type, ()> {
+        let function_type = self.resolve_signature(parameters, return_type);
+        let return_type = return_type.map_or(Type::Nil, |t| self.resolve_annotation(t));
+
+        let mut signature_result = self.check_type_exists(&return_type, span);
+        if parameters.len() > MAX_ARITY {
+            self.throw_error(HydorError::TooManyParameters {
+                count: parameters.len(),
+                span,
+            });
+            signature_result = Err(());
+        }
+        for param in parameters {
+            let param_type = self.resolve_annotation(&param.annotated_type);
+            signature_result =
+                signature_result.and(self.check_type_exists(&param_type, param.span));
+        }
+
+        // Enclosing locals stay visible, so the body can capture them
+        let narrowings = self.symbol_type_table.suspend_narrowings();
+        self.symbol_type_table.begin_scope();
+        for param in parameters {
+            // Parameters behave like 'let' bindings inside the body
+            let _ = self.symbol_type_table.declare_identifier(
+                param.name.clone(),
+                self.resolve_annotation(&param.annotated_type),
+                param.span,
+                true,
+                &mut self.errors,
+            );
+        }
+
+        // Loops outside the function cannot be targeted from inside of it
+        let enclosing_loops = mem::take(&mut self.loop_labels);
+        self.return_types.push(return_type.clone());
+
+        self.check_block(body);
+
+        self.return_types.pop();
+        self.loop_labels = enclosing_loops;
+        self.symbol_type_table.end_scope();
+        self.symbol_type_table.restore_narrowings(narrowings);
+
+        if return_type != Type::Nil && !Self::always_returns(body) {
+            self.throw_error(HydorError::MissingReturn {
+                name: name.to_string(),
+                expected: return_type,
+                span,
+            });
+            return Err(());
+        }
+
+        signature_result.map(|()| function_type)
+    }
+
+    pub(crate) fn check_return(
+        &mut self,
+        value: Option<&Expression>,
+        span: Span,
+    ) -> Result<(), ()> {
+        let expected = match self.return_types.last() {
+            Some(t) => t.clone(),
+            None => {
+                self.throw_error(HydorError::ReturnOutsideFunction { span });
+                return Err(());
+            }
+        };
+
+        let (got, error_span) = match value {
+            Some(value) => (self.check_expression_as(value, &expected)?, value.span),
+            None => (Type::Nil, span),
+        };
+
+        if !expected.accepts(&got) {
+            self.throw_error(HydorError::ReturnTypeMismatch {
+                expected,
+                got,
+                span: error_span,
+            });
+            return Err(());
+        }
+
+        Ok(())
+    }
+
+    /// The type arguments of a generic function are inferred from the
+    /// arguments and the `expected` type of the result
+    pub(crate) fn check_call(
+        &mut self,
+        callee: &Expression,
+        arguments: &[Expression],
+        expected: Option<&Type>,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let callee_type = self.check_expression(callee)?;
+
+        if arguments.len() > MAX_ARITY {
+            self.throw_error(HydorError::TooManyArguments {
+                count: arguments.len(),
+                span,
+            });
+            return Err(());
+        }
+
+        let (params, return_type) = match callee_type {
+            Type::Function {
+                params,
+                return_type,
+            } => (params, *return_type),
+            Type::GenericFunction {
+                type_params,
+                function,
+            } => {
+                let (params, return_type) = match *function {
+                    Type::Function {
+                        params,
+                        return_type,
+                    } => (params, *return_type),
+                    _ => unreachable!("Only functions are generic over their calls"),
+                };
+
+                let type_args = self.check_generic_arguments(
+                    &type_params,
+                    params,
+                    arguments,
+                    expected.map(|expected| (&return_type, expected)),
+                    span,
+                )?;
+
+                // The compiler cannot infer this again
+                let return_type = return_type.substitute(&type_args);
+                self.expr_types.insert(span, return_type.clone());
+                return Ok(return_type);
+            }
+            found => {
+                self.throw_error(HydorError::NotCallable {
+                    found,
+                    span: callee.span,
+                });
+                return Err(());
+            }
+        };
+
+        self.check_arguments(params, arguments, span)?;
+        Ok(return_type)
+    }
+
+    /// Check call arguments against the parameter types, reporting every bad argument
+    pub(crate) fn check_arguments(
+        &mut self,
+        params: Vec<Type>,
+        arguments: &[Expression],
+        span: Span,
+    ) -> Result<(), ()> {
+        if params.len() != arguments.len() {
+            self.throw_error(HydorError::ArgumentCountMismatch {
+                expected: params.len(),
+                got: arguments.len(),
+                span,
+            });
+            return Err(());
+        }
+
+        let mut result = Ok(());
+        for (argument, expected) in arguments.iter().zip(params) {
+            let got = match self.check_expression_as(argument, &expected) {
+                Ok(t) => t,
+                Err(()) => {
+                    result = Err(());
+                    continue;
+                }
+            };
+
+            if !expected.accepts(&got) {
+                self.throw_error(HydorError::ArgumentTypeMismatch {
+                    expected,
+                    got,
+                    span: argument.span,
+                });
+                result = Err(());
+            }
+        }
+
+        result
+    }
+
+    /// Like `check_arguments`, inferring `type_params` from the arguments
+    pub(crate) fn check_generic_arguments(
+        &mut self,
+        type_params: &[String],
+        params: Vec<Type>,
+        arguments: &[Expression],
+        expected: Option<(&Type, &Type)>,
+        span: Span,
+    ) -> Result<HashMap<String, Type>, ()> {
+        if params.len() != arguments.len() {
+            self.throw_error(HydorError::ArgumentCountMismatch {
+                expected: params.len(),
+                got: arguments.len(),
+                span,
+            });
+            return Err(());
+        }
+
+        let values: Vec<(Type, &Expression)> = params.into_iter().zip(arguments).collect();
+        let (type_args, checked) =
+            self.infer_type_arguments(type_params, &values, expected, span)?;
+
+        let mut result = Ok(type_args);
+        for ((expected, got), argument) in checked.into_iter().zip(arguments) {
+            if !expected.accepts(&got) {
+                self.throw_error(HydorError::ArgumentTypeMismatch {
+                    expected,
+                    got,
+                    span: argument.span,
+                });
+                result = Err(());
+            }
+        }
+
+        result
+    }
+
+    /// Whether every path through `statements` ends in a `return`
+    fn always_returns(statements: &[Statement]) -> bool {
+        statements.iter().any(|stmt| match &stmt.node {
+            Stmt::Return { .. } => true,
+            Stmt::Block { body } => Self::always_returns(body),
+            Stmt::If {
+                then_branch,
+                else_branch: Some(else_branch),
+                ..
+            } => Self::always_returns(then_branch) && Self::always_returns(else_branch),
+            _ => false,
+        })
+    }
+}
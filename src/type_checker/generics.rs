@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::ast::{Expr, Expression},
+    errors::HydorError,
+    type_checker::type_checker::{Type, TypeChecker},
+    utils::Span,
+};
+
+/// Type arguments inferred so far, each with the span of the value it was
+/// first inferred from. Those taken from the expected type have no span
+type Inferred = HashMap<String, (Type, Option<Span>)>;
+
+/// Each value's expected type paired with the type it was found to have
+type CheckedValues = Vec<(Type, Type)>;
+
+impl Type {
+    /// The type of a function declared with `type_params`
+    pub fn generic(type_params: &[String], function: Type) -> Type {
+        if type_params.is_empty() {
+            return function;
+        }
+
+        Type::GenericFunction {
+            type_params: type_params.to_vec(),
+            function: Box::new(function),
+        }
+    }
+
+    /// Replace the type parameters in `bindings` with the types bound to them
+    pub fn substitute(&self, bindings: &HashMap<String, Type>) -> Type {
+        let substitute_all = |types: &[Type]| -> Vec<Type> {
+            types.iter().map(|t| t.substitute(bindings)).collect()
+        };
+
+        match self {
+            Type::TypeParameter(name) => {
+                bindings.get(name).cloned().unwrap_or_else(|| self.clone())
+            }
+            Type::Array(element_type) => Type::Array(Box::new(element_type.substitute(bindings))),
+            Type::Map { key, value } => Type::Map {
+                key: Box::new(key.substitute(bindings)),
+                value: Box::new(value.substitute(bindings)),
+            },
+            Type::Optional(inner) => Type::optional(inner.substitute(bindings)),
+            Type::Tuple(elements) => Type::Tuple(substitute_all(elements)),
+            Type::Struct(name, type_args) => Type::Struct(name.clone(), substitute_all(type_args)),
+            Type::Enum(name, type_args) => Type::Enum(name.clone(), substitute_all(type_args)),
+            Type::Function {
+                params,
+                return_type,
+            } => Type::Function {
+                params: substitute_all(params),
+                return_type: Box::new(return_type.substitute(bindings)),
+            },
+            // Its own type parameters shadow any outer ones with the same name
+            Type::GenericFunction {
+                type_params,
+                function,
+            } => {
+                let mut inner = bindings.clone();
+                inner.retain(|name, _| !type_params.contains(name));
+
+                Type::GenericFunction {
+                    type_params: type_params.clone(),
+                    function: Box::new(function.substitute(&inner)),
+                }
+            }
+            Type::Integer
+            | Type::Int64
+            | Type::Byte
+            | Type::Float
+            | Type::Bool
+            | Type::String
+            | Type::Nil => self.clone(),
+        }
+    }
+
+    /// A member type declared on a generic struct or enum, as seen
+    /// through a value whose type arguments are `type_args`
+    pub fn instantiate(&self, type_params: &[String], type_args: &[Type]) -> Type {
+        if type_params.is_empty() {
+            return self.clone();
+        }
+
+        let bindings = type_params
+            .iter()
+            .cloned()
+            .zip(type_args.iter().cloned())
+            .collect();
+        self.substitute(&bindings)
+    }
+
+    /// Whether any of `type_params` appears in this type
+    fn mentions(&self, type_params: &[String]) -> bool {
+        let any = |types: &[Type]| types.iter().any(|t| t.mentions(type_params));
+
+        match self {
+            Type::TypeParameter(name) => type_params.contains(name),
+            Type::Array(inner) | Type::Optional(inner) => inner.mentions(type_params),
+            Type::Map { key, value } => key.mentions(type_params) || value.mentions(type_params),
+            Type::Tuple(types) | Type::Struct(_, types) | Type::Enum(_, types) => any(types),
+            Type::Function {
+                params,
+                return_type,
+            } => any(params) || return_type.mentions(type_params),
+            Type::GenericFunction { function, .. } => function.mentions(type_params),
+            Type::Integer
+            | Type::Int64
+            | Type::Byte
+            | Type::Float
+            | Type::Bool
+            | Type::String
+            | Type::Nil => false,
+        }
+    }
+
+    /// Pairs of a type parameter in `declared` and the part of `found` in its
+    /// place. Parts that do not line up are left for the caller's type check
+    fn match_parameters(&self, found: &Type, pairs: &mut Vec<(String, Type)>) {
+        match (self, found) {
+            (Type::TypeParameter(name), found) => pairs.push((name.clone(), found.clone())),
+            (Type::Array(declared), Type::Array(found))
+            | (Type::Optional(declared), Type::Optional(found)) => {
+                declared.match_parameters(found, pairs)
+            }
+            // Nil says nothing about the inner type
+            (Type::Optional(_), Type::Nil) => {}
+            (Type::Optional(declared), found) => declared.match_parameters(found, pairs),
+            (
+                Type::Map { key, value },
+                Type::Map {
+                    key: found_key,
+                    value: found_value,
+                },
+            ) => {
+                key.match_parameters(found_key, pairs);
+                value.match_parameters(found_value, pairs);
+            }
+            (Type::Tuple(declared), Type::Tuple(found)) if declared.len() == found.len() => {
+                for (declared, found) in declared.iter().zip(found) {
+                    declared.match_parameters(found, pairs);
+                }
+            }
+            (Type::Struct(name, declared), Type::Struct(found_name, found))
+            | (Type::Enum(name, declared), Type::Enum(found_name, found))
+                if name == found_name && declared.len() == found.len() =>
+            {
+                for (declared, found) in declared.iter().zip(found) {
+                    declared.match_parameters(found, pairs);
+                }
+            }
+            (
+                Type::Function {
+                    params,
+                    return_type,
+                },
+                Type::Function {
+                    params: found_params,
+                    return_type: found_return,
+                },
+            ) if params.len() == found_params.len() => {
+                for (declared, found) in params.iter().zip(found_params) {
+                    declared.match_parameters(found, pairs);
+                }
+                return_type.match_parameters(found_return, pairs);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl TypeChecker {
+    /// Run `check` with the type parameters of a generic declaration in scope
+    pub(crate) fn with_type_parameters<R>(
+        &mut self,
+        type_params: &[String],
+        check: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let enclosing = self.type_parameters.len();
+        self.type_parameters.extend_from_slice(type_params);
+        let result = check(self);
+        self.type_parameters.truncate(enclosing);
+
+        result
+    }
+
+    /// Check each value against the type it is declared with, inferring the
+    /// type parameters of a generic declaration along the way. `expected` pairs
+    /// the declared result with the type the context wants, if it wants one.
+    /// Returns the inferred type arguments and, for every value, the type it
+    /// should have and the type it was found to have, for the caller to compare
+    pub(crate) fn infer_type_arguments(
+        &mut self,
+        type_params: &[String],
+        values: &[(Type, &Expression)],
+        expected: Option<(&Type, &Type)>,
+        span: Span,
+    ) -> Result<(HashMap<String, Type>, CheckedValues), ()> {
+        let mut inferred = Inferred::new();
+
+        if let Some((declared, expected)) = expected {
+            let mut pairs = Vec::new();
+            declared.match_parameters(expected, &mut pairs);
+
+            for (name, expected) in pairs {
+                if type_params.contains(&name) {
+                    inferred.entry(name).or_insert((expected, None));
+                }
+            }
+        }
+
+        let mut found_types = vec![None; values.len()];
+        let mut result = Ok(());
+
+        // Integer literals go after the other values, so that in `pair(7, x)`
+        // the 7 takes the width of x rather than fixing the type parameter to
+        // Int. Values like `[]` that cannot type themselves go last, so that in
+        // `take([], 5)` they are checked once the 5 has settled the parameter
+        let (from_context, rest): (Vec<usize>, Vec<usize>) =
+            (0..values.len()).partition(|&i| Self::takes_type_from_context(values[i].1));
+        let (literals, others): (Vec<usize>, Vec<usize>) = rest
+            .into_iter()
+            .partition(|&i| Self::is_integer_literal(values[i].1));
+
+        for i in others.into_iter().chain(literals).chain(from_context) {
+            let (declared, value) = &values[i];
+            let expected = declared.substitute(&Self::bindings(&inferred));
+
+            // An expected type that still has holes would type `[]` as `[T]`
+            let found = if expected.mentions(type_params) {
+                self.check_expression(value)
+            } else {
+                self.check_expression_as(value, &expected)
+            };
+
+            match found {
+                Ok(found) => {
+                    let inference =
+                        self.infer_from(type_params, declared, &found, value.span, &mut inferred);
+                    result = result.and(inference);
+                    found_types[i] = Some(found);
+                }
+                Err(()) => result = Err(()),
+            }
+        }
+        result?;
+
+        for name in type_params {
+            if !inferred.contains_key(name) {
+                self.throw_error(HydorError::CannotInferTypeArgument {
+                    parameter: name.clone(),
+                    span,
+                });
+                return Err(());
+            }
+        }
+
+        let bindings = Self::bindings(&inferred);
+        let checked = values
+            .iter()
+            .zip(found_types)
+            .map(|((declared, _), found)| {
+                let found = found.expect("Every value was checked");
+                (declared.substitute(&bindings), found)
+            })
+            .collect();
+
+        Ok((bindings, checked))
+    }
+
+    /// Whether `expr` only gets a type from where it is used, like `[]`, `{}`,
+    /// `nil` or a variant without a payload such as `Opt::None`
+    fn takes_type_from_context(expr: &Expression) -> bool {
+        match &expr.node {
+            Expr::NilLiteral => true,
+            Expr::ArrayLiteral { elements } => elements.is_empty(),
+            Expr::MapLiteral { entries } => entries.is_empty(),
+            Expr::EnumVariant { arguments, .. } => arguments.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Record what a value of type `found` given for `declared` says about the type
+    /// parameters. Two values may widen a parameter to a common type, like `Int`
+    /// and `nil` to `Int?`, but otherwise have to agree
+    fn infer_from(
+        &mut self,
+        type_params: &[String],
+        declared: &Type,
+        found: &Type,
+        span: Span,
+        inferred: &mut Inferred,
+    ) -> Result<(), ()> {
+        let mut pairs = Vec::new();
+        declared.match_parameters(found, &mut pairs);
+
+        for (name, found) in pairs {
+            if !type_params.contains(&name) {
+                continue;
+            }
+
+            let (previous, first_span) = match inferred.get(&name) {
+                Some(previous) => previous.clone(),
+                None => {
+                    inferred.insert(name, (found, Some(span)));
+                    continue;
+                }
+            };
+
+            match (previous.common_type(&found), first_span) {
+                (Some(common), _) => {
+                    inferred.insert(name, (common, first_span));
+                }
+                // The caller reports values that do not fit the expected type
+                (None, None) => {}
+                (None, Some(first_span)) => {
+                    self.throw_error(HydorError::TypeArgumentConflict {
+                        parameter: name,
+                        first: previous,
+                        first_span,
+                        second: found,
+                        span,
+                    });
+                    return Err(());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn bindings(inferred: &Inferred) -> HashMap<String, Type> {
+        inferred
+            .iter()
+            .map(|(name, (inferred_type, _))| (name.clone(), inferred_type.clone()))
+            .collect()
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    errors::HydorError,
+    type_checker::type_checker::{Type, TypeChecker},
+    utils::Span,
+};
+
+impl TypeChecker {
+    /// A literal takes the integer width its context expects, otherwise it is
+    /// an `Int`, or an `Int64` when it is too large for one. The compiler
+    /// reads the width back from `expr_types`
+    pub(crate) fn check_integer_literal(
+        &mut self,
+        value: i64,
+        expected: Option<&Type>,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let literal_type = match expected {
+            Some(expected) if expected.is_integer() => expected.clone(),
+            _ if i32::try_from(value).is_ok() => Type::Integer,
+            _ => Type::Int64,
+        };
+
+        let (min, max) = literal_type.integer_range().unwrap();
+        if value < min || value > max {
+            self.throw_error(HydorError::IntegerLiteralOutOfRange {
+                value,
+                expected: literal_type,
+                span,
+            });
+            return Err(());
+        }
+
+        self.expr_types.insert(span, literal_type.clone());
+        Ok(literal_type)
+    }
+}
@@ -1,4 +1,16 @@
 pub mod binary_expr;
+pub mod casts;
+pub mod collections;
+pub mod enums;
+pub mod exhaustiveness;
+pub mod functions;
+pub mod generics;
+pub mod integers;
+pub mod narrowing;
+pub mod optionals;
+pub mod patterns;
+pub mod structs;
 pub mod symbol_type_table;
+pub mod tuples;
 pub mod type_checker;
 pub mod unary;
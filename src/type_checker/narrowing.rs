@@ -0,0 +1,369 @@
+use std::collections::HashSet;
+
+use crate::{
+    ast::ast::{ChainAccess, Expr, Expression, Parameter, Statement, Stmt},
+    tokens::{Token, TokenType},
+    type_checker::type_checker::{Type, TypeChecker},
+};
+
+/// Variables known not to be nil, with the type they are narrowed to
+pub(crate) type Narrowings = Vec<(String, Type)>;
+
+/// Flow sensitive narrowing of optional variables. After `x != nil`
+/// has been checked, `x` is treated as its inner type until it is
+/// assigned something that could be nil
+impl TypeChecker {
+    /// What `condition` proves about nil when it is true, and when it is false
+    pub(crate) fn nil_checks(&self, condition: &Expression) -> (Narrowings, Narrowings) {
+        match &condition.node {
+            Expr::BinaryOperation {
+                left,
+                operator,
+                right,
+            } => match operator.get_token_type() {
+                TokenType::Equal | TokenType::NotEqual => {
+                    let checked = match (&left.node, &right.node) {
+                        (Expr::Identifier(name), Expr::NilLiteral)
+                        | (Expr::NilLiteral, Expr::Identifier(name)) => self.non_nil_type(name),
+                        _ => None,
+                    };
+                    let narrowings = checked.into_iter().collect();
+
+                    if *operator == Token::Equal {
+                        (Vec::new(), narrowings)
+                    } else {
+                        (narrowings, Vec::new())
+                    }
+                }
+                TokenType::And => {
+                    let (mut when_true, _) = self.nil_checks(left);
+                    when_true.extend(self.nil_checks(right).0);
+                    (when_true, Vec::new())
+                }
+                TokenType::Or => {
+                    let (_, mut when_false) = self.nil_checks(left);
+                    when_false.extend(self.nil_checks(right).1);
+                    (Vec::new(), when_false)
+                }
+                _ => (Vec::new(), Vec::new()),
+            },
+
+            Expr::Unary { operator, right } if operator.get_token_type() == TokenType::Not => {
+                let (when_true, when_false) = self.nil_checks(right);
+                (when_false, when_true)
+            }
+
+            _ => (Vec::new(), Vec::new()),
+        }
+    }
+
+    /// `name` and its inner type, if it is an optional that can be narrowed
+    fn non_nil_type(&self, name: &str) -> Option<(String, Type)> {
+        if self.unstable_names.contains(name) {
+            return None;
+        }
+
+        let current = match self.symbol_type_table.narrowed_type(name) {
+            Some(narrowed) => narrowed,
+            None => self.symbol_type_table.lookup(name)?.symbol_type.clone(),
+        };
+
+        match current {
+            Type::Optional(inner) => Some((name.to_string(), *inner)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn narrow_all(&mut self, narrowings: Narrowings) {
+        for (name, narrowed) in narrowings {
+            self.symbol_type_table.narrow(name, narrowed);
+        }
+    }
+
+    pub(crate) fn check_block_narrowed(
+        &mut self,
+        statements: &[Statement],
+        narrowings: &[(String, Type)],
+    ) {
+        self.symbol_type_table.begin_scope();
+        self.narrow_all(narrowings.to_vec());
+
+        for stmt in statements {
+            let _ = self.check_statement(stmt);
+        }
+
+        self.symbol_type_table.end_scope();
+    }
+
+    pub(crate) fn check_expression_narrowed(
+        &mut self,
+        expr: &Expression,
+        narrowings: &[(String, Type)],
+        expected: Option<&Type>,
+    ) -> Result<Type, ()> {
+        self.symbol_type_table.begin_scope();
+        self.narrow_all(narrowings.to_vec());
+        let result = match expected {
+            Some(expected) => self.check_expression_as(expr, expected),
+            None => self.check_expression(expr),
+        };
+        self.symbol_type_table.end_scope();
+
+        result
+    }
+
+    /// Assigning a value that cannot be nil keeps the variable narrowed
+    pub(crate) fn narrow_after_assignment(&mut self, name: &str, declared: &Type, value: Type) {
+        self.symbol_type_table.forget_narrowing(name);
+
+        if let Type::Optional(inner) = declared
+            && **inner == value
+            && !self.unstable_names.contains(name)
+        {
+            self.symbol_type_table.narrow(name.to_string(), value);
+        }
+    }
+
+    /// A loop body can undo a narrowing for the iterations after it
+    pub(crate) fn forget_assigned(&mut self, body: &[Statement]) {
+        for name in assigned_names(body) {
+            self.symbol_type_table.forget_narrowing(&name);
+        }
+    }
+
+    /// Whether control never reaches the end of `statements`
+    pub(crate) fn always_exits(statements: &[Statement]) -> bool {
+        statements.iter().any(|stmt| match &stmt.node {
+            Stmt::Return { .. } | Stmt::Break { .. } | Stmt::Continue { .. } => true,
+            Stmt::Block { body } => Self::always_exits(body),
+            Stmt::If {
+                then_branch,
+                else_branch: Some(else_branch),
+                ..
+            } => Self::always_exits(then_branch) && Self::always_exits(else_branch),
+            _ => false,
+        })
+    }
+
+    /// Names a function assigns without declaring them itself
+    pub(crate) fn assigned_from_functions(statements: &[Statement]) -> HashSet<String> {
+        let mut unstable = HashSet::new();
+
+        walk_statements(statements, &mut |node| {
+            let (parameters, body) = match node {
+                Node::Statement(Stmt::FunctionDeclaration {
+                    parameters, body, ..
+                })
+                | Node::Expression(Expr::Function {
+                    parameters, body, ..
+                }) => (parameters, body),
+                _ => return,
+            };
+
+            let declared = declared_names(parameters, body);
+            unstable.extend(
+                assigned_names(body)
+                    .into_iter()
+                    .filter(|name| !declared.contains(name)),
+            );
+        });
+
+        unstable
+    }
+}
+
+enum Node<'a> {
+    Statement(&'a Stmt),
+    Expression(&'a Expr),
+}
+
+/// Variables assigned anywhere in `statements`. Compound assignments keep
+/// the variable's type, so they cannot undo a narrowing and are left out
+fn assigned_names(statements: &[Statement]) -> HashSet<String> {
+    let mut assigned = HashSet::new();
+
+    walk_statements(statements, &mut |node| {
+        if let Node::Expression(Expr::Assign { target, .. }) = node
+            && let Expr::Identifier(name) = &target.node
+        {
+            assigned.insert(name.clone());
+        }
+    });
+
+    assigned
+}
+
+/// Parameters and variables declared anywhere in a function body
+fn declared_names(parameters: &[Parameter], body: &[Statement]) -> HashSet<String> {
+    let mut declared: HashSet<String> = parameters.iter().map(|p| p.name.clone()).collect();
+
+    walk_statements(body, &mut |node| {
+        let identifiers = match node {
+            Node::Statement(Stmt::VariableDeclaration { identifier, .. })
+            | Node::Statement(Stmt::For {
+                variable: identifier,
+                ..
+            })
+            | Node::Statement(Stmt::FunctionDeclaration { identifier, .. }) => {
+                std::slice::from_ref(identifier)
+            }
+            Node::Statement(Stmt::TupleDestructuring { identifiers, .. }) => identifiers,
+            _ => return,
+        };
+
+        for identifier in identifiers {
+            if let Expr::Identifier(name) = &identifier.node {
+                declared.insert(name.clone());
+            }
+        }
+    });
+
+    declared
+}
+
+/// Visit every statement and expression, including those in nested function bodies
+fn walk_statements<'a>(statements: &'a [Statement], visit: &mut impl FnMut(Node<'a>)) {
+    for stmt in statements {
+        visit(Node::Statement(&stmt.node));
+
+        match &stmt.node {
+            Stmt::Expression { expression: value }
+            | Stmt::VariableDeclaration { value, .. }
+            | Stmt::TupleDestructuring { value, .. }
+            | Stmt::Return { value: Some(value) } => walk_expression(value, visit),
+            Stmt::Block { body } | Stmt::FunctionDeclaration { body, .. } => {
+                walk_statements(body, visit)
+            }
+            Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                walk_expression(condition, visit);
+                walk_statements(then_branch, visit);
+                if let Some(else_branch) = else_branch {
+                    walk_statements(else_branch, visit);
+                }
+            }
+            Stmt::While {
+                condition, body, ..
+            } => {
+                walk_expression(condition, visit);
+                walk_statements(body, visit);
+            }
+            Stmt::For {
+                start,
+                end,
+                step,
+                body,
+                ..
+            } => {
+                walk_expression(start, visit);
+                walk_expression(end, visit);
+                if let Some(step) = step {
+                    walk_expression(step, visit);
+                }
+                walk_statements(body, visit);
+            }
+            Stmt::Return { value: None }
+            | Stmt::Break { .. }
+            | Stmt::Continue { .. }
+            | Stmt::StructDeclaration { .. }
+            | Stmt::EnumDeclaration { .. } => {}
+        }
+    }
+}
+
+fn walk_expression<'a>(expr: &'a Expression, visit: &mut impl FnMut(Node<'a>)) {
+    visit(Node::Expression(&expr.node));
+
+    match &expr.node {
+        Expr::IntegerLiteral(_)
+        | Expr::FloatLiteral(_)
+        | Expr::BooleanLiteral(_)
+        | Expr::StringLiteral(_)
+        | Expr::Identifier(_)
+        | Expr::NilLiteral => {}
+
+        Expr::Unary { right: inner, .. }
+        | Expr::Cast { value: inner, .. }
+        | Expr::TupleAccess { object: inner, .. }
+        | Expr::FieldAccess { object: inner, .. } => walk_expression(inner, visit),
+
+        Expr::BinaryOperation { left, right, .. }
+        | Expr::Assign {
+            target: left,
+            value: right,
+        }
+        | Expr::CompoundAssign {
+            target: left,
+            value: right,
+            ..
+        }
+        | Expr::Index {
+            object: left,
+            index: right,
+        } => {
+            walk_expression(left, visit);
+            walk_expression(right, visit);
+        }
+
+        Expr::Conditional {
+            then_expr,
+            condition,
+            else_expr,
+        } => {
+            walk_expression(then_expr, visit);
+            walk_expression(condition, visit);
+            walk_expression(else_expr, visit);
+        }
+
+        Expr::Call {
+            callee: object,
+            arguments,
+        }
+        | Expr::MethodCall {
+            object, arguments, ..
+        } => {
+            walk_expression(object, visit);
+            arguments.iter().for_each(|a| walk_expression(a, visit));
+        }
+
+        Expr::OptionalChain { object, access } => {
+            walk_expression(object, visit);
+            if let ChainAccess::Method { arguments, .. } = access {
+                arguments.iter().for_each(|a| walk_expression(a, visit));
+            }
+        }
+
+        Expr::Function { body, .. } => walk_statements(body, visit),
+
+        Expr::ArrayLiteral { elements }
+        | Expr::TupleLiteral { elements }
+        | Expr::EnumVariant {
+            arguments: elements,
+            ..
+        } => elements.iter().for_each(|e| walk_expression(e, visit)),
+
+        Expr::MapLiteral { entries } => {
+            for (key, value) in entries {
+                walk_expression(key, visit);
+                walk_expression(value, visit);
+            }
+        }
+
+        Expr::StructLiteral { fields, .. } => {
+            fields.iter().for_each(|f| walk_expression(&f.value, visit));
+        }
+
+        Expr::Match { subject, arms } => {
+            walk_expression(subject, visit);
+            for arm in arms {
+                if let Some(guard) = &arm.guard {
+                    walk_expression(guard, visit);
+                }
+                walk_expression(&arm.body, visit);
+            }
+        }
+    }
+}
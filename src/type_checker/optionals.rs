@@ -0,0 +1,119 @@
+use crate::{
+    ast::ast::{ChainAccess, Expression},
+    errors::HydorError,
+    tokens::TokenType,
+    type_checker::type_checker::{Type, TypeChecker},
+    utils::Span,
+};
+
+impl Type {
+    /// `inner?`. Nil and optional types can already be nil, so they stay as they are
+    pub fn optional(inner: Type) -> Type {
+        match inner {
+            Type::Optional(_) | Type::Nil => inner,
+            other => Type::Optional(Box::new(other)),
+        }
+    }
+
+    /// Whether a value of type `found` can be used where `self` is expected
+    pub fn accepts(&self, found: &Type) -> bool {
+        match (self, found) {
+            _ if self == found => true,
+            (Type::Optional(_), Type::Nil) => true,
+            (Type::Optional(inner), found) => **inner == *found,
+            _ => false,
+        }
+    }
+
+    /// The type of a value that comes from either of two branches
+    pub fn common_type(&self, other: &Type) -> Option<Type> {
+        if self.accepts(other) {
+            return Some(self.clone());
+        }
+        if other.accepts(self) {
+            return Some(other.clone());
+        }
+
+        match (self, other) {
+            (Type::Nil, other) | (other, Type::Nil) => Some(Type::optional(other.clone())),
+            _ => None,
+        }
+    }
+}
+
+impl TypeChecker {
+    /// `value ?? default` is only nil if the default can be
+    pub(crate) fn check_nil_coalescing(
+        &mut self,
+        left: &Expression,
+        right: &Expression,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let left_type = self.check_expression(left)?;
+
+        let inner = match &left_type {
+            Type::Optional(inner) => *inner.clone(),
+            _ => {
+                self.throw_error(HydorError::NotOptional {
+                    operator: TokenType::QuestionQuestion,
+                    found: left_type,
+                    span: left.span,
+                });
+                return Err(());
+            }
+        };
+
+        let right_type = self.check_expression_as(right, &inner)?;
+
+        let result_type = if inner == right_type {
+            inner
+        } else if left_type.accepts(&right_type) {
+            left_type
+        } else {
+            self.throw_error(HydorError::InvalidBinaryOp {
+                operator: TokenType::QuestionQuestion,
+                left_type,
+                right_type,
+                span,
+            });
+            return Err(());
+        };
+
+        self.expr_types.insert(span, result_type.clone());
+        Ok(result_type)
+    }
+
+    /// The member is looked up on the inner type, and the result is optional
+    pub(crate) fn check_optional_chain(
+        &mut self,
+        object: &Expression,
+        access: &ChainAccess,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let object_type = self.check_expression(object)?;
+
+        let inner = match object_type {
+            Type::Optional(inner) => *inner,
+            found => {
+                self.throw_error(HydorError::NotOptional {
+                    operator: TokenType::QuestionDot,
+                    found,
+                    span: object.span,
+                });
+                return Err(());
+            }
+        };
+
+        let member_type = match access {
+            ChainAccess::Field(field) => self.field_type(inner, field, span)?,
+            ChainAccess::Element(index) => self.element_type(inner, *index, span)?,
+            ChainAccess::Method { name, arguments } => {
+                self.method_call_type(inner, name, arguments, span)?
+            }
+        };
+
+        let chain_type = Type::optional(member_type);
+        self.expr_types.insert(span, chain_type.clone());
+        Ok(chain_type)
+    }
+}
@@ -0,0 +1,248 @@
+use std::collections::HashSet;
+
+use crate::{
+    ast::ast::{Expression, FieldPattern, MatchArm, Pat, Pattern},
+    errors::HydorError,
+    type_checker::type_checker::{Type, TypeChecker},
+    utils::Span,
+};
+
+impl TypeChecker {
+    /// Every arm must produce the same type, which becomes the type of the match.
+    /// Arms that produce nil make it optional. Arm bodies are checked against
+    /// `expected`, if the context expects a type
+    pub(crate) fn check_match(
+        &mut self,
+        subject: &Expression,
+        arms: &[MatchArm],
+        expected: Option<&Type>,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let subject_type = self.check_expression(subject)?;
+
+        let mut match_type: Option<Type> = None;
+        let mut result = Ok(());
+
+        for arm in arms {
+            // Bindings are only visible to the guard and the body of their own arm
+            self.symbol_type_table.begin_scope();
+            let arm_type = self.check_arm(arm, &subject_type, expected);
+            self.symbol_type_table.end_scope();
+
+            match (arm_type, &match_type) {
+                (Ok(arm_type), None) => match_type = Some(arm_type),
+                (Ok(arm_type), Some(first_type)) => match first_type.common_type(&arm_type) {
+                    Some(common) => match_type = Some(common),
+                    None => {
+                        self.throw_error(HydorError::BranchTypeMismatch {
+                            first_type: first_type.clone(),
+                            second_type: arm_type,
+                            span: arm.body.span,
+                        });
+                        result = Err(());
+                    }
+                },
+                (Err(()), _) => result = Err(()),
+            }
+        }
+
+        result?;
+        self.check_exhaustiveness(&subject_type, arms, span)?;
+
+        let match_type = match_type.unwrap_or(Type::Nil);
+        self.expr_types.insert(span, match_type.clone());
+        Ok(match_type)
+    }
+
+    fn check_arm(
+        &mut self,
+        arm: &MatchArm,
+        subject_type: &Type,
+        expected: Option<&Type>,
+    ) -> Result<Type, ()> {
+        self.check_pattern(&arm.pattern, subject_type)?;
+
+        if let Some(guard) = &arm.guard {
+            self.check_condition(guard)?;
+        }
+
+        match expected {
+            Some(expected) => self.check_expression_as(&arm.body, expected),
+            None => self.check_expression(&arm.body),
+        }
+    }
+
+    /// Declare the names bound by `pattern` in the current scope
+    fn check_pattern(&mut self, pattern: &Pattern, expected: &Type) -> Result<(), ()> {
+        let span = pattern.span;
+
+        match &pattern.node {
+            Pat::Wildcard => Ok(()),
+
+            // Bindings behave like 'let' bindings inside the arm
+            Pat::Binding(name) => self.symbol_type_table.declare_identifier(
+                name.clone(),
+                expected.clone(),
+                span,
+                true,
+                &mut self.errors,
+            ),
+
+            Pat::Literal(literal) => {
+                let found = self.check_expression_as(&literal.clone().spanned(span), expected)?;
+                self.expect_pattern_type(found, expected, span)
+            }
+
+            Pat::Range { start, end, .. } => {
+                if !expected.is_integer() && *expected != Type::Float {
+                    self.throw_error(HydorError::TypeMismatch {
+                        expected: vec![Type::Integer, Type::Int64, Type::Byte, Type::Float],
+                        found: expected.clone(),
+                        span,
+                    });
+                    return Err(());
+                }
+
+                let start_type =
+                    self.check_expression_as(&start.clone().spanned(span), expected)?;
+                let end_type = self.check_expression_as(&end.clone().spanned(span), expected)?;
+                self.expect_pattern_type(start_type, expected, span)
+                    .and(self.expect_pattern_type(end_type, expected, span))
+            }
+
+            Pat::Struct { name, fields } => {
+                if !self.structs.contains_key(name) {
+                    self.throw_error(HydorError::InvalidTypeName {
+                        got: name.clone(),
+                        span,
+                    });
+                    return Err(());
+                }
+
+                // A pattern names no type arguments, they come from the subject
+                let struct_type = match expected {
+                    Type::Struct(expected_name, _) if expected_name == name => expected.clone(),
+                    _ => self.structs[name].declared_type(name),
+                };
+                self.expect_pattern_type(struct_type.clone(), expected, span)?;
+                self.check_field_patterns(&struct_type, name, fields)
+            }
+
+            Pat::Variant {
+                enum_name,
+                variant,
+                payload,
+            } => {
+                let definition = match self.enums.get(enum_name) {
+                    Some(definition) => definition.clone(),
+                    None => {
+                        self.throw_error(HydorError::InvalidTypeName {
+                            got: enum_name.clone(),
+                            span,
+                        });
+                        return Err(());
+                    }
+                };
+
+                let enum_type = match expected {
+                    Type::Enum(expected_name, _) if expected_name == enum_name => expected.clone(),
+                    _ => definition.declared_type(enum_name),
+                };
+                let type_args = match &enum_type {
+                    Type::Enum(_, type_args) => type_args.clone(),
+                    _ => unreachable!("Built as an enum type above"),
+                };
+
+                let payload_types = match definition.variant(variant) {
+                    Some((_, payload_types)) => payload_types
+                        .iter()
+                        .map(|t| t.instantiate(&definition.type_params, &type_args))
+                        .collect::<Vec<Type>>(),
+                    None => {
+                        self.throw_error(HydorError::UnknownVariant {
+                            name: enum_name.clone(),
+                            variant: variant.clone(),
+                            span,
+                        });
+                        return Err(());
+                    }
+                };
+
+                self.expect_pattern_type(enum_type, expected, span)?;
+
+                if payload.len() != payload_types.len() {
+                    self.throw_error(HydorError::PayloadCountMismatch {
+                        variant: format!("{}::{}", enum_name, variant),
+                        expected: payload_types.len(),
+                        got: payload.len(),
+                        span,
+                    });
+                    return Err(());
+                }
+
+                let mut result = Ok(());
+                for (pattern, payload_type) in payload.iter().zip(&payload_types) {
+                    result = result.and(self.check_pattern(pattern, payload_type));
+                }
+                result
+            }
+        }
+    }
+
+    fn check_field_patterns(
+        &mut self,
+        struct_type: &Type,
+        name: &str,
+        fields: &[FieldPattern],
+    ) -> Result<(), ()> {
+        let definition = self.structs[name].clone();
+        let type_args = match struct_type {
+            Type::Struct(_, type_args) => type_args.as_slice(),
+            _ => &[],
+        };
+
+        let mut result = Ok(());
+        let mut seen = HashSet::new();
+
+        for field in fields {
+            if !seen.insert(&field.name) {
+                self.throw_error(HydorError::DuplicateField {
+                    field: field.name.clone(),
+                    span: field.span,
+                });
+                result = Err(());
+                continue;
+            }
+
+            match definition.field(&field.name) {
+                Some((_, field_type)) => {
+                    let field_type = field_type.instantiate(&definition.type_params, type_args);
+                    result = result.and(self.check_pattern(&field.pattern, &field_type));
+                }
+                None => {
+                    self.throw_error(HydorError::UnknownField {
+                        found: struct_type.clone(),
+                        field: field.name.clone(),
+                        span: field.span,
+                    });
+                    result = Err(());
+                }
+            }
+        }
+
+        result
+    }
+
+    fn expect_pattern_type(&mut self, found: Type, expected: &Type, span: Span) -> Result<(), ()> {
+        if !expected.accepts(&found) {
+            self.throw_error(HydorError::TypeMismatch {
+                expected: vec![expected.clone()],
+                found,
+                span,
+            });
+            return Err(());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,305 @@
+use std::collections::HashSet;
+
+use crate::{
+    ast::ast::{Expr, Expression, Field, FieldInit},
+    errors::HydorError,
+    type_checker::type_checker::{Type, TypeChecker},
+    utils::Span,
+};
+
+#[derive(Debug, Clone)]
+pub struct StructDefinition {
+    pub type_params: Vec<String>,
+    /// Fields in declaration order, which is also their runtime layout
+    pub fields: Vec<(String, Type)>,
+    pub span: Span,
+}
+
+impl StructDefinition {
+    /// Layout index and type of `name`
+    pub fn field(&self, name: &str) -> Option<(usize, &Type)> {
+        self.fields
+            .iter()
+            .enumerate()
+            .find(|(_, (field, _))| field == name)
+            .map(|(index, (_, field_type))| (index, field_type))
+    }
+
+    /// Type of a value of this struct before its type arguments are known
+    pub fn declared_type(&self, name: &str) -> Type {
+        let type_args = self.type_params.iter().cloned().map(Type::TypeParameter);
+        Type::Struct(name.to_string(), type_args.collect())
+    }
+}
+
+impl TypeChecker {
+    pub(crate) fn check_struct_declaration(
+        &mut self,
+        identifier: &Expression,
+        type_params: &[String],
+        fields: &[Field],
+    ) -> Result<(), ()> {
+        self.declare_struct(identifier, type_params)?;
+        self.define_struct(identifier, type_params, fields)
+    }
+
+    /// Register the name of a struct without its fields, so field types
+    /// declared before or after it can refer to it
+    pub(crate) fn declare_struct(
+        &mut self,
+        identifier: &Expression,
+        type_params: &[String],
+    ) -> Result<(), ()> {
+        let name = match &identifier.node {
+            Expr::Identifier(name) => name.clone(),
+            _ => unreachable!("Struct names are always identifiers"),
+        };
+
+        if let Some(original_span) = self.declared_type_span(&name) {
+            self.throw_error(HydorError::TypeRedeclaration {
+                name,
+                original_span,
+                redeclaration_span: identifier.span,
+            });
+            return Err(());
+        }
+
+        self.structs.insert(
+            name,
+            StructDefinition {
+                type_params: type_params.to_vec(),
+                fields: Vec::new(),
+                span: identifier.span,
+            },
+        );
+        Ok(())
+    }
+
+    /// Resolve and check the fields of a struct that was already declared
+    pub(crate) fn define_struct(
+        &mut self,
+        identifier: &Expression,
+        type_params: &[String],
+        fields: &[Field],
+    ) -> Result<(), ()> {
+        let name = match &identifier.node {
+            Expr::Identifier(name) => name.clone(),
+            _ => unreachable!("Struct names are always identifiers"),
+        };
+
+        let mut result = Ok(());
+        let mut seen = HashSet::new();
+        for field in fields {
+            if !seen.insert(&field.name) {
+                self.throw_error(HydorError::DuplicateField {
+                    field: field.name.clone(),
+                    span: field.span,
+                });
+                result = Err(());
+            }
+        }
+
+        let resolved: Vec<(String, Type)> = self.with_type_parameters(type_params, |checker| {
+            fields
+                .iter()
+                .map(|f| {
+                    (
+                        f.name.clone(),
+                        checker.resolve_annotation(&f.annotated_type),
+                    )
+                })
+                .collect()
+        });
+
+        for (field, (_, field_type)) in fields.iter().zip(&resolved) {
+            result = result.and(self.check_type_exists(field_type, field.span));
+        }
+
+        if let Some(definition) = self.structs.get_mut(&name) {
+            definition.fields = resolved;
+        }
+
+        result
+    }
+
+    /// Every field must be given exactly once. The type arguments of a generic
+    /// struct are inferred from the field values and the `expected` type
+    pub(crate) fn check_struct_literal(
+        &mut self,
+        name: &str,
+        fields: &[FieldInit],
+        expected: Option<&Type>,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let definition = match self.structs.get(name) {
+            Some(definition) => definition.clone(),
+            None => {
+                self.throw_error(HydorError::InvalidTypeName {
+                    got: name.to_string(),
+                    span,
+                });
+                return Err(());
+            }
+        };
+        let declared_type = definition.declared_type(name);
+
+        let mut result = Ok(());
+        let mut seen = HashSet::new();
+        let mut values = Vec::new();
+
+        for init in fields {
+            if !seen.insert(&init.name) {
+                self.throw_error(HydorError::DuplicateField {
+                    field: init.name.clone(),
+                    span: init.span,
+                });
+                result = Err(());
+                continue;
+            }
+
+            match definition.field(&init.name) {
+                Some((_, field_type)) => values.push((field_type.clone(), &init.value)),
+                None => {
+                    self.throw_error(HydorError::UnknownField {
+                        found: declared_type.clone(),
+                        field: init.name.clone(),
+                        span: init.span,
+                    });
+                    result = Err(());
+                }
+            }
+        }
+
+        let inferred = self.infer_type_arguments(
+            &definition.type_params,
+            &values,
+            expected.map(|expected| (&declared_type, expected)),
+            span,
+        );
+
+        let missing: Vec<String> = definition
+            .fields
+            .iter()
+            .filter(|(field, _)| !seen.contains(field))
+            .map(|(field, _)| field.clone())
+            .collect();
+
+        if !missing.is_empty() {
+            self.throw_error(HydorError::MissingFields {
+                name: name.to_string(),
+                fields: missing,
+                span,
+            });
+            return Err(());
+        }
+
+        let (type_args, checked) = inferred?;
+        for ((expected, found), (_, value)) in checked.into_iter().zip(&values) {
+            if !expected.accepts(&found) {
+                self.throw_error(HydorError::TypeMismatch {
+                    expected: vec![expected],
+                    found,
+                    span: value.span,
+                });
+                result = Err(());
+            }
+        }
+        result?;
+
+        let struct_type = declared_type.substitute(&type_args);
+        if !type_args.is_empty() {
+            self.expr_types.insert(span, struct_type.clone());
+        }
+        Ok(struct_type)
+    }
+
+    pub(crate) fn check_field_access(
+        &mut self,
+        object: &Expression,
+        field: &str,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let object_type = self.check_expression(object)?;
+        self.field_type(object_type, field, span)
+    }
+
+    pub(crate) fn field_type(
+        &mut self,
+        object_type: Type,
+        field: &str,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let field_type = match &object_type {
+            Type::Struct(name, type_args) => {
+                let definition = &self.structs[name];
+                definition.field(field).map(|(_, field_type)| {
+                    field_type.instantiate(&definition.type_params, type_args)
+                })
+            }
+            _ => None,
+        };
+
+        match field_type {
+            Some(field_type) => Ok(field_type),
+            None => {
+                self.throw_error(HydorError::UnknownField {
+                    found: object_type,
+                    field: field.to_string(),
+                    span,
+                });
+                Err(())
+            }
+        }
+    }
+
+    /// Report struct names in `declared` that were never declared
+    pub(crate) fn check_type_exists(&mut self, declared: &Type, span: Span) -> Result<(), ()> {
+        match declared {
+            Type::Struct(name, type_args) | Type::Enum(name, type_args) => {
+                let type_params = match (declared, self.structs.get(name), self.enums.get(name)) {
+                    (Type::Struct(..), Some(definition), _) => definition.type_params.len(),
+                    (Type::Enum(..), _, Some(definition)) => definition.type_params.len(),
+                    _ => {
+                        self.throw_error(HydorError::InvalidTypeName {
+                            got: name.clone(),
+                            span,
+                        });
+                        return Err(());
+                    }
+                };
+
+                if type_args.len() != type_params {
+                    self.throw_error(HydorError::TypeArgumentCountMismatch {
+                        name: name.clone(),
+                        expected: type_params,
+                        got: type_args.len(),
+                        span,
+                    });
+                    return Err(());
+                }
+
+                type_args
+                    .iter()
+                    .try_for_each(|type_arg| self.check_type_exists(type_arg, span))
+            }
+            Type::Array(element_type) | Type::Optional(element_type) => {
+                self.check_type_exists(element_type, span)
+            }
+            Type::Map { key, value } => self
+                .check_type_exists(key, span)
+                .and(self.check_type_exists(value, span)),
+            Type::Tuple(elements) => elements
+                .iter()
+                .try_for_each(|element| self.check_type_exists(element, span)),
+            Type::Function {
+                params,
+                return_type,
+            } => params
+                .iter()
+                .try_for_each(|param| self.check_type_exists(param, span))
+                .and(self.check_type_exists(return_type, span)),
+            Type::GenericFunction { function, .. } => self.check_type_exists(function, span),
+            _ => Ok(()),
+        }
+    }
+}
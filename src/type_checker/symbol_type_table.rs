@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, mem};
 
 use crate::{
     errors::{ErrorCollector, HydorError},
@@ -7,29 +7,93 @@ use crate::{
 };
 
 pub struct SymbolType {
-    symbol_type: Type,
-    span: Span,
+    pub symbol_type: Type,
+    pub span: Span,
+    pub mutable: bool,
 }
 
+/// Lexically scoped symbol types, the first scope holds the globals
 pub struct SymbolTypeTable {
-    store: HashMap<String, SymbolType>,
+    scopes: Vec<HashMap<String, SymbolType>>,
+    /// Optional variables known not to be nil, with one map per scope.
+    /// A narrowing lasts until the end of the scope it was made in
+    narrowings: Vec<HashMap<String, Type>>,
 }
 
 impl SymbolTypeTable {
     pub fn new() -> Self {
         Self {
-            store: HashMap::new(),
+            scopes: vec![HashMap::new()],
+            narrowings: vec![HashMap::new()],
         }
     }
 
+    pub fn begin_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+        self.narrowings.push(HashMap::new());
+    }
+
+    pub fn end_scope(&mut self) {
+        // The global scope is never popped
+        if self.scopes.len() > 1 {
+            self.scopes.pop();
+            self.narrowings.pop();
+        }
+    }
+
+    /// Treat `ident` as `t` until the current scope ends
+    pub fn narrow(&mut self, ident: String, t: Type) {
+        self.narrowings
+            .last_mut()
+            .expect("Global scope always exists")
+            .insert(ident, t);
+    }
+
+    /// Drop every narrowing of `ident`, in this scope and the enclosing ones
+    pub fn forget_narrowing(&mut self, ident: &str) {
+        for narrowings in &mut self.narrowings {
+            narrowings.remove(ident);
+        }
+    }
+
+    /// Hide every narrowing from a function body, which may run after the
+    /// variables it captures have been set to nil
+    pub fn suspend_narrowings(&mut self) -> Vec<HashMap<String, Type>> {
+        let hidden = vec![HashMap::new(); self.narrowings.len()];
+        mem::replace(&mut self.narrowings, hidden)
+    }
+
+    pub fn restore_narrowings(&mut self, narrowings: Vec<HashMap<String, Type>>) {
+        self.narrowings = narrowings;
+    }
+
+    /// The narrowed type of `ident`, unless it is shadowed by a declaration
+    /// made after the narrowing
+    pub fn narrowed_type(&self, ident: &str) -> Option<Type> {
+        for (scope, narrowings) in self.scopes.iter().zip(&self.narrowings).rev() {
+            if let Some(t) = narrowings.get(ident) {
+                return Some(t.clone());
+            }
+            if scope.contains_key(ident) {
+                return None;
+            }
+        }
+
+        None
+    }
+
     pub fn declare_identifier(
         &mut self,
         ident: String,
         t: Type,
         span: Span,
+        mutable: bool,
         errors: &mut ErrorCollector,
     ) -> Result<(), ()> {
-        if let Some(existing) = self.store.get(&ident) {
+        let current_scope = self.scopes.last_mut().expect("Global scope always exists");
+
+        // Names from outer scopes may be shadowed, but not redeclared in the same one
+        if let Some(existing) = current_scope.get(&ident) {
             errors.add(HydorError::VariableRedeclaration {
                 name: ident,
                 original_span: existing.span,
@@ -41,19 +105,43 @@ impl SymbolTypeTable {
         let symbol_type = SymbolType {
             symbol_type: t,
             span: span,
+            mutable,
         };
 
-        self.store.insert(ident, symbol_type);
+        // A new declaration is not affected by narrowings of an older one
+        self.narrowings
+            .last_mut()
+            .expect("Global scope always exists")
+            .remove(&ident);
+
+        current_scope.insert(ident, symbol_type);
         Ok(())
     }
 
+    /// Whether declarations are currently made in the global scope
+    pub fn in_global_scope(&self) -> bool {
+        self.scopes.len() == 1
+    }
+
+    /// Whether `ident` refers to a global, rather than a local shadowing it
+    pub fn is_global(&self, ident: &str) -> bool {
+        self.scopes
+            .iter()
+            .rposition(|scope| scope.contains_key(ident))
+            == Some(0)
+    }
+
+    pub fn lookup(&self, ident: &str) -> Option<&SymbolType> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(ident))
+    }
+
     pub fn resolve_identifier(
         &self,
         ident: &str,
         span: Span,
         errors: &mut ErrorCollector,
     ) -> Result<Type, ()> {
-        match self.store.get(ident) {
+        match self.lookup(ident) {
             Some(s) => Ok(s.symbol_type.clone()),
             None => {
                 errors.add(HydorError::UndefinedVariable {
@@ -0,0 +1,109 @@
+use crate::{
+    ast::ast::{Expr, Expression},
+    errors::HydorError,
+    type_checker::type_checker::{Type, TypeChecker},
+    utils::Span,
+};
+
+impl TypeChecker {
+    /// Elements are checked against the expected element types when the
+    /// context gives them, so `([], 1)` can still be typed by an annotation
+    pub(crate) fn check_tuple_literal(
+        &mut self,
+        elements: &[Expression],
+        expected: Option<&[Type]>,
+    ) -> Result<Type, ()> {
+        let mut element_types = Vec::with_capacity(elements.len());
+        let mut result = Ok(());
+
+        for (position, element) in elements.iter().enumerate() {
+            let checked = match expected {
+                Some(expected) => self.check_expression_as(element, &expected[position]),
+                None => self.check_expression(element),
+            };
+
+            match checked {
+                Ok(element_type) => element_types.push(element_type),
+                Err(()) => result = Err(()),
+            }
+        }
+
+        result.map(|()| Type::Tuple(element_types))
+    }
+
+    pub(crate) fn check_tuple_access(
+        &mut self,
+        object: &Expression,
+        index: usize,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let object_type = self.check_expression(object)?;
+        self.element_type(object_type, index, span)
+    }
+
+    pub(crate) fn element_type(
+        &mut self,
+        object_type: Type,
+        index: usize,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let element_type = match &object_type {
+            Type::Tuple(elements) => elements.get(index).cloned(),
+            _ => None,
+        };
+
+        match element_type {
+            Some(element_type) => Ok(element_type),
+            None => {
+                self.throw_error(HydorError::UnknownField {
+                    found: object_type,
+                    field: index.to_string(),
+                    span,
+                });
+                Err(())
+            }
+        }
+    }
+
+    /// Each name takes the type of the element in its position
+    pub(crate) fn check_tuple_destructuring(
+        &mut self,
+        identifiers: &[Expression],
+        value: &Expression,
+        mutable: bool,
+        span: Span,
+    ) -> Result<(), ()> {
+        let value_type = self.check_expression(value)?;
+
+        let element_types = match value_type {
+            Type::Tuple(elements) if elements.len() == identifiers.len() => elements,
+            found => {
+                self.throw_error(HydorError::TupleDestructureMismatch {
+                    names: identifiers.len(),
+                    found,
+                    span,
+                });
+                return Err(());
+            }
+        };
+
+        let mut result = Ok(());
+        for (identifier, element_type) in identifiers.iter().zip(element_types) {
+            let name = match &identifier.node {
+                Expr::Identifier(name) => name.clone(),
+                _ => unreachable!("Destructured names are always identifiers"),
+            };
+
+            let declared = self.symbol_type_table.declare_identifier(
+                name,
+                element_type,
+                identifier.span,
+                mutable,
+                &mut self.errors,
+            );
+            result = result.and(declared);
+        }
+
+        result
+    }
+}
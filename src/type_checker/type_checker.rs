@@ -1,63 +1,250 @@
 use crate::{
     ast::{
-        ast::{Expr, Expression, Program, Statement, Stmt},
+        ast::{Expr, Expression, Parameter, Program, Statement, Stmt},
         type_annotation::TypeAnnotation,
     },
     errors::{ErrorCollector, HydorError},
-    type_checker::symbol_type_table::SymbolTypeTable,
-    utils::throw_error,
+    tokens::{Token, TokenType},
+    type_checker::{
+        enums::EnumDefinition, structs::StructDefinition, symbol_type_table::SymbolTypeTable,
+    },
+    utils::Span,
 };
 use core::fmt;
-use std::mem;
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Type {
     Integer,
+    Int64,
+    Byte,
     Float,
     Bool,
     String,
     Nil,
+    Array(Box<Type>),
+    Map {
+        key: Box<Type>,
+        value: Box<Type>,
+    },
+    /// Structs are nominal, their fields live in `TypeChecker::structs`.
+    /// A generic struct carries its type arguments
+    Struct(String, Vec<Type>),
+    /// Enums are nominal too, their variants live in `TypeChecker::enums`
+    Enum(String, Vec<Type>),
+    /// `T` inside a generic declaration. Nothing is known about its values,
+    /// so they can only be passed around and compared
+    TypeParameter(String),
+    Tuple(Vec<Type>),
+    /// Either a value of the inner type or nil, never nested
+    Optional(Box<Type>),
+    Function {
+        params: Vec<Type>,
+        return_type: Box<Type>,
+    },
+    /// A generic function, its type parameters are inferred at every call
+    GenericFunction {
+        type_params: Vec<String>,
+        function: Box<Type>,
+    },
+}
+
+/// `A, B, C`
+fn join(types: &[Type]) -> String {
+    types
+        .iter()
+        .map(|t| t.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
 }
 
 impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Type::Integer => write!(f, "Integer"),
+            Type::Int64 => write!(f, "Int64"),
+            Type::Byte => write!(f, "Byte"),
             Type::Float => write!(f, "Float"),
             Type::Bool => write!(f, "Bool"),
             Type::String => write!(f, "String"),
             Type::Nil => write!(f, "Nil"),
+            Type::Array(element_type) => write!(f, "Array<{}>", element_type),
+            Type::Map { key, value } => write!(f, "Map<{}, {}>", key, value),
+            Type::Struct(name, type_args) | Type::Enum(name, type_args) => {
+                if type_args.is_empty() {
+                    write!(f, "{}", name)
+                } else {
+                    write!(f, "{}<{}>", name, join(type_args))
+                }
+            }
+            Type::TypeParameter(name) => write!(f, "{}", name),
+            Type::Optional(inner) => write!(f, "{}?", inner),
+            Type::Tuple(elements) => {
+                // A one element tuple keeps its comma, like its annotation
+                if elements.len() == 1 {
+                    write!(f, "({},)", join(elements))
+                } else {
+                    write!(f, "({})", join(elements))
+                }
+            }
+            Type::Function {
+                params,
+                return_type,
+            } => write!(f, "fn({}) -> {}", join(params), return_type),
+            Type::GenericFunction {
+                type_params,
+                function,
+            } => match function.as_ref() {
+                Type::Function {
+                    params,
+                    return_type,
+                } => write!(
+                    f,
+                    "fn<{}>({}) -> {}",
+                    type_params.join(", "),
+                    join(params),
+                    return_type
+                ),
+                other => write!(f, "{}", other),
+            },
         }
     }
 }
 
 impl Type {
+    /// Type of a function with the given parameters and return annotation
+    pub fn from_signature(parameters: &[Parameter], return_type: Option<&TypeAnnotation>) -> Self {
+        Self::Function {
+            params: parameters
+                .iter()
+                .map(|p| Self::from_anotated_type(&p.annotated_type))
+                .collect(),
+            return_type: Box::new(return_type.map_or(Self::Nil, Self::from_anotated_type)),
+        }
+    }
+
+    /// Whether values of this type can be used as map keys
+    pub fn is_hashable(&self) -> bool {
+        self.is_integer() || matches!(self, Type::Bool | Type::String)
+    }
+
+    /// `Int`, `Int64` or `Byte`
+    pub fn is_integer(&self) -> bool {
+        matches!(self, Type::Integer | Type::Int64 | Type::Byte)
+    }
+
+    /// The smallest and largest value of an integer type
+    pub fn integer_range(&self) -> Option<(i64, i64)> {
+        match self {
+            Type::Integer => Some((i32::MIN.into(), i32::MAX.into())),
+            Type::Int64 => Some((i64::MIN, i64::MAX)),
+            Type::Byte => Some((u8::MIN.into(), u8::MAX.into())),
+            _ => None,
+        }
+    }
+
     pub fn from_anotated_type(an_type: &TypeAnnotation) -> Self {
         match an_type {
             TypeAnnotation::StringType => Self::String,
             TypeAnnotation::IntegerType => Self::Integer,
+            TypeAnnotation::Int64Type => Self::Int64,
+            TypeAnnotation::ByteType => Self::Byte,
             TypeAnnotation::FloatType => Self::Float,
             TypeAnnotation::BooleanType => Self::Bool,
+            TypeAnnotation::ArrayType(element_type) => {
+                Self::Array(Box::new(Self::from_anotated_type(element_type)))
+            }
+            TypeAnnotation::MapType { key, value } => Self::Map {
+                key: Box::new(Self::from_anotated_type(key)),
+                value: Box::new(Self::from_anotated_type(value)),
+            },
+            TypeAnnotation::OptionalType(inner) => Self::optional(Self::from_anotated_type(inner)),
+            TypeAnnotation::TupleType(elements) => {
+                Self::Tuple(elements.iter().map(Self::from_anotated_type).collect())
+            }
+            TypeAnnotation::NamedType(name, type_args) => Self::Struct(
+                name.clone(),
+                type_args.iter().map(Self::from_anotated_type).collect(),
+            ),
+            TypeAnnotation::FunctionType {
+                params,
+                return_type,
+            } => Self::Function {
+                params: params.iter().map(Self::from_anotated_type).collect(),
+                return_type: Box::new(
+                    return_type
+                        .as_deref()
+                        .map_or(Self::Nil, Self::from_anotated_type),
+                ),
+            },
         }
     }
 }
 
 pub struct TypeChecker {
-    symbol_type_table: SymbolTypeTable,
-    errors: ErrorCollector,
+    pub(crate) symbol_type_table: SymbolTypeTable,
+    /// Labels of the loops enclosing the current statement, innermost last
+    pub(crate) loop_labels: Vec<Option<String>>,
+    /// Return types of the functions being checked, innermost last
+    pub(crate) return_types: Vec<Type>,
+    /// Every declared struct, regardless of the scope it was declared in
+    pub(crate) structs: HashMap<String, StructDefinition>,
+    /// Every declared enum, regardless of the scope it was declared in
+    pub(crate) enums: HashMap<String, EnumDefinition>,
+    /// Type parameters of the generic declarations being checked, outermost first
+    pub(crate) type_parameters: Vec<String>,
+    /// Types the compiler cannot work out again from the declarations alone,
+    /// like those of match expressions and narrowed variables, keyed by span
+    pub(crate) expr_types: HashMap<Span, Type>,
+    /// Types of the variables declared without an annotation, keyed by
+    /// the span of their name, for tools that want to show them
+    pub inferred_types: HashMap<Span, Type>,
+    /// Names assigned from inside a function they are not declared in.
+    /// A call could set them to nil at any time, so they are never narrowed
+    pub(crate) unstable_names: HashSet<String>,
+    /// Top-level functions declared ahead of their statement, by the span
+    /// of their name, until the checker reaches that statement
+    pub(crate) hoisted_functions: HashMap<String, Span>,
+    pub(crate) errors: ErrorCollector,
+    /// Problems that do not stop compilation, like unreachable match arms
+    pub(crate) warnings: ErrorCollector,
 }
 
 impl TypeChecker {
     pub fn new() -> Self {
         Self {
             symbol_type_table: SymbolTypeTable::new(),
+            loop_labels: Vec::new(),
+            return_types: Vec::new(),
+            structs: HashMap::new(),
+            enums: HashMap::new(),
+            type_parameters: Vec::new(),
+            expr_types: HashMap::new(),
+            inferred_types: HashMap::new(),
+            unstable_names: HashSet::new(),
+            hoisted_functions: HashMap::new(),
             errors: ErrorCollector::new(),
+            warnings: ErrorCollector::new(),
         }
     }
 
     /// Main entry point
     pub fn check_program(&mut self, program: &Program) -> Result<(), ErrorCollector> {
+        self.unstable_names = Self::assigned_from_functions(&program.statements);
+        self.declare_top_level(&program.statements);
+
         for stmt in &program.statements {
+            // Types were already declared by the first pass
+            if matches!(
+                stmt.node,
+                Stmt::StructDeclaration { .. } | Stmt::EnumDeclaration { .. }
+            ) {
+                continue;
+            }
+
             // Ignore individual errors, keep checking all statements
             let _ = self.check_statement(stmt);
         }
@@ -79,27 +266,39 @@ impl TypeChecker {
                 identifier,
                 value,
                 annotated_type,
+                mutable,
                 span,
-                ..
             } => {
-                let an_type = Type::from_anotated_type(annotated_type);
-                let value_type = self.check_expression(value)?;
-
                 let var_name = match &identifier.node {
                     Expr::Identifier(name) => name.clone(),
                     _ => unreachable!("Var names are always identifiers"),
                 };
 
+                let Some(annotated_type) = annotated_type else {
+                    return self.check_inferred_declaration(
+                        var_name,
+                        identifier.span,
+                        value,
+                        *mutable,
+                        *span,
+                    );
+                };
+
+                let an_type = self.resolve_annotation(annotated_type);
+                self.check_type_exists(&an_type, *span)?;
+                let value_type = self.check_expression_as(value, &an_type)?;
+
                 // Early store so future reference of the identifier
                 // wont return an "undeclared variable" error
                 self.symbol_type_table.declare_identifier(
                     var_name,
                     an_type.clone(),
                     *span,
+                    *mutable,
                     &mut self.errors,
                 )?;
 
-                if an_type != value_type {
+                if !an_type.accepts(&value_type) {
                     self.throw_error(HydorError::DeclarationTypeMismatch {
                         expected: an_type,
                         got: value_type,
@@ -112,25 +311,299 @@ impl TypeChecker {
                 Ok(())
             }
 
-            _ => throw_error(&format!("unknown ast: \n\n{:#?}", stmt.node), 1),
+            Stmt::TupleDestructuring {
+                identifiers,
+                value,
+                mutable,
+                span,
+            } => self.check_tuple_destructuring(identifiers, value, *mutable, *span),
+
+            Stmt::Block { body } => {
+                self.check_block(body);
+                Ok(())
+            }
+
+            Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                self.check_condition(condition)?;
+                let (when_true, when_false) = self.nil_checks(condition);
+
+                self.check_block_narrowed(then_branch, &when_true);
+                if let Some(else_branch) = else_branch {
+                    self.check_block_narrowed(else_branch, &when_false);
+                }
+
+                // Code after `if x == nil { return }` only runs when x is not nil
+                if Self::always_exits(then_branch) {
+                    self.narrow_all(when_false);
+                } else if else_branch.as_deref().is_some_and(Self::always_exits) {
+                    self.narrow_all(when_true);
+                }
+
+                Ok(())
+            }
+
+            Stmt::While {
+                condition,
+                body,
+                label,
+            } => {
+                // Later iterations see the assignments made by earlier ones
+                self.forget_assigned(body);
+                let condition_result = self.check_condition(condition);
+                let (when_true, _) = self.nil_checks(condition);
+
+                self.loop_labels.push(label.clone());
+                self.check_block_narrowed(body, &when_true);
+                self.loop_labels.pop();
+
+                condition_result
+            }
+
+            Stmt::For {
+                variable,
+                annotated_type,
+                start,
+                end,
+                step,
+                body,
+                label,
+                ..
+            } => {
+                let annotated_type = annotated_type.as_ref().map(|t| self.resolve_annotation(t));
+                let range_type =
+                    self.check_range(start, end, step.as_deref(), annotated_type, variable.span);
+
+                let var_name = match &variable.node {
+                    Expr::Identifier(name) => name.clone(),
+                    _ => unreachable!("Loop variables are always identifiers"),
+                };
+                self.forget_assigned(body);
+
+                // The loop variable is immutable and only visible inside the body
+                self.symbol_type_table.begin_scope();
+                let declare_result = self.symbol_type_table.declare_identifier(
+                    var_name,
+                    range_type.clone().unwrap_or(Type::Integer),
+                    variable.span,
+                    false,
+                    &mut self.errors,
+                );
+
+                self.loop_labels.push(label.clone());
+                self.check_block(body);
+                self.loop_labels.pop();
+
+                self.symbol_type_table.end_scope();
+
+                range_type.map(|_| ()).and(declare_result)
+            }
+
+            Stmt::FunctionDeclaration {
+                identifier,
+                type_params,
+                parameters,
+                return_type,
+                body,
+            } => self.check_function_declaration(
+                identifier,
+                type_params,
+                parameters,
+                return_type.as_ref(),
+                body,
+                stmt.span,
+            ),
+
+            Stmt::Return { value } => self.check_return(value.as_ref(), stmt.span),
+
+            Stmt::StructDeclaration {
+                identifier,
+                type_params,
+                fields,
+            } => self.check_struct_declaration(identifier, type_params, fields),
+
+            Stmt::EnumDeclaration {
+                identifier,
+                type_params,
+                variants,
+            } => self.check_enum_declaration(identifier, type_params, variants),
+
+            Stmt::Break { label } => self.check_loop_control(TokenType::Break, label, stmt.span),
+            Stmt::Continue { label } => {
+                self.check_loop_control(TokenType::Continue, label, stmt.span)
+            }
+        }
+    }
+
+    /// `let x = value` takes the type of the value, which must say more than `nil`
+    fn check_inferred_declaration(
+        &mut self,
+        var_name: String,
+        name_span: Span,
+        value: &Expression,
+        mutable: bool,
+        span: Span,
+    ) -> Result<(), ()> {
+        let value_type = self.check_expression(value)?;
+
+        if value_type == Type::Nil {
+            self.throw_error(HydorError::UntypedNil {
+                name: var_name,
+                span,
+            });
+            return Err(());
+        }
+
+        self.inferred_types.insert(name_span, value_type.clone());
+        self.symbol_type_table.declare_identifier(
+            var_name,
+            value_type,
+            span,
+            mutable,
+            &mut self.errors,
+        )
+    }
+
+    /// Check every statement in a block, continuing past individual errors
+    pub(crate) fn check_block(&mut self, statements: &[Statement]) {
+        self.check_block_narrowed(statements, &[]);
+    }
+
+    /// Conditions must be strictly boolean, no implicit truthiness
+    pub(crate) fn check_condition(&mut self, condition: &Expression) -> Result<(), ()> {
+        let condition_type = self.check_expression(condition)?;
+
+        if condition_type != Type::Bool {
+            self.throw_error(HydorError::TypeMismatch {
+                expected: vec![Type::Bool],
+                found: condition_type,
+                span: condition.span,
+            });
+            return Err(());
+        }
+
+        Ok(())
+    }
+
+    /// The bounds and the step of a range share one integer type, which is
+    /// also the type of the loop variable. It is the annotated type if there
+    /// is one, otherwise literals take the width of the other values like in
+    /// `total + 1`, so `for i in 0..n` counts in n's width
+    fn check_range(
+        &mut self,
+        start: &Expression,
+        end: &Expression,
+        step: Option<&Expression>,
+        annotated_type: Option<Type>,
+        variable_span: Span,
+    ) -> Result<Type, ()> {
+        let values = [start, end].into_iter().chain(step);
+
+        let (range_type, deciding, span) = match annotated_type {
+            Some(annotated_type) => (annotated_type, None, variable_span),
+            None => {
+                let deciding = Self::deciding_element(values.clone());
+                let deciding_value = values
+                    .clone()
+                    .nth(deciding.expect("A range always has bounds"))
+                    .expect("The deciding value is one of the values");
+                let range_type = self.check_expression(deciding_value)?;
+                (range_type, deciding, deciding_value.span)
+            }
+        };
+
+        if !range_type.is_integer() {
+            self.throw_error(HydorError::TypeMismatch {
+                expected: vec![Type::Integer, Type::Int64, Type::Byte],
+                found: range_type,
+                span,
+            });
+            return Err(());
+        }
+
+        let mut result = self.check_all_as(Self::all_but(values, deciding), &range_type);
+        if let Some(step) = step {
+            result = result.and(self.check_range_step(step));
         }
+
+        result.map(|()| range_type)
+    }
+
+    fn check_range_step(&mut self, step: &Expression) -> Result<(), ()> {
+        // Catch steps that are known to never advance the loop
+        let literal_step = match &step.node {
+            Expr::IntegerLiteral(n) => Some(*n),
+            Expr::Unary { operator, right } if *operator == Token::Minus => match right.node {
+                Expr::IntegerLiteral(n) => Some(-n),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Some(n) = literal_step
+            && n <= 0
+        {
+            self.throw_error(HydorError::InvalidRangeStep {
+                step: n,
+                span: step.span,
+            });
+            return Err(());
+        }
+
+        Ok(())
+    }
+
+    fn check_loop_control(
+        &mut self,
+        keyword: TokenType,
+        label: &Option<String>,
+        span: Span,
+    ) -> Result<(), ()> {
+        if self.loop_labels.is_empty() {
+            self.throw_error(HydorError::LoopControlOutsideLoop { keyword, span });
+            return Err(());
+        }
+
+        if let Some(label) = label
+            && !self.loop_labels.iter().any(|l| l.as_ref() == Some(label))
+        {
+            self.throw_error(HydorError::UndefinedLoopLabel {
+                label: label.clone(),
+                span,
+            });
+            return Err(());
+        }
+
+        Ok(())
     }
 
     pub(crate) fn check_expression(&mut self, expr: &Expression) -> Result<Type, ()> {
         let span = expr.span;
 
         match &expr.node {
-            Expr::IntegerLiteral(_) => Ok(Type::Integer),
+            Expr::IntegerLiteral(value) => self.check_integer_literal(*value, None, span),
             Expr::FloatLiteral(_) => Ok(Type::Float),
             Expr::BooleanLiteral(_) => Ok(Type::Bool),
             Expr::StringLiteral(_) => Ok(Type::String),
             Expr::NilLiteral => Ok(Type::Nil),
             Expr::Identifier(name) => {
-                self.symbol_type_table
-                    .resolve_identifier(name, span, &mut self.errors)
+                self.check_hoisted_function_use(name, span)?;
+
+                match self.symbol_type_table.narrowed_type(name) {
+                    Some(narrowed) => {
+                        self.expr_types.insert(span, narrowed.clone());
+                        Ok(narrowed)
+                    }
+                    None => self
+                        .symbol_type_table
+                        .resolve_identifier(name, span, &mut self.errors),
+                }
             }
 
-            Expr::Unary { operator, right } => self.check_unary(operator, right, span),
+            Expr::Unary { operator, right } => self.check_unary(operator, right, None, span),
 
             Expr::BinaryOperation {
                 left,
@@ -138,8 +611,225 @@ impl TypeChecker {
                 right,
             } => self.check_binary_expr(operator, left, right, span),
 
-            _ => unreachable!("Unknown expression type {:?}", expr.node),
+            Expr::Assign { target, value } => self.check_assignment(target, value, span),
+
+            Expr::CompoundAssign {
+                target,
+                operator,
+                value,
+            } => self.check_compound_assignment(target, operator, value, span),
+
+            Expr::Cast { value, target } => self.check_cast(value, target, span),
+
+            Expr::Call { callee, arguments } => self.check_call(callee, arguments, None, span),
+
+            Expr::ArrayLiteral { elements } => self.check_array_literal(elements, None, span),
+
+            Expr::Index { object, index } => self.check_index(object, index),
+
+            Expr::MapLiteral { entries } => self.check_map_literal(entries, None, span),
+
+            Expr::MethodCall {
+                object,
+                method,
+                arguments,
+            } => self.check_method_call(object, method, arguments, span),
+
+            Expr::StructLiteral { name, fields } => {
+                self.check_struct_literal(name, fields, None, span)
+            }
+
+            Expr::FieldAccess { object, field } => self.check_field_access(object, field, span),
+
+            Expr::TupleLiteral { elements } => self.check_tuple_literal(elements, None),
+
+            Expr::TupleAccess { object, index } => self.check_tuple_access(object, *index, span),
+
+            Expr::OptionalChain { object, access } => {
+                self.check_optional_chain(object, access, span)
+            }
+
+            Expr::EnumVariant {
+                enum_name,
+                variant,
+                arguments,
+            } => self.check_enum_variant(enum_name, variant, arguments, None, span),
+
+            Expr::Match { subject, arms } => self.check_match(subject, arms, None, span),
+
+            Expr::Function {
+                parameters,
+                return_type,
+                body,
+            } => self.check_function_body(
+                "<anonymous>",
+                parameters,
+                return_type.as_ref(),
+                body,
+                span,
+            ),
+
+            Expr::Conditional {
+                then_expr,
+                condition,
+                else_expr,
+            } => self.check_conditional(then_expr, condition, else_expr, None, span),
+        }
+    }
+
+    /// Both branches of `a if cond else b` are checked against `expected`,
+    /// so literals in either one take the width the context wants
+    pub(crate) fn check_conditional(
+        &mut self,
+        then_expr: &Expression,
+        condition: &Expression,
+        else_expr: &Expression,
+        expected: Option<&Type>,
+        span: Span,
+    ) -> Result<Type, ()> {
+        self.check_condition(condition)?;
+        let (when_true, when_false) = self.nil_checks(condition);
+
+        let then_type = self.check_expression_narrowed(then_expr, &when_true, expected)?;
+        let else_type = self.check_expression_narrowed(else_expr, &when_false, expected)?;
+
+        // `x if c else nil` is optional
+        match then_type.common_type(&else_type) {
+            Some(conditional_type) => {
+                self.expr_types.insert(span, conditional_type.clone());
+                Ok(conditional_type)
+            }
+            None => {
+                self.throw_error(HydorError::BranchTypeMismatch {
+                    first_type: then_type,
+                    second_type: else_type,
+                    span,
+                });
+                Err(())
+            }
+        }
+    }
+
+    fn check_assignment(
+        &mut self,
+        target: &Expression,
+        value: &Expression,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let name = match &target.node {
+            Expr::Identifier(name) => name,
+            Expr::Index { object, index } => {
+                let element_type = self.check_index(object, index)?;
+                return self.check_element_assignment(element_type, value);
+            }
+            Expr::FieldAccess { object, field } => {
+                let field_type = self.check_field_access(object, field, target.span)?;
+                return self.check_element_assignment(field_type, value);
+            }
+            _ => unreachable!(
+                "Parser only accepts variables, fields and indexing as assignment targets"
+            ),
+        };
+
+        let (declared_type, declaration_span, mutable) = match self.symbol_type_table.lookup(name) {
+            Some(symbol) => (symbol.symbol_type.clone(), symbol.span, symbol.mutable),
+            None => {
+                self.throw_error(HydorError::UndefinedVariable {
+                    name: name.clone(),
+                    span: target.span,
+                });
+                return Err(());
+            }
+        };
+
+        let value_type = self.check_expression_as(value, &declared_type)?;
+
+        if !mutable {
+            self.throw_error(HydorError::ConstantReassignment {
+                name: name.clone(),
+                declaration_span,
+                span,
+            });
+            return Err(());
+        }
+
+        if !declared_type.accepts(&value_type) {
+            self.throw_error(HydorError::AssignmentTypeMismatch {
+                name: name.clone(),
+                expected: declared_type,
+                got: value_type,
+                span,
+            });
+            return Err(());
         }
+
+        self.narrow_after_assignment(name, &declared_type, value_type);
+        Ok(declared_type)
+    }
+
+    /// `target += value` reads the target first, so it is checked like
+    /// `target + value` whose result then has to fit back into the target
+    fn check_compound_assignment(
+        &mut self,
+        target: &Expression,
+        operator: &Token,
+        value: &Expression,
+        span: Span,
+    ) -> Result<Type, ()> {
+        let result_type = self.check_binary_expr(operator, target, value, span)?;
+
+        // Fields and elements can be replaced through a 'const' binding
+        let Expr::Identifier(name) = &target.node else {
+            return Ok(result_type);
+        };
+
+        let (declared_type, declaration_span, mutable) = match self.symbol_type_table.lookup(name) {
+            Some(symbol) => (symbol.symbol_type.clone(), symbol.span, symbol.mutable),
+            None => return Err(()),
+        };
+
+        if !mutable {
+            self.throw_error(HydorError::ConstantReassignment {
+                name: name.clone(),
+                declaration_span,
+                span,
+            });
+            return Err(());
+        }
+
+        if !declared_type.accepts(&result_type) {
+            self.throw_error(HydorError::AssignmentTypeMismatch {
+                name: name.clone(),
+                expected: declared_type,
+                got: result_type,
+                span,
+            });
+            return Err(());
+        }
+
+        self.narrow_after_assignment(name, &declared_type, result_type.clone());
+        Ok(result_type)
+    }
+
+    /// Fields and elements can be replaced even when the value
+    /// holding them is bound with 'const'
+    fn check_element_assignment(
+        &mut self,
+        element_type: Type,
+        value: &Expression,
+    ) -> Result<Type, ()> {
+        let value_type = self.check_expression_as(value, &element_type)?;
+
+        if !element_type.accepts(&value_type) {
+            self.throw_error(HydorError::TypeMismatch {
+                expected: vec![element_type],
+                found: value_type,
+                span: value.span,
+            });
+            return Err(());
+        }
+
+        Ok(element_type)
     }
 
     pub(crate) fn throw_error(&mut self, error: HydorError) {
@@ -11,9 +11,15 @@ impl TypeChecker {
         &mut self,
         operator: &Token,
         right: &Expression,
+        expected: Option<&Type>,
         span: Span,
     ) -> Result<Type, ()> {
-        let right_type = self.check_expression(right)?;
+        // Literal operands, like in `~0` or `-(1 + 2)`, take the integer width
+        // the context expects
+        let right_type = match expected {
+            Some(expected) => self.check_expression_as(right, expected)?,
+            None => self.check_expression(right)?,
+        };
         let op_token = operator.get_token_type();
 
         match op_token {
@@ -30,7 +36,19 @@ impl TypeChecker {
             }
 
             TokenType::Minus => {
-                if right_type != Type::Integer && right_type != Type::Float {
+                if !matches!(right_type, Type::Integer | Type::Int64 | Type::Float) {
+                    self.throw_error(HydorError::InvalidUnaryOp {
+                        operator: op_token,
+                        operand_type: right_type,
+                        span,
+                    });
+                    return Err(());
+                }
+                Ok(right_type)
+            }
+
+            TokenType::Tilde => {
+                if !right_type.is_integer() {
                     self.throw_error(HydorError::InvalidUnaryOp {
                         operator: op_token,
                         operand_type: right_type,